@@ -0,0 +1,289 @@
+//! Plays gamble games from a terminal, without a bot token or a Discord server.
+//!
+//! Type `g!` commands as you would in Discord (the prefix is optional) and switch between
+//! players with `:as <name>`. Mentions can be written as `@name`. Run `:help` for the rest.
+
+use std::{ collections::HashMap, error::Error, io::{ self, BufRead, Write }, sync::Arc };
+
+use gamble::{
+    CommandContext,
+    GGMResponse,
+    GambleGameManager,
+    GameError,
+    GameStore,
+    GuildConfigStore,
+    IdleTimeouts,
+    Ledger,
+    map_game_error_to_discord_message,
+    map_ggm_response_to_discord_message,
+    parse_prefixed_message,
+};
+
+const HELP: &str =
+    r#"
+:as <name>        play as another user (created on first use)
+:guild <id>|dm    send commands from a guild or from DMs
+:channel <id>     send commands from another channel
+:mod on|off       toggle moderator and Manage Server permissions for the current user
+:wait <minutes>   let time pass and apply the idle timeouts
+:users            list the users and their ids
+:help             show this help
+:quit             exit
+Anything else is run as a `g!` command, e.g. `g!create 500` or `kick @bob`.
+"#;
+
+/// Impersonated users get small numeric ids so they can be mentioned like Discord users.
+struct Users {
+    ids: HashMap<String, String>,
+    names: HashMap<String, String>,
+}
+
+impl Users {
+    fn new() -> Self {
+        Self { ids: HashMap::new(), names: HashMap::new() }
+    }
+
+    fn id(&mut self, name: &str) -> String {
+        if let Some(id) = self.ids.get(name) {
+            return id.clone();
+        }
+
+        let id = (self.ids.len() + 1).to_string();
+        self.ids.insert(name.to_string(), id.clone());
+        self.names.insert(id.clone(), name.to_string());
+
+        id
+    }
+
+    /// Turns `@name` arguments into `<@id>` mentions.
+    fn mention(&mut self, arg: &str) -> String {
+        match arg.strip_prefix('@') {
+            Some(name) if !name.is_empty() => format!("<@{}>", self.id(name)),
+            _ => arg.to_string(),
+        }
+    }
+
+    /// Turns `<@id>` mentions back into `@name` so the output is readable.
+    fn humanize(&self, message: &str) -> String {
+        let mut message = message.to_string();
+
+        for (id, name) in &self.names {
+            message = message.replace(&format!("<@{}>", id), &format!("@{}", name));
+        }
+
+        message
+    }
+}
+
+struct Session {
+    manager: GambleGameManager,
+    users: Users,
+    user: String,
+    guild_id: Option<String>,
+    channel_id: String,
+    moderators: Vec<String>,
+}
+
+impl Session {
+    fn print(&self, message: &str) {
+        println!("{}", self.users.humanize(message));
+    }
+
+    fn print_response(&self, guild_id: Option<&String>, user_id: &String, response: GGMResponse) {
+        let config = self.manager.guild_config(guild_id);
+
+        if let Some(message) = map_ggm_response_to_discord_message(user_id, response, &config) {
+            self.print(&message);
+        }
+    }
+
+    fn print_error(&self, guild_id: Option<&String>, user_id: &String, e: GameError) {
+        let config = self.manager.guild_config(guild_id);
+        self.print(&map_game_error_to_discord_message(user_id, e, &config));
+    }
+
+    fn run_command(&mut self, line: &str) {
+        let prefix = self.manager.guild_config(self.guild_id.as_ref()).prefix;
+        let line = if line.starts_with(&prefix) { line.to_string() } else { format!("{}{}", prefix, line) };
+
+        let Some(mut command) = parse_prefixed_message(&line, &prefix) else {
+            return;
+        };
+
+        command.args = command.args
+            .iter()
+            .map(|arg| self.users.mention(arg))
+            .collect();
+
+        let user_id = self.users.id(&self.user);
+        let ctx = CommandContext {
+            guild_id: self.guild_id.clone(),
+            channel_id: self.channel_id.clone(),
+            user_id: user_id.clone(),
+            is_moderator: self.moderators.contains(&user_id),
+            can_manage_guild: self.moderators.contains(&user_id),
+            locale: None,
+            can_embed: false,
+        };
+
+        let args = command.args
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+
+        let guild_id = ctx.guild_id.as_ref();
+        let game_id = self.manager.find_game(&ctx, &args);
+
+        match self.manager.execute(&ctx, &command.name, args) {
+            Ok(response) => self.print_response(guild_id, &user_id, response),
+            Err(e) => self.print_error(guild_id, &user_id, e),
+        }
+
+        let Some(game_id) = game_id else {
+            return;
+        };
+
+        match self.manager.tick(&game_id) {
+            Ok(response) => self.print_response(guild_id, &user_id, response),
+            Err(e) => self.print_error(guild_id, &user_id, e),
+        }
+    }
+
+    fn wait(&mut self, minutes: u64) {
+        self.manager.clock().advance(minutes * 60);
+
+        let updates = self.manager.expire_idle_games(&IdleTimeouts::default());
+
+        for update in updates {
+            self.print(&format!("[channel {}, game {}]", update.channel_id, update.game_id));
+
+            let guild_id = update.guild_id.as_ref();
+
+            for response in update.responses {
+                match response {
+                    Ok(response) => self.print_response(guild_id, &update.creator, response),
+                    Err(e) => self.print_error(guild_id, &update.creator, e),
+                }
+            }
+        }
+    }
+
+    /// Handles a `:` directive, returning false when the session should end.
+    fn run_directive(&mut self, directive: &str) -> bool {
+        let mut parts = directive.split_ascii_whitespace();
+
+        match (parts.next(), parts.next()) {
+            (Some("as"), Some(name)) => {
+                self.users.id(name);
+                self.user = name.to_string();
+            }
+            (Some("guild"), Some("dm")) => {
+                self.guild_id = None;
+            }
+            (Some("guild"), Some(guild_id)) => {
+                self.guild_id = Some(guild_id.to_string());
+            }
+            (Some("channel"), Some(channel_id)) => {
+                self.channel_id = channel_id.to_string();
+            }
+            (Some("mod"), Some(toggle)) => {
+                let user_id = self.users.id(&self.user);
+                self.moderators.retain(|id| *id != user_id);
+
+                if toggle == "on" {
+                    self.moderators.push(user_id);
+                }
+            }
+            (Some("wait"), Some(minutes)) => {
+                match minutes.parse::<u64>() {
+                    Ok(minutes) => self.wait(minutes),
+                    Err(_) => println!("usage: :wait <minutes>"),
+                }
+            }
+            (Some("users"), None) => {
+                let mut users = self.users.ids.iter().collect::<Vec<(&String, &String)>>();
+                users.sort_by_key(|(_, id)| id.parse::<u64>().unwrap_or(0));
+
+                for (name, id) in users {
+                    println!("{} <@{}>", name, id);
+                }
+            }
+            (Some("quit"), None) => {
+                return false;
+            }
+            _ => print!("{}", HELP.trim_start()),
+        }
+
+        true
+    }
+
+    fn prompt(&self) -> String {
+        let guild = self.guild_id.as_deref().unwrap_or("dm");
+        let moderator = if self.moderators.contains(self.users.ids.get(&self.user).unwrap()) {
+            " (mod)"
+        } else {
+            ""
+        };
+
+        format!("[{}{} @ {}/{}]> ", self.user, moderator, guild, self.channel_id)
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // `--database <PATH>` keeps the ledger, games and settings between runs, otherwise nothing is saved.
+    let args = std::env::args().collect::<Vec<String>>();
+    let (ledger, store, configs) = match args.iter().position(|arg| arg == "--database") {
+        Some(index) => {
+            let path = args.get(index + 1).ok_or("--database needs a path")?;
+            (Ledger::open(path)?, GameStore::open(path)?, GuildConfigStore::open(path)?)
+        }
+        None =>
+            (Ledger::open_in_memory()?, GameStore::open_in_memory()?, GuildConfigStore::open_in_memory()?),
+    };
+
+    let mut manager = GambleGameManager::new(Arc::new(ledger), Arc::new(store), Arc::new(configs));
+    manager.restore();
+
+    let mut users = Users::new();
+    users.id("alice");
+
+    let mut session = Session {
+        manager,
+        users,
+        user: "alice".into(),
+        guild_id: Some("1".into()),
+        channel_id: "1".into(),
+        moderators: vec![],
+    };
+
+    println!("gamble-cli: type `:help` for directives, `g!help` for commands.");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("{}", session.prompt());
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        match line.strip_prefix(':') {
+            Some(directive) => {
+                if !session.run_directive(directive) {
+                    break;
+                }
+            }
+            None => session.run_command(line),
+        }
+    }
+
+    Ok(())
+}
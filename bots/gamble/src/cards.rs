@@ -0,0 +1,156 @@
+use core::fmt;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::{ fairness::FairDice, types::{ PlayerId, RollValue } };
+
+const SUITS: [char; 4] = ['♠', '♥', '♦', '♣'];
+const RANKS: u8 = 13;
+
+/// A playing card. Ranks go from 1 (ace) to 13 (king), suits index `SUITS`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    pub rank: u8,
+    pub suit: u8,
+}
+
+impl Card {
+    /// Blackjack value of the card, counting aces as 1.
+    pub fn value(&self) -> u64 {
+        u64::from(self.rank.min(10))
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rank = match self.rank {
+            1 => "A".to_string(),
+            11 => "J".to_string(),
+            12 => "Q".to_string(),
+            13 => "K".to_string(),
+            rank => rank.to_string(),
+        };
+
+        write!(f, "{}{}", rank, SUITS[usize::from(self.suit)])
+    }
+}
+
+/// Best blackjack total of `cards`: aces count as 11 unless that would bust the hand.
+pub fn total(cards: &[Card]) -> u64 {
+    let total = cards.iter().map(Card::value).sum::<u64>();
+
+    if cards.iter().any(|card| card.rank == 1) && total + 10 <= 21 {
+        return total + 10;
+    }
+
+    return total;
+}
+
+/// Decks shuffled together. Cards are drawn with the game's dice, each draw being a roll for the
+/// index of the card among those left in the shoe, so `g!verify` can replay the whole deal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Shoe {
+    decks: usize,
+    cards: Vec<Card>,
+}
+
+impl Shoe {
+    /// `decks` unshuffled decks, one after the other.
+    pub fn new(decks: usize) -> Self {
+        let cards = (0..decks)
+            .flat_map(|_| {
+                (0..SUITS.len() as u8).flat_map(|suit| (1..=RANKS).map(move |rank| Card { rank, suit }))
+            })
+            .collect();
+
+        Self { decks, cards }
+    }
+
+    /// Draws a card for `player_id`, refilling the shoe once it runs out.
+    pub fn draw(&mut self, dice: &mut FairDice, player_id: &str) -> Card {
+        if self.cards.is_empty() {
+            *self = Self::new(self.decks);
+        }
+
+        let index = dice.roll(player_id, 0, (self.cards.len() - 1) as RollValue);
+
+        return self.cards.remove(index as usize);
+    }
+}
+
+/// A player's blackjack hand and what they bet on it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hand {
+    pub player_id: PlayerId,
+    pub cards: Vec<Card>,
+    pub wager: RollValue,
+    /// The player can't draw to the hand anymore: they stood, doubled, busted or reached 21.
+    pub done: bool,
+    /// Hands split off another one don't count as a blackjack.
+    pub split: bool,
+    /// The player gave the hand up, by leaving or running out of time, and loses its wager.
+    pub forfeited: bool,
+}
+
+impl Hand {
+    pub fn new(player_id: PlayerId, wager: RollValue) -> Self {
+        Self {
+            player_id,
+            cards: vec![],
+            wager,
+            done: false,
+            split: false,
+            forfeited: false,
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        total(&self.cards)
+    }
+
+    pub fn is_bust(&self) -> bool {
+        self.total() > 21
+    }
+
+    pub fn is_blackjack(&self) -> bool {
+        !self.split && self.cards.len() == 2 && self.total() == 21
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(ranks: &[u8]) -> Vec<Card> {
+        ranks.iter().map(|&rank| Card { rank, suit: 0 }).collect()
+    }
+
+    #[test]
+    fn aces_count_as_eleven_unless_that_busts_the_hand() {
+        assert_eq!(total(&cards(&[1, 6])), 17);
+        assert_eq!(total(&cards(&[1, 6, 10])), 17);
+        assert_eq!(total(&cards(&[1, 1])), 12);
+        assert_eq!(total(&cards(&[1, 1, 9])), 21);
+        assert_eq!(total(&cards(&[1, 1, 10, 10])), 22);
+    }
+
+    #[test]
+    fn face_cards_count_as_ten() {
+        assert_eq!(total(&cards(&[11, 12])), 20);
+        assert_eq!(total(&cards(&[1, 13])), 21);
+    }
+
+    #[test]
+    fn only_two_card_hands_that_were_not_split_are_a_blackjack() {
+        let mut hand = Hand::new("a".into(), 100);
+        hand.cards = cards(&[1, 13]);
+        assert!(hand.is_blackjack());
+
+        hand.split = true;
+        assert!(!hand.is_blackjack());
+
+        hand.split = false;
+        hand.cards = cards(&[7, 7, 7]);
+        assert!(!hand.is_blackjack());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{ locale::Locale, messages::MessageKey, tone::Tone };
+
+mod de;
+mod en;
+mod fr;
+
+/// The template of a message in a language and a tone. Every catalog matches every key, so a
+/// message can't be added without writing it in each of them.
+pub fn template(locale: Locale, tone: Tone, key: MessageKey) -> &'static str {
+    let template = match (locale, tone) {
+        (Locale::En, Tone::Savage) => en::savage::template(key),
+        (Locale::En, Tone::Neutral) => en::neutral::template(key),
+        (Locale::En, Tone::Wholesome) => en::wholesome::template(key),
+        (Locale::Fr, Tone::Savage) => fr::savage::template(key),
+        (Locale::Fr, Tone::Neutral) => fr::neutral::template(key),
+        (Locale::Fr, Tone::Wholesome) => fr::wholesome::template(key),
+        (Locale::De, Tone::Savage) => de::savage::template(key),
+        (Locale::De, Tone::Neutral) => de::neutral::template(key),
+        (Locale::De, Tone::Wholesome) => de::wholesome::template(key),
+    };
+
+    // Long templates are written as raw strings starting and ending on their own line.
+    return template.trim_matches('\n');
+}
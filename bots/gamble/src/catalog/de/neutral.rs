@@ -0,0 +1,225 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame => "{player}, es läuft bereits ein Spiel. (z. B.: `g!info`)",
+        MessageKey::SeveralGames =>
+            "{player}, hier laufen mehrere Spiele. Gib die ID des gewünschten Spiels im Befehl an: {games}",
+        MessageKey::NotEnoughPlayers => "{player}, das Spiel braucht mindestens {count} Spieler.",
+        MessageKey::GoldAmountTooSmall => "{player}, der Mindesteinsatz beträgt {amount} Gold.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            "{player}, es gibt kein Spiel, in dem du würfeln kannst. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            "{player}, es gibt kein Spiel, das angezeigt werden kann. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            "{player}, es gibt kein Spiel, das gestartet werden kann. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            "{player}, es gibt kein Spiel, dem du beitreten kannst. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            "{player}, dieses Spiel hat bereits begonnen. Du kannst beim nächsten mitmachen.",
+        MessageKey::PlayerAlreadyPartOfGame => "{player}, du bist diesem Spiel bereits beigetreten.",
+        MessageKey::PlayerCannotRoll => "{player}, du kannst gerade nicht würfeln.",
+        MessageKey::PlayerAlreadyRolled => "{player}, du hast in dieser Runde bereits gewürfelt.",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, du hast den niedrigsten Wurf geteilt. Bitte würfle erneut.",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, du hast den höchsten Wurf geteilt. Bitte würfle erneut.",
+        MessageKey::NoWinnersFound => "Das Spiel ist vorbei, aber es konnte kein Gewinner ermittelt werden.",
+        MessageKey::UnknownCommand => "{player}, diesen Befehl gibt es nicht. (z. B.: `g!help`)",
+        MessageKey::MissingPlayerMention => "{player}, bitte erwähne einen Spieler. (z. B.: `g!owes @jemand`)",
+        MessageKey::LedgerOnlyAvailableInGuilds => "{player}, das Kassenbuch gibt es nur auf Servern.",
+        MessageKey::LedgerUnavailable =>
+            "{player}, das Kassenbuch ist gerade nicht verfügbar, versuche es später erneut.",
+        MessageKey::CannotPayYourself => "{player}, du kannst dich nicht selbst bezahlen.",
+        MessageKey::InvalidPaymentAmount =>
+            "{player}, bitte gib den gezahlten Betrag an. (z. B.: `g!paid @jemand 500`)",
+        MessageKey::NothingOwed =>
+            "{player}, du schuldest {payee} nichts (oder es wartet bereits auf Bestätigung).",
+        MessageKey::PaymentExceedsDebt =>
+            "{player}, das ist mehr, als du schuldest. Du schuldest **{amount}** Gold.",
+        MessageKey::NoPendingPayments => "{player}, du hast keine Zahlungen zu bestätigen.",
+        MessageKey::UnknownPeriod => "{player}, unbekannter Zeitraum. (z. B.: `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode => "{player}, unbekannter Spielmodus. (z. B.: `g!modes`)",
+        MessageKey::PlayerNotPartOfGame => "{player}, du nimmst nicht an diesem Spiel teil.",
+        MessageKey::NotYourTurn => "{player}, {current} ist mit Würfeln dran.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame => "{player}, es gibt kein Spiel, das du verlassen kannst.",
+        MessageKey::PlayerCannotCancelAnInexistentGame => "{player}, es gibt kein Spiel, das abgebrochen werden kann.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame =>
+            "{player}, es gibt kein Spiel, aus dem jemand entfernt werden kann.",
+        MessageKey::OnlyCreatorOrModerator =>
+            "{player}, das können nur der Ersteller des Spiels oder ein Moderator.",
+        MessageKey::CannotKickFromOngoingGame =>
+            "{player}, nach Spielbeginn können keine Spieler mehr entfernt werden. (z. B.: `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => "{player}, {kicked} nimmt nicht an diesem Spiel teil.",
+        MessageKey::WinnerCannotLeave => "{player}, du hast bereits gewonnen und kannst das Spiel nicht verlassen.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            "{player}, es gibt kein Spiel, in dem du eine Hand spielen kannst. (z. B.: `g!create blackjack`)",
+        MessageKey::NoHandToPlay => "{player}, du hast gerade keine Hand zu spielen.",
+        MessageKey::CannotDouble => "{player}, du kannst nur mit deinen ersten zwei Karten verdoppeln.",
+        MessageKey::CannotSplit => "{player}, du kannst nur ein Paar teilen, in höchstens 4 Hände.",
+        MessageKey::HouseCannotLeave =>
+            "{player}, du bist die Bank und kannst nicht gehen, solange Hände gespielt werden.",
+        MessageKey::GameAlreadyOver => "{player}, dieses Spiel ist bereits vorbei.",
+        MessageKey::MissingGameId => "{player}, bitte gib eine Spiel-ID an. (z. B.: `g!verify <SPIEL_ID>`)",
+        MessageKey::UnknownGameId => "{player}, kein beendetes Spiel mit der ID `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds => "{player}, Einstellungen gibt es nur auf Servern.",
+        MessageKey::OnlyGuildManagers =>
+            "{player}, nur Mitglieder, die den Server verwalten dürfen, können seine Einstellungen ändern.",
+        MessageKey::UnknownConfigKey =>
+            "{player}, unbekannte Einstellung. Verfügbare Einstellungen: {keys}. (z. B.: `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            "{player}, ungültiger Wert für `{key}`. (z. B.: `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            "{player}, die Einstellungen können gerade nicht gespeichert werden, versuche es später erneut.",
+
+        MessageKey::Started => "Das Spiel hat begonnen. Tippe `g!roll` oder klicke auf **Roll**.",
+        MessageKey::ShowJoinInfo =>
+            "Tippe `g!join` oder klicke auf **Join**, um dem Spiel beizutreten.\n:lock: Spiel `{game_id}`, Seed-Hash `{seed_hash}`",
+        MessageKey::Done =>
+            "__Das Spiel ist vorbei.__\n{payouts}\n:unlock: Seed `{seed}` (z. B.: `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} schuldet {creditor} **{amount}** Gold.",
+        MessageKey::NoPayouts => ":handshake: Niemand schuldet jemandem etwas.",
+        MessageKey::Verified =>
+            ":mag: __Spiel `{game_id}`__\nSeed `{seed}`\nHash `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} hat **{value}** zwischen {min} und {max} gewürfelt {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Jeder Wurf passt zum offengelegten Seed.",
+        MessageKey::VerifiedUnfair => ":x: Dieses Spiel passt **nicht** zu seinem offengelegten Seed.",
+        MessageKey::TieBreak => "Stechen",
+        MessageKey::Dealer => "Geber",
+        MessageKey::PlayerRolled => "{player} hat eine {roll} gewürfelt.",
+        MessageKey::OwedTo => "__Schulden bei {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => "Niemand schuldet {creditor} etwas.",
+        MessageKey::OwedBy => "__Schulden von {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => "{debtor} schuldet niemandem etwas.",
+        MessageKey::DebtLine => "- {debtor} schuldet {creditor} **{amount}** Gold",
+        MessageKey::Ledger => "__Letzte Spiele__\n{outcomes}",
+        MessageKey::LedgerEmpty => "Es wurden noch keine Spiele aufgezeichnet.",
+        MessageKey::OutcomeLine => "- {time} {creditor} hat **{amount}** Gold von {debtor} gewonnen",
+        MessageKey::PaymentPending =>
+            "{payee}, {payer} sagt, dir **{amount}** Gold gezahlt zu haben. Tippe `g!confirm` oder klicke auf **Confirm**, sobald du es erhalten hast.",
+        MessageKey::PaymentsConfirmed => "{payee} hat den Erhalt von **{amount}** Gold von {payer} bestätigt.",
+        MessageKey::Settlement =>
+            "__Abrechnung__\nDiese Zahlungen begleichen alle Schulden auf dem Server:\n{debts}\n*Nutze `g!paid @spieler <GOLDBETRAG>`, sobald du gezahlt hast.*",
+        MessageKey::SettlementEmpty => "Es gibt keine Schulden zu begleichen.",
+        MessageKey::Stats =>
+            "__Statistiken von {player}__\n- Gespielte Spiele: **{games}**\n- Siege: **{wins}**\n- Niederlagen: **{losses}**\n- Bilanz: **{net}** Gold\n- Größter Gewinn: **{biggest_win}** Gold\n- Größter Verlust: **{biggest_loss}** Gold",
+        MessageKey::StatsNoGames => "{player} hat noch kein Spiel beendet.",
+        MessageKey::Leaderboard => "__Bestenliste {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => "Es wurden {period} keine Spiele beendet.",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** Gold ({wins}S / {losses}N)",
+        MessageKey::PeriodWeek => "diese Woche",
+        MessageKey::PeriodMonth => "diesen Monat",
+        MessageKey::PeriodAll => "aller Zeiten",
+        MessageKey::ShowModes =>
+            "__Spielmodi__\n{modes}\n*Erstelle ein Spiel mit `g!create <MODUS> <GOLDBETRAG>`, mehr erfährst du mit `g!modes <MODUS>`.*",
+        MessageKey::ModeLine => "- `{name}` {summary} *({min_players}+ Spieler, {min_wager}+ Gold)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- Mindestens **{min_players}** Spieler und **{min_wager}** Gold.\n*z. B.: `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled => "Das Spiel wurde abgebrochen, weil es niemand rechtzeitig gestartet hat.",
+        MessageKey::AutoRolled => "{player} hat nicht rechtzeitig gewürfelt, also wurde automatisch gewürfelt: **{roll}**.",
+        MessageKey::AutoPlayed => "{player} hat nicht rechtzeitig gespielt, also bleiben die Hände stehen.",
+        MessageKey::Forfeited => "{player} hat nicht rechtzeitig gewürfelt und gibt auf.",
+        MessageKey::Left => "{player} hat das Spiel verlassen.",
+        MessageKey::LeftAndForfeited => "{player} hat das Spiel verlassen und gibt auf.",
+        MessageKey::Kicked => "{player} wurde aus dem Spiel entfernt.",
+        MessageKey::Cancelled => "Das Spiel wurde abgebrochen.",
+        MessageKey::ShowConfig =>
+            "__Servereinstellungen__\n{settings}\n*Ändere eine mit `g!config set <SCHLÜSSEL> <WERT>` oder setze sie mit `default` zurück.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => "`{key}` ist `{value}`.",
+        MessageKey::ConfigUpdated => "{player} hat `{key}` auf `{value}` gesetzt.",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Würfle gegen andere Spieler um Gold.*
+- `g!create [MODUS] <GOLDBETRAG>`  __Ein Spiel in einem Kanal erstellen__
+- `g!modes [MODUS]` __Die Spielmodi auflisten oder einen erklären__
+- `g!join [SPIEL_ID]` __Einem neuen Spiel beitreten__
+- `g!play [SPIEL_ID]` __Ein neues Spiel starten__
+- `g!roll [SPIEL_ID]` __Würfeln__
+- `g!hit|stand|double|split [SPIEL_ID]` __Deine Blackjack-Hand spielen__
+- `g!leave [SPIEL_ID]` __Das Spiel verlassen und aufgeben, falls es schon begonnen hat__
+- `g!cancel [SPIEL_ID]` __Das Spiel abbrechen (nur Ersteller oder Moderatoren)__
+- `g!kick @spieler [SPIEL_ID]` __Einen Spieler vor Spielbeginn entfernen (nur Ersteller oder Moderatoren)__
+- `g!verify <SPIEL_ID>` __Jeden Wurf eines beendeten Spiels aus seinem offengelegten Seed nachrechnen__
+- `g!help` __Alle Befehle auflisten__
+- `g!info [SPIEL_ID]` __Informationen zum aktuellen Spiel anzeigen__
+- `g!owed` __Auflisten, wer dir Gold schuldet__
+- `g!owes @spieler` __Auflisten, wem ein Spieler Gold schuldet__
+- `g!ledger` __Die letzten Spiele auf diesem Server auflisten__
+- `g!settle` __Die wenigsten Zahlungen auflisten, die alle Schulden auf diesem Server begleichen__
+- `g!stats [@spieler]` __Die Statistiken eines Spielers anzeigen__
+- `g!leaderboard [week|month|all]` __Die besten Spieler des Zeitraums anzeigen__
+- `g!paid @spieler <GOLDBETRAG>` __Einem Spieler mitteilen, dass du ihn bezahlt hast__
+- `g!confirm [@spieler]` __Den Erhalt einer Zahlung bestätigen__
+- `g!config [get|set] [SCHLÜSSEL] [WERT]` __Die Einstellungen dieses Servers anzeigen oder ändern (nur Server verwalten)__
+*Jeder Befehl ist auch als Slash-Befehl verfügbar, z. B. `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary =>
+            "Alle würfeln einmal, der niedrigste Wurf zahlt dem höchsten die Differenz.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Klassisch**
+- Jeder Spieler würfelt zwischen 0 und dem Goldbetrag.
+- Der niedrigste Wurf schuldet dem höchsten die Differenz zwischen beiden Würfen.
+- Spieler mit gleichem höchsten oder niedrigsten Wurf würfeln zum Stechen erneut.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Würfelt abwechselnd unter dem vorherigen Wurf, wer eine 1 würfelt, zahlt den Einsatz.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Die Spieler würfeln in der Reihenfolge ihres Beitritts, beginnend zwischen 1 und dem Goldbetrag.
+- Jeder Wurf liegt zwischen 1 und dem vorherigen Wurf.
+- Wer eine 1 würfelt, schuldet den gesamten Betrag dem Spieler, der davor gewürfelt hat.
+"#,
+        MessageKey::BlackjackSummary => "Spielt eure Hände gegen den Geber, der Ersteller ist die Bank.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- Der Ersteller ist die Bank. Alle anderen bekommen eine Hand um den Goldbetrag.
+- `g!hit` zieht eine Karte, `g!stand` behält die Hand, `g!double` verdoppelt den Einsatz für eine letzte Karte und `g!split` teilt ein Paar in zwei Hände.
+- Sobald alle Hände gespielt sind, zieht der Geber bis 17. Schlag den Geber, ohne über 21 zu kommen, um den Einsatz von der Bank zu gewinnen. Ein Blackjack zahlt 3 zu 2.
+- Die Karten werden mit dem Seed des Spiels aus 6 Decks gezogen.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- Noch niemand ist beigetreten",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Laufendes Spiel!__\nUm **{amount}** Gold!\n\n*Bereits beigetretene Spieler*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Starten des Spiels",
+        MessageKey::InfoClassicOngoing => "Das Spiel läuft!\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} muss noch würfeln! (z. B.: `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll!__\nUm **{amount}** Gold!\n\n*Spieler in Zugreihenfolge*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Starten des Spiels",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Der Death Roll läuft!\n{player}, würfle zwischen **1** und **{max}**! (z. B.: `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack!__\nUm **{amount}** Gold pro Hand, {house} ist die Bank!\n\n*Bereits beigetretene Spieler*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Austeilen der Karten",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack!__\n{house} ist die Bank, **{amount}** Gold pro Hand.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Geber* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** um {amount} Gold {status}",
+        MessageKey::InfoDone => "{debtor} schuldet {creditor} {amount} Gold!",
+        MessageKey::InfoCancelled => "Das Spiel wurde abgebrochen.",
+        MessageKey::InfoRolls => "{info}\n\n*Würfe*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "Spiel von {player}",
+
+        MessageKey::FieldPlayers => "Spieler",
+        MessageKey::FieldRolls => "Würfe",
+        MessageKey::FieldWager => "Einsatz",
+        MessageKey::FieldWinner => "Gewinner",
+        MessageKey::FieldLoser => "Verlierer",
+        MessageKey::FieldDifference => "Differenz",
+        MessageKey::ButtonJoin => "Beitreten",
+        MessageKey::ButtonStart => "Starten",
+        MessageKey::ButtonRoll => "Würfeln",
+        MessageKey::ButtonHit => "Karte",
+        MessageKey::ButtonStand => "Halten",
+        MessageKey::ButtonDouble => "Verdoppeln",
+        MessageKey::ButtonSplit => "Teilen",
+        MessageKey::ButtonInfo => "Info",
+        MessageKey::ButtonConfirm => "Bestätigen",
+    }
+}
@@ -0,0 +1,241 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame =>
+            ":man_facepalming: {player}, Alter, es läuft doch schon ein Spiel. (z. B.: `g!info`)",
+        MessageKey::SeveralGames =>
+            "{player}, hier läuft mehr als ein Spiel, Genie. Häng die ID von deinem an den Befehl: {games}",
+        MessageKey::NotEnoughPlayers => ":upside_down: {player}, es braucht mindestens {count} Spieler.",
+        MessageKey::GoldAmountTooSmall =>
+            ":pinched_fingers: {player}, bist du pleite oder was? Setz mindestens {amount} Gold.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            "{player}, __du Vollpfosten__, worum willst du denn würfeln? (z. B.: `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            "{player}, __du hoffnungsloser Fall__, erstell erst mal ein Spiel, bevor du nach Infos fragst. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            "{player}, __du Pfeife__, erstell erst mal ein Spiel, bevor du es startest. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            "{player}, __du Pappnase__, erstell erst mal ein Spiel, bevor du beitrittst. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            ":weary: {player}, lass das Spiel erst zu Ende gehen und mach beim nächsten mit.",
+        MessageKey::PlayerAlreadyPartOfGame => ":zany_face: {player}, du bist schon im Spiel, du Knalltüte.",
+        MessageKey::PlayerCannotRoll => ":unamused: {player}, jetzt ist nicht der Moment zum Würfeln.",
+        MessageKey::PlayerAlreadyRolled =>
+            ":expresionless: {player}, glaubst du echt, zweimal würfeln hilft dir?",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, du hast den niedrigsten Wurf geteilt. Bitte würfle erneut.",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, du hast den höchsten Wurf geteilt. Bitte würfle erneut.",
+        MessageKey::NoWinnersFound =>
+            ":thinking: Was zum Teufel, kein Gewinner.. aber das Spiel ist vorbei? Ach du Scheiße.",
+        MessageKey::UnknownCommand => "{player}, ist das dein erstes Mal? (z. B.: `g!help`)",
+        MessageKey::MissingPlayerMention =>
+            "{player}, von wem redest du? Erwähne einen Spieler. (z. B.: `g!owes @jemand`)",
+        MessageKey::LedgerOnlyAvailableInGuilds =>
+            "{player}, das Kassenbuch gibt's nur auf Servern, hier gibt's niemanden, dem du was schulden kannst.",
+        MessageKey::LedgerUnavailable =>
+            ":floppy_disk: {player}, das Kassenbuch ist gerade nicht verfügbar, versuch's später nochmal.",
+        MessageKey::CannotPayYourself => ":clown: {player}, dich selbst zurückzahlen? Netter Versuch.",
+        MessageKey::InvalidPaymentAmount =>
+            "{player}, wie viel hast du gezahlt? (z. B.: `g!paid @jemand 500`)",
+        MessageKey::NothingOwed =>
+            ":face_with_raised_eyebrow: {player}, du schuldest {payee} nichts (oder es wartet schon auf Bestätigung).",
+        MessageKey::PaymentExceedsDebt =>
+            ":money_mouth: {player}, das ist mehr, als du schuldest. Du schuldest nur **{amount}** Gold.",
+        MessageKey::NoPendingPayments => "{player}, niemand wartet darauf, dass du eine Zahlung bestätigst.",
+        MessageKey::UnknownPeriod =>
+            "{player}, such dir einen Zeitraum aus, den es gibt. (z. B.: `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode => "{player}, von dem Spiel hab ich noch nie gehört. (z. B.: `g!modes`)",
+        MessageKey::PlayerNotPartOfGame => ":eyes: {player}, du spielst nicht mal mit. Warte aufs nächste.",
+        MessageKey::NotYourTurn =>
+            ":hand_splayed: {player}, warte, bis du dran bist, {current} würfelt gerade.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame =>
+            "{player}, was verlassen? Hier gibt's kein Spiel. (z. B.: `g!create`)",
+        MessageKey::PlayerCannotCancelAnInexistentGame =>
+            "{player}, hier gibt's nichts abzubrechen, __du Genie__.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame =>
+            "{player}, woraus rauswerfen? Hier gibt's kein Spiel.",
+        MessageKey::OnlyCreatorOrModerator =>
+            ":police_officer: {player}, das darf nur, wer das Spiel erstellt hat, oder ein Moderator.",
+        MessageKey::CannotKickFromOngoingGame =>
+            "{player}, das Spiel läuft schon, zu spät, um jemanden rauszuwerfen. (z. B.: `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => "{player}, {kicked} ist nicht mal im Spiel.",
+        MessageKey::WinnerCannotLeave =>
+            ":trophy: {player}, du hast schon gewonnen, bleib da und kassier dein Gold.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            "{player}, __du Genie__, es gibt keinen Tisch. Gegen wen willst du spielen? (z. B.: `g!create blackjack`)",
+        MessageKey::NoHandToPlay => ":unamused: {player}, du hast keine Hand zu spielen. Setz dich erst mal hin.",
+        MessageKey::CannotDouble =>
+            ":no_entry: {player}, verdoppelt wird mit den ersten zwei Karten, nicht mit diesem Chaos.",
+        MessageKey::CannotSplit =>
+            ":no_entry: {player}, das ist kein Paar. Oder du hast schon genug geteilt, maximal 4 Hände.",
+        MessageKey::HouseCannotLeave => ":bank: {player}, die Bank haut nicht mitten in der Hand ab. Sitzen bleiben.",
+        MessageKey::GameAlreadyOver => "{player}, dieses Spiel ist bereits vorbei.",
+        MessageKey::MissingGameId => "{player}, bitte gib eine Spiel-ID an. (z. B.: `g!verify <SPIEL_ID>`)",
+        MessageKey::UnknownGameId => "{player}, kein beendetes Spiel mit der ID `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds =>
+            "{player}, hier gibt's nichts einzustellen, Einstellungen gibt's nur auf Servern.",
+        MessageKey::OnlyGuildManagers =>
+            ":police_officer: {player}, nur wer den Server verwalten darf, fasst seine Einstellungen an.",
+        MessageKey::UnknownConfigKey =>
+            "{player}, das ist keine Einstellung. Nimm eine von {keys}. (z. B.: `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            "{player}, das ist kein gültiger Wert für `{key}`. (z. B.: `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            ":floppy_disk: {player}, die Einstellungen können gerade nicht gespeichert werden, versuch's später nochmal.",
+
+        MessageKey::Started => "Das Spiel beginnt :rocket:! Tippe `g!roll` oder klicke auf **Roll**!",
+        MessageKey::ShowJoinInfo =>
+            "Tippe `g!join` oder klicke auf **Join**, um mitzumachen!\n:lock: Spiel `{game_id}`, Seed-Hash `{seed_hash}`",
+        MessageKey::Done =>
+            "__Wir haben einen Gewinner!__\n{payouts}\n:unlock: Seed `{seed}` (z. B.: `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} schuldet {creditor} **{amount}** Gold.",
+        MessageKey::NoPayouts => ":handshake: Niemand schuldet jemandem etwas. Wie langweilig.",
+        MessageKey::Verified =>
+            ":mag: __Spiel `{game_id}`__\nSeed `{seed}`\nHash `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} hat **{value}** zwischen {min} und {max} gewürfelt {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Jeder Wurf passt zum offengelegten Seed.",
+        MessageKey::VerifiedUnfair => ":x: Dieses Spiel passt **nicht** zu seinem offengelegten Seed.",
+        MessageKey::TieBreak => "Stechen",
+        MessageKey::Dealer => "Geber",
+        MessageKey::PlayerRolled => "{player} hat eine {roll} gewürfelt!",
+        MessageKey::OwedTo => ":money_with_wings: __Schulden bei {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => "Niemand schuldet {creditor} etwas.",
+        MessageKey::OwedBy => ":money_with_wings: __Schulden von {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => "{debtor} schuldet niemandem etwas.",
+        MessageKey::DebtLine => "- {debtor} schuldet {creditor} **{amount}** Gold",
+        MessageKey::Ledger => ":ledger: __Letzte Spiele__\n{outcomes}",
+        MessageKey::LedgerEmpty => "Das Kassenbuch ist leer, geht spielen!",
+        MessageKey::OutcomeLine => "- {time} {creditor} hat **{amount}** Gold von {debtor} gewonnen",
+        MessageKey::PaymentPending =>
+            ":hourglass: {payee}, {payer} sagt, dir **{amount}** Gold gezahlt zu haben. Tippe `g!confirm` oder klicke auf **Confirm**, sobald es da ist.",
+        MessageKey::PaymentsConfirmed =>
+            ":white_check_mark: {payee} hat den Erhalt von **{amount}** Gold von {payer} bestätigt.",
+        MessageKey::Settlement =>
+            ":scales: __Abrechnung__\nDiese Zahlungen begleichen alle Schulden auf dem Server:\n{debts}\n*Nutze `g!paid @spieler <GOLDBETRAG>`, sobald du gezahlt hast.*",
+        MessageKey::SettlementEmpty => ":handshake: Alle sind quitt, nichts zu begleichen.",
+        MessageKey::Stats =>
+            ":bar_chart: __Statistiken von {player}__\n- Gespielte Spiele: **{games}**\n- Siege: **{wins}**\n- Niederlagen: **{losses}**\n- Bilanz: **{net}** Gold\n- Größter Gewinn: **{biggest_win}** Gold\n- Größter Verlust: **{biggest_loss}** Gold",
+        MessageKey::StatsNoGames => "{player} hat noch kein Spiel beendet.",
+        MessageKey::Leaderboard => ":trophy: __Bestenliste {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => "Es wurden {period} keine Spiele beendet.",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** Gold ({wins}S / {losses}N)",
+        MessageKey::PeriodWeek => "diese Woche",
+        MessageKey::PeriodMonth => "diesen Monat",
+        MessageKey::PeriodAll => "aller Zeiten",
+        MessageKey::ShowModes =>
+            ":game_die: __Spielmodi__\n{modes}\n*Erstelle ein Spiel mit `g!create <MODUS> <GOLDBETRAG>`, mehr erfährst du mit `g!modes <MODUS>`.*",
+        MessageKey::ModeLine => "- `{name}` {summary} *({min_players}+ Spieler, {min_wager}+ Gold)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- Mindestens **{min_players}** Spieler und **{min_wager}** Gold.\n*z. B.: `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled =>
+            ":hourglass: Keiner hat das Spiel rechtzeitig gestartet, also ist es abgeblasen.",
+        MessageKey::AutoRolled =>
+            ":hourglass: {player} hat rumgetrödelt, also hab ich gewürfelt: **{roll}**!",
+        MessageKey::AutoPlayed => ":hourglass: {player} hat rumgetrödelt, also bleiben die Hände stehen.",
+        MessageKey::Forfeited => ":hourglass: {player} hat zu lange zum Würfeln gebraucht und gibt auf.",
+        MessageKey::Left => ":door: {player} hat das Spiel verlassen.",
+        MessageKey::LeftAndForfeited => ":chicken: {player} hat mitten im Spiel gekniffen und gibt auf.",
+        MessageKey::Kicked => ":boot: {player} ist aus dem Spiel geflogen.",
+        MessageKey::Cancelled => ":x: Das Spiel wurde abgebrochen.",
+        MessageKey::ShowConfig =>
+            ":gear: __Servereinstellungen__\n{settings}\n*Ändere eine mit `g!config set <SCHLÜSSEL> <WERT>` oder setze sie mit `default` zurück.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => ":gear: `{key}` ist `{value}`.",
+        MessageKey::ConfigUpdated => ":gear: {player} hat `{key}` auf `{value}` gesetzt.",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Die beste Art, Gold in deinem Lieblingsspiel zu verlieren.*
+- `g!create [MODUS] <GOLDBETRAG>`  __Ein Spiel in einem Kanal erstellen__
+- `g!modes [MODUS]` __Die Spielmodi auflisten oder einen erklären__
+- `g!join [SPIEL_ID]` __Einem neuen Spiel beitreten__
+- `g!play [SPIEL_ID]` __Ein neues Spiel starten__
+- `g!roll [SPIEL_ID]` __Würfeln__
+- `g!hit|stand|double|split [SPIEL_ID]` __Deine Blackjack-Hand spielen__
+- `g!leave [SPIEL_ID]` __Das Spiel verlassen und aufgeben, falls es schon begonnen hat__
+- `g!cancel [SPIEL_ID]` __Das Spiel abbrechen (nur Ersteller oder Moderatoren)__
+- `g!kick @spieler [SPIEL_ID]` __Einen Spieler vor Spielbeginn entfernen (nur Ersteller oder Moderatoren)__
+- `g!verify <SPIEL_ID>` __Jeden Wurf eines beendeten Spiels aus seinem offengelegten Seed nachrechnen__
+- `g!help` __Alle Befehle auflisten__
+- `g!info [SPIEL_ID]` __Informationen zum aktuellen Spiel anzeigen__
+- `g!owed` __Auflisten, wer dir Gold schuldet__
+- `g!owes @spieler` __Auflisten, wem ein Spieler Gold schuldet__
+- `g!ledger` __Die letzten Spiele auf diesem Server auflisten__
+- `g!settle` __Die wenigsten Zahlungen auflisten, die alle Schulden auf diesem Server begleichen__
+- `g!stats [@spieler]` __Die Statistiken eines Spielers anzeigen__
+- `g!leaderboard [week|month|all]` __Die besten Spieler des Zeitraums anzeigen__
+- `g!paid @spieler <GOLDBETRAG>` __Einem Spieler mitteilen, dass du ihn bezahlt hast__
+- `g!confirm [@spieler]` __Den Erhalt einer Zahlung bestätigen__
+- `g!config [get|set] [SCHLÜSSEL] [WERT]` __Die Einstellungen dieses Servers anzeigen oder ändern (nur Server verwalten)__
+*Jeder Befehl ist auch als Slash-Befehl verfügbar, z. B. `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary =>
+            "Alle würfeln einmal, der niedrigste Wurf zahlt dem höchsten die Differenz.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Klassisch**
+- Jeder Spieler würfelt zwischen 0 und dem Goldbetrag.
+- Der niedrigste Wurf schuldet dem höchsten die Differenz zwischen beiden Würfen.
+- Spieler mit gleichem höchsten oder niedrigsten Wurf würfeln zum Stechen erneut.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Würfelt abwechselnd unter dem vorherigen Wurf, wer eine 1 würfelt, zahlt den Einsatz.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Die Spieler würfeln in der Reihenfolge ihres Beitritts, beginnend zwischen 1 und dem Goldbetrag.
+- Jeder Wurf liegt zwischen 1 und dem vorherigen Wurf.
+- Wer eine 1 würfelt, schuldet den gesamten Betrag dem Spieler, der davor gewürfelt hat.
+"#,
+        MessageKey::BlackjackSummary => "Spielt eure Hände gegen den Geber, der Ersteller ist die Bank.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- Der Ersteller ist die Bank. Alle anderen bekommen eine Hand um den Goldbetrag.
+- `g!hit` zieht eine Karte, `g!stand` behält die Hand, `g!double` verdoppelt den Einsatz für eine letzte Karte und `g!split` teilt ein Paar in zwei Hände.
+- Sobald alle Hände gespielt sind, zieht der Geber bis 17. Schlag den Geber, ohne über 21 zu kommen, um den Einsatz von der Bank zu gewinnen. Ein Blackjack zahlt 3 zu 2.
+- Die Karten werden mit dem Seed des Spiels aus 6 Decks gezogen.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- Noch niemand ist beigetreten",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Laufendes Spiel!__\nUm **{amount}** Gold!\n\n*Bereits beigetretene Spieler*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Starten des Spiels",
+        MessageKey::InfoClassicOngoing => "Das Spiel läuft!\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} muss noch würfeln! (z. B.: `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll!__\nUm **{amount}** Gold!\n\n*Spieler in Zugreihenfolge*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Starten des Spiels",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Der Death Roll läuft!\n{player}, würfle zwischen **1** und **{max}**! (z. B.: `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack!__\nUm **{amount}** Gold pro Hand, {house} ist die Bank!\n\n*Bereits beigetretene Spieler*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Austeilen der Karten",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack!__\n{house} ist die Bank, **{amount}** Gold pro Hand.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Geber* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** um {amount} Gold {status}",
+        MessageKey::InfoDone => "{debtor} schuldet {creditor} {amount} Gold!",
+        MessageKey::InfoCancelled => "Das Spiel wurde abgebrochen.",
+        MessageKey::InfoRolls => "{info}\n\n*Würfe*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "Spiel von {player}",
+
+        MessageKey::FieldPlayers => "Spieler",
+        MessageKey::FieldRolls => "Würfe",
+        MessageKey::FieldWager => "Einsatz",
+        MessageKey::FieldWinner => "Gewinner",
+        MessageKey::FieldLoser => "Verlierer",
+        MessageKey::FieldDifference => "Differenz",
+        MessageKey::ButtonJoin => "Beitreten",
+        MessageKey::ButtonStart => "Starten",
+        MessageKey::ButtonRoll => "Würfeln",
+        MessageKey::ButtonHit => "Karte",
+        MessageKey::ButtonStand => "Halten",
+        MessageKey::ButtonDouble => "Verdoppeln",
+        MessageKey::ButtonSplit => "Teilen",
+        MessageKey::ButtonInfo => "Info",
+        MessageKey::ButtonConfirm => "Bestätigen",
+    }
+}
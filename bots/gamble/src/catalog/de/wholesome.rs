@@ -0,0 +1,247 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame =>
+            ":sparkles: {player}, es läuft schon ein Spiel, schau doch mal rein! (z. B.: `g!info`)",
+        MessageKey::SeveralGames =>
+            ":sparkles: {player}, hier laufen gerade mehrere Spiele! Gib einfach die ID des Spiels an, das du meinst: {games}",
+        MessageKey::NotEnoughPlayers =>
+            ":people_hugging: {player}, zusammen macht es mehr Spaß! Warten wir auf mindestens {count} Spieler.",
+        MessageKey::GoldAmountTooSmall =>
+            ":coin: {player}, machen wir es spannend! Der Mindesteinsatz beträgt {amount} Gold.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            ":seedling: {player}, es gibt noch kein Spiel, wie wäre es, eins zu erstellen? (z. B.: `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            ":seedling: {player}, es gibt noch kein Spiel zum Anzeigen, wie wäre es, eins zu erstellen? (z. B.: `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            ":seedling: {player}, es gibt noch kein Spiel zum Starten, wie wäre es, eins zu erstellen? (z. B.: `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            ":seedling: {player}, es gibt noch kein Spiel zum Beitreten, wie wäre es, eins zu erstellen? (z. B.: `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            ":hourglass: {player}, dieses Spiel hat schon begonnen, beim nächsten bist du als Erstes dabei!",
+        MessageKey::PlayerAlreadyPartOfGame => ":blush: {player}, gute Nachricht, du bist schon im Spiel!",
+        MessageKey::PlayerCannotRoll => ":relaxed: {player}, nur Geduld, es ist noch nicht Zeit zum Würfeln.",
+        MessageKey::PlayerAlreadyRolled => ":relaxed: {player}, du hast schon gewürfelt, Daumen drücken!",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, du hast den niedrigsten Wurf geteilt. Würfle noch einmal, viel Glück!",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, du hast den höchsten Wurf geteilt. Würfle noch einmal, viel Glück!",
+        MessageKey::NoWinnersFound =>
+            ":thinking: Oje, das Spiel ist vorbei, aber es wurde kein Gewinner gefunden. Tut mir leid!",
+        MessageKey::UnknownCommand =>
+            ":wave: {player}, den kenne ich nicht, aber hier ist alles, was ich kann! (z. B.: `g!help`)",
+        MessageKey::MissingPlayerMention =>
+            ":wave: {player}, an wen denkst du? Erwähne einen Spieler. (z. B.: `g!owes @jemand`)",
+        MessageKey::LedgerOnlyAvailableInGuilds =>
+            ":house: {player}, das Kassenbuch wohnt auf Servern, komm und spiel dort mit deinen Freunden!",
+        MessageKey::LedgerUnavailable =>
+            ":floppy_disk: {player}, das Kassenbuch macht gerade ein Nickerchen, versuche es später erneut.",
+        MessageKey::CannotPayYourself =>
+            ":blush: {player}, du kannst dich nicht selbst bezahlen, aber lieb, dass du daran denkst!",
+        MessageKey::InvalidPaymentAmount =>
+            ":coin: {player}, wie viel hast du gezahlt? (z. B.: `g!paid @jemand 500`)",
+        MessageKey::NothingOwed =>
+            ":tada: {player}, gute Nachricht, du schuldest {payee} nichts (oder die Zahlung wartet schon auf Bestätigung)!",
+        MessageKey::PaymentExceedsDebt =>
+            ":heart: {player}, das ist großzügig, aber du schuldest nur **{amount}** Gold.",
+        MessageKey::NoPendingPayments =>
+            ":sparkles: {player}, alles erledigt, keine Zahlungen zu bestätigen!",
+        MessageKey::UnknownPeriod =>
+            ":calendar: {player}, wähle einen dieser Zeiträume. (z. B.: `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode =>
+            ":game_die: {player}, dieses Spiel kenne ich nicht, hier sind die, die ich kenne! (z. B.: `g!modes`)",
+        MessageKey::PlayerNotPartOfGame =>
+            ":eyes: {player}, du bist in diesem Spiel nicht dabei, aber beim nächsten herzlich willkommen!",
+        MessageKey::NotYourTurn => ":hourglass: {player}, nur Geduld, {current} ist mit Würfeln dran.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame =>
+            ":seedling: {player}, es gibt kein Spiel zum Verlassen, wie wäre es, eins zu erstellen? (z. B.: `g!create`)",
+        MessageKey::PlayerCannotCancelAnInexistentGame =>
+            ":seedling: {player}, es gibt kein Spiel zum Abbrechen.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame => ":seedling: {player}, hier gibt es kein Spiel.",
+        MessageKey::OnlyCreatorOrModerator =>
+            ":shield: {player}, das können nur der Ersteller des Spiels und die Moderatoren.",
+        MessageKey::CannotKickFromOngoingGame =>
+            ":shield: {player}, das Spiel hat begonnen, also spielen alle bis zum Ende. (z. B.: `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => ":eyes: {player}, {kicked} ist nicht in diesem Spiel.",
+        MessageKey::WinnerCannotLeave =>
+            ":trophy: {player}, herzlichen Glückwunsch zum Sieg! Bleib noch, um dein Gold einzusammeln.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            ":seedling: {player}, es gibt noch keinen Tisch, wie wäre es, einen zu eröffnen? (z. B.: `g!create blackjack`)",
+        MessageKey::NoHandToPlay => ":relaxed: {player}, du hast gerade keine Hand zu spielen.",
+        MessageKey::CannotDouble => ":relaxed: {player}, verdoppeln geht nur mit deinen ersten zwei Karten.",
+        MessageKey::CannotSplit => ":relaxed: {player}, nur Paare lassen sich teilen, in höchstens 4 Hände.",
+        MessageKey::HouseCannotLeave =>
+            ":bank: {player}, der Tisch braucht seine Bank! Bleib, bis alle Hände gespielt sind.",
+        MessageKey::GameAlreadyOver => ":checkered_flag: {player}, dieses Spiel ist schon vorbei.",
+        MessageKey::MissingGameId =>
+            ":mag: {player}, welches Spiel soll ich prüfen? (z. B.: `g!verify <SPIEL_ID>`)",
+        MessageKey::UnknownGameId =>
+            ":mag: {player}, ich habe kein beendetes Spiel mit der ID `{game_id}` gefunden.",
+        MessageKey::ConfigOnlyAvailableInGuilds => ":house: {player}, Einstellungen wohnen auf Servern.",
+        MessageKey::OnlyGuildManagers =>
+            ":shield: {player}, nur Mitglieder, die den Server verwalten dürfen, können seine Einstellungen ändern.",
+        MessageKey::UnknownConfigKey =>
+            ":gear: {player}, diese Einstellung kenne ich nicht. Probier eine von {keys}. (z. B.: `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            ":gear: {player}, dieser Wert passt nicht zu `{key}`. (z. B.: `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            ":floppy_disk: {player}, die Einstellungen können gerade nicht gespeichert werden, versuche es später erneut.",
+
+        MessageKey::Started =>
+            "Das Spiel beginnt :rocket:! Tippe `g!roll` oder klicke auf **Roll**, und viel Glück allen!",
+        MessageKey::ShowJoinInfo =>
+            "Tippe `g!join` oder klicke auf **Join**, um bei der Party dabei zu sein!\n:lock: Spiel `{game_id}`, Seed-Hash `{seed_hash}`",
+        MessageKey::Done =>
+            "__Gut gespielt, alle zusammen!__\n{payouts}\n:unlock: Seed `{seed}` (z. B.: `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} schuldet {creditor} **{amount}** Gold.",
+        MessageKey::NoPayouts => ":handshake: Niemand schuldet jemandem etwas, alle sind quitt!",
+        MessageKey::Verified =>
+            ":mag: __Spiel `{game_id}`__\nSeed `{seed}`\nHash `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} hat **{value}** zwischen {min} und {max} gewürfelt {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Jeder Wurf passt zum offengelegten Seed. Alles fair!",
+        MessageKey::VerifiedUnfair => ":x: Dieses Spiel passt **nicht** zu seinem offengelegten Seed.",
+        MessageKey::TieBreak => "Stechen",
+        MessageKey::Dealer => "Geber",
+        MessageKey::PlayerRolled => "{player} hat eine {roll} gewürfelt!",
+        MessageKey::OwedTo => ":money_with_wings: __Schulden bei {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => ":sparkles: Niemand schuldet {creditor} etwas.",
+        MessageKey::OwedBy => ":money_with_wings: __Schulden von {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => ":sparkles: {debtor} schuldet niemandem etwas, großartig!",
+        MessageKey::DebtLine => "- {debtor} schuldet {creditor} **{amount}** Gold",
+        MessageKey::Ledger => ":ledger: __Letzte Spiele__\n{outcomes}",
+        MessageKey::LedgerEmpty => ":ledger: Das Kassenbuch ist leer, Zeit für ein Spiel mit Freunden!",
+        MessageKey::OutcomeLine => "- {time} {creditor} hat **{amount}** Gold von {debtor} gewonnen",
+        MessageKey::PaymentPending =>
+            ":hourglass: {payee}, {payer} sagt, dir **{amount}** Gold gezahlt zu haben. Tippe `g!confirm` oder klicke auf **Confirm**, sobald es angekommen ist.",
+        MessageKey::PaymentsConfirmed =>
+            ":white_check_mark: {payee} hat den Erhalt von **{amount}** Gold von {payer} bestätigt. Danke!",
+        MessageKey::Settlement =>
+            ":scales: __Abrechnung__\nDiese Zahlungen begleichen alle Schulden auf dem Server:\n{debts}\n*Nutze `g!paid @spieler <GOLDBETRAG>`, sobald du gezahlt hast.*",
+        MessageKey::SettlementEmpty => ":handshake: Alle sind quitt, wunderbar!",
+        MessageKey::Stats =>
+            ":bar_chart: __Statistiken von {player}__\n- Gespielte Spiele: **{games}**\n- Siege: **{wins}**\n- Niederlagen: **{losses}**\n- Bilanz: **{net}** Gold\n- Größter Gewinn: **{biggest_win}** Gold\n- Größter Verlust: **{biggest_loss}** Gold",
+        MessageKey::StatsNoGames =>
+            ":seedling: {player} hat noch kein Spiel beendet, das erste ist immer etwas Besonderes!",
+        MessageKey::Leaderboard => ":trophy: __Bestenliste {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => ":seedling: Es wurden {period} noch keine Spiele beendet!",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** Gold ({wins}S / {losses}N)",
+        MessageKey::PeriodWeek => "diese Woche",
+        MessageKey::PeriodMonth => "diesen Monat",
+        MessageKey::PeriodAll => "aller Zeiten",
+        MessageKey::ShowModes =>
+            ":game_die: __Spielmodi__\n{modes}\n*Erstelle ein Spiel mit `g!create <MODUS> <GOLDBETRAG>`, mehr erfährst du mit `g!modes <MODUS>`.*",
+        MessageKey::ModeLine => "- `{name}` {summary} *({min_players}+ Spieler, {min_wager}+ Gold)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- Mindestens **{min_players}** Spieler und **{min_wager}** Gold.\n*z. B.: `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled =>
+            ":hourglass: Das Spiel wurde nicht rechtzeitig gestartet, also habe ich es weggeräumt. Erstellt jederzeit ein neues!",
+        MessageKey::AutoRolled =>
+            ":hourglass: {player} ist wohl beschäftigt, also habe ich gewürfelt: **{roll}**!",
+        MessageKey::AutoPlayed => ":hourglass: {player} ist wohl beschäftigt, also bleiben die Hände stehen.",
+        MessageKey::Forfeited => ":hourglass: {player} ist wohl beschäftigt und setzt dieses Spiel aus.",
+        MessageKey::Left => ":wave: {player} hat das Spiel verlassen. Bis zum nächsten Mal!",
+        MessageKey::LeftAndForfeited =>
+            ":wave: {player} musste mitten im Spiel gehen und gibt auf. Bis zum nächsten Mal!",
+        MessageKey::Kicked => ":wave: {player} wurde aus dem Spiel genommen.",
+        MessageKey::Cancelled => ":leaves: Das Spiel wurde abgebrochen. Erstellt jederzeit ein neues!",
+        MessageKey::ShowConfig =>
+            ":gear: __Servereinstellungen__\n{settings}\n*Ändere eine mit `g!config set <SCHLÜSSEL> <WERT>` oder setze sie mit `default` zurück.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => ":gear: `{key}` ist `{value}`.",
+        MessageKey::ConfigUpdated => ":gear: {player} hat `{key}` auf `{value}` gesetzt. Danke!",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Eine freundliche Art, mit deinen Freunden zu würfeln!*
+- `g!create [MODUS] <GOLDBETRAG>`  __Ein Spiel in einem Kanal erstellen__
+- `g!modes [MODUS]` __Die Spielmodi auflisten oder einen erklären__
+- `g!join [SPIEL_ID]` __Einem neuen Spiel beitreten__
+- `g!play [SPIEL_ID]` __Ein neues Spiel starten__
+- `g!roll [SPIEL_ID]` __Würfeln__
+- `g!hit|stand|double|split [SPIEL_ID]` __Deine Blackjack-Hand spielen__
+- `g!leave [SPIEL_ID]` __Das Spiel verlassen und aufgeben, falls es schon begonnen hat__
+- `g!cancel [SPIEL_ID]` __Das Spiel abbrechen (nur Ersteller oder Moderatoren)__
+- `g!kick @spieler [SPIEL_ID]` __Einen Spieler vor Spielbeginn entfernen (nur Ersteller oder Moderatoren)__
+- `g!verify <SPIEL_ID>` __Jeden Wurf eines beendeten Spiels aus seinem offengelegten Seed nachrechnen__
+- `g!help` __Alle Befehle auflisten__
+- `g!info [SPIEL_ID]` __Informationen zum aktuellen Spiel anzeigen__
+- `g!owed` __Auflisten, wer dir Gold schuldet__
+- `g!owes @spieler` __Auflisten, wem ein Spieler Gold schuldet__
+- `g!ledger` __Die letzten Spiele auf diesem Server auflisten__
+- `g!settle` __Die wenigsten Zahlungen auflisten, die alle Schulden auf diesem Server begleichen__
+- `g!stats [@spieler]` __Die Statistiken eines Spielers anzeigen__
+- `g!leaderboard [week|month|all]` __Die besten Spieler des Zeitraums anzeigen__
+- `g!paid @spieler <GOLDBETRAG>` __Einem Spieler mitteilen, dass du ihn bezahlt hast__
+- `g!confirm [@spieler]` __Den Erhalt einer Zahlung bestätigen__
+- `g!config [get|set] [SCHLÜSSEL] [WERT]` __Die Einstellungen dieses Servers anzeigen oder ändern (nur Server verwalten)__
+*Jeder Befehl ist auch als Slash-Befehl verfügbar, z. B. `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary =>
+            "Alle würfeln einmal, der niedrigste Wurf zahlt dem höchsten die Differenz.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Klassisch**
+- Jeder Spieler würfelt zwischen 0 und dem Goldbetrag.
+- Der niedrigste Wurf schuldet dem höchsten die Differenz zwischen beiden Würfen.
+- Spieler mit gleichem höchsten oder niedrigsten Wurf würfeln zum Stechen erneut.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Würfelt abwechselnd unter dem vorherigen Wurf, wer eine 1 würfelt, zahlt den Einsatz.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Die Spieler würfeln in der Reihenfolge ihres Beitritts, beginnend zwischen 1 und dem Goldbetrag.
+- Jeder Wurf liegt zwischen 1 und dem vorherigen Wurf.
+- Wer eine 1 würfelt, schuldet den gesamten Betrag dem Spieler, der davor gewürfelt hat.
+"#,
+        MessageKey::BlackjackSummary => "Spielt eure Hände gegen den Geber, der Ersteller ist die Bank.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- Der Ersteller ist die Bank. Alle anderen bekommen eine Hand um den Goldbetrag.
+- `g!hit` zieht eine Karte, `g!stand` behält die Hand, `g!double` verdoppelt den Einsatz für eine letzte Karte und `g!split` teilt ein Paar in zwei Hände.
+- Sobald alle Hände gespielt sind, zieht der Geber bis 17. Schlag den Geber, ohne über 21 zu kommen, um den Einsatz von der Bank zu gewinnen. Ein Blackjack zahlt 3 zu 2.
+- Die Karten werden mit dem Seed des Spiels aus 6 Decks gezogen.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- Noch niemand ist beigetreten, sei die erste Person!",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Laufendes Spiel!__\nUm **{amount}** Gold!\n\n*Bereits beigetretene Spieler*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Starten des Spiels",
+        MessageKey::InfoClassicOngoing => "Das Spiel läuft!\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} muss noch würfeln! (z. B.: `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll!__\nUm **{amount}** Gold!\n\n*Spieler in Zugreihenfolge*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Starten des Spiels",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Der Death Roll läuft!\n{player}, würfle zwischen **1** und **{max}**! (z. B.: `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack!__\nUm **{amount}** Gold pro Hand, {house} ist die Bank!\n\n*Bereits beigetretene Spieler*\n{players}\n\n*Nächste Schritte*\n- `g!join` zum Beitreten\n- `g!play` zum Austeilen der Karten",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack!__\n{house} ist die Bank, **{amount}** Gold pro Hand.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Geber* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** um {amount} Gold {status}",
+        MessageKey::InfoDone => "{debtor} schuldet {creditor} {amount} Gold!",
+        MessageKey::InfoCancelled => "Das Spiel wurde abgebrochen.",
+        MessageKey::InfoRolls => "{info}\n\n*Würfe*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "Spiel von {player}",
+
+        MessageKey::FieldPlayers => "Spieler",
+        MessageKey::FieldRolls => "Würfe",
+        MessageKey::FieldWager => "Einsatz",
+        MessageKey::FieldWinner => "Gewinner",
+        MessageKey::FieldLoser => "Verlierer",
+        MessageKey::FieldDifference => "Differenz",
+        MessageKey::ButtonJoin => "Beitreten",
+        MessageKey::ButtonStart => "Starten",
+        MessageKey::ButtonRoll => "Würfeln",
+        MessageKey::ButtonHit => "Karte",
+        MessageKey::ButtonStand => "Halten",
+        MessageKey::ButtonDouble => "Verdoppeln",
+        MessageKey::ButtonSplit => "Teilen",
+        MessageKey::ButtonInfo => "Info",
+        MessageKey::ButtonConfirm => "Bestätigen",
+    }
+}
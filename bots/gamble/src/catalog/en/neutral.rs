@@ -0,0 +1,215 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame => "{player}, there is already an ongoing game. (i.e.: `g!info`)",
+        MessageKey::SeveralGames =>
+            "{player}, there are several games here. Add the id of the one you mean to the command: {games}",
+        MessageKey::NotEnoughPlayers => "{player}, the game needs at least {count} players.",
+        MessageKey::GoldAmountTooSmall => "{player}, the minimum wager is {amount} gold.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            "{player}, there is no game to roll in. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            "{player}, there is no game to show. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            "{player}, there is no game to start. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            "{player}, there is no game to join. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            "{player}, this game has already started. You can join the next one.",
+        MessageKey::PlayerAlreadyPartOfGame => "{player}, you already joined this game.",
+        MessageKey::PlayerCannotRoll => "{player}, you can't roll right now.",
+        MessageKey::PlayerAlreadyRolled => "{player}, you already rolled this round.",
+        MessageKey::PlayersMatchedLowestRoll => "{player}, you matched the lowest roll. Please reroll.",
+        MessageKey::PlayersMatchedHighestRoll => "{player}, you matched the highest roll. Please reroll.",
+        MessageKey::NoWinnersFound => "The game is over, but no winner could be determined.",
+        MessageKey::UnknownCommand => "{player}, that command doesn't exist. (i.e.: `g!help`)",
+        MessageKey::MissingPlayerMention => "{player}, please mention a player. (i.e.: `g!owes @someone`)",
+        MessageKey::LedgerOnlyAvailableInGuilds => "{player}, the ledger is only available in servers.",
+        MessageKey::LedgerUnavailable => "{player}, the ledger is unavailable right now, try again later.",
+        MessageKey::CannotPayYourself => "{player}, you can't pay yourself.",
+        MessageKey::InvalidPaymentAmount =>
+            "{player}, please give the amount you paid. (i.e.: `g!paid @someone 500`)",
+        MessageKey::NothingOwed =>
+            "{player}, you don't owe {payee} anything (or it's already waiting on confirmation).",
+        MessageKey::PaymentExceedsDebt => "{player}, that's more than you owe. You owe **{amount}** gold.",
+        MessageKey::NoPendingPayments => "{player}, you have no payments to confirm.",
+        MessageKey::UnknownPeriod => "{player}, unknown period. (i.e.: `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode => "{player}, unknown game mode. (i.e.: `g!modes`)",
+        MessageKey::PlayerNotPartOfGame => "{player}, you're not part of this game.",
+        MessageKey::NotYourTurn => "{player}, it's {current}'s turn to roll.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame => "{player}, there is no game to leave.",
+        MessageKey::PlayerCannotCancelAnInexistentGame => "{player}, there is no game to cancel.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame => "{player}, there is no game to kick anyone from.",
+        MessageKey::OnlyCreatorOrModerator => "{player}, only the game's creator or a moderator can do that.",
+        MessageKey::CannotKickFromOngoingGame =>
+            "{player}, players can't be kicked once the game has started. (i.e.: `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => "{player}, {kicked} is not part of this game.",
+        MessageKey::WinnerCannotLeave => "{player}, you already won and can't leave the game.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            "{player}, there is no game to play a hand in. (i.e.: `g!create blackjack`)",
+        MessageKey::NoHandToPlay => "{player}, you have no hand to play right now.",
+        MessageKey::CannotDouble => "{player}, you can only double down on your first two cards.",
+        MessageKey::CannotSplit => "{player}, you can only split a pair, into at most 4 hands.",
+        MessageKey::HouseCannotLeave => "{player}, you're the house and can't leave while hands are being played.",
+        MessageKey::GameAlreadyOver => "{player}, this game is already over.",
+        MessageKey::MissingGameId => "{player}, please give a game id. (i.e.: `g!verify <GAME_ID>`)",
+        MessageKey::UnknownGameId => "{player}, no finished game with id `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds => "{player}, settings are only available in servers.",
+        MessageKey::OnlyGuildManagers => "{player}, only members who can manage the server can change its settings.",
+        MessageKey::UnknownConfigKey =>
+            "{player}, unknown setting. Available settings: {keys}. (i.e.: `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            "{player}, invalid value for `{key}`. (i.e.: `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable => "{player}, the settings can't be saved right now, try again later.",
+
+        MessageKey::Started => "The game has started. Type `g!roll` or click **Roll**.",
+        MessageKey::ShowJoinInfo =>
+            "Type `g!join` or click **Join** to join the game.\n:lock: Game `{game_id}`, seed hash `{seed_hash}`",
+        MessageKey::Done =>
+            "__The game is over.__\n{payouts}\n:unlock: Seed `{seed}` (i.e.: `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} owes {creditor} **{amount}** gold.",
+        MessageKey::NoPayouts => ":handshake: Nobody owes anybody anything.",
+        MessageKey::Verified =>
+            ":mag: __Game `{game_id}`__\nSeed `{seed}`\nHash `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} rolled **{value}** between {min} and {max} {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Every roll matches the revealed seed.",
+        MessageKey::VerifiedUnfair => ":x: This game does **not** match its revealed seed.",
+        MessageKey::TieBreak => "Tie-break",
+        MessageKey::Dealer => "Dealer",
+        MessageKey::PlayerRolled => "{player} rolled a {roll}.",
+        MessageKey::OwedTo => "__Owed to {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => "Nobody owes {creditor} anything.",
+        MessageKey::OwedBy => "__Owed by {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => "{debtor} doesn't owe anybody anything.",
+        MessageKey::DebtLine => "- {debtor} owes {creditor} **{amount}** gold",
+        MessageKey::Ledger => "__Latest games__\n{outcomes}",
+        MessageKey::LedgerEmpty => "No games have been recorded yet.",
+        MessageKey::OutcomeLine => "- {time} {creditor} won **{amount}** gold from {debtor}",
+        MessageKey::PaymentPending =>
+            "{payee}, {payer} says they paid you **{amount}** gold. Type `g!confirm` or click **Confirm** once you've received it.",
+        MessageKey::PaymentsConfirmed => "{payee} confirmed receiving **{amount}** gold from {payer}.",
+        MessageKey::Settlement =>
+            "__Settle up__\nThese payments clear every debt in the server:\n{debts}\n*Use `g!paid @user <GOLD_AMOUNT>` once you've paid.*",
+        MessageKey::SettlementEmpty => "There are no debts to settle.",
+        MessageKey::Stats =>
+            "__Stats for {player}__\n- Games played: **{games}**\n- Wins: **{wins}**\n- Losses: **{losses}**\n- Net: **{net}** gold\n- Biggest win: **{biggest_win}** gold\n- Biggest loss: **{biggest_loss}** gold",
+        MessageKey::StatsNoGames => "{player} hasn't finished a game yet.",
+        MessageKey::Leaderboard => "__Leaderboard {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => "No games were finished {period}.",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** gold ({wins}W / {losses}L)",
+        MessageKey::PeriodWeek => "this week",
+        MessageKey::PeriodMonth => "this month",
+        MessageKey::PeriodAll => "of all time",
+        MessageKey::ShowModes =>
+            "__Game modes__\n{modes}\n*Create one with `g!create <MODE> <GOLD_AMOUNT>`, learn more with `g!modes <MODE>`.*",
+        MessageKey::ModeLine => "- `{name}` {summary} *({min_players}+ players, {min_wager}+ gold)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- At least **{min_players}** players and **{min_wager}** gold.\n*i.e.: `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled => "The game was cancelled because nobody started it in time.",
+        MessageKey::AutoRolled => "{player} didn't roll in time and was rolled for: **{roll}**.",
+        MessageKey::AutoPlayed => "{player} didn't play in time, so their hands stand.",
+        MessageKey::Forfeited => "{player} didn't roll in time and forfeits.",
+        MessageKey::Left => "{player} left the game.",
+        MessageKey::LeftAndForfeited => "{player} left the game and forfeits.",
+        MessageKey::Kicked => "{player} was removed from the game.",
+        MessageKey::Cancelled => "The game was cancelled.",
+        MessageKey::ShowConfig =>
+            "__Server settings__\n{settings}\n*Change one with `g!config set <KEY> <VALUE>`, or reset it with `default`.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => "`{key}` is `{value}`.",
+        MessageKey::ConfigUpdated => "{player} set `{key}` to `{value}`.",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Roll dice against other players for gold.*
+- `g!create [MODE] <GOLD_AMOUNT>`  __Create a game in a channel__
+- `g!modes [MODE]` __List the game modes, or explain one__
+- `g!join [GAME_ID]` __Join a new game__
+- `g!play [GAME_ID]` __Start a new game__
+- `g!roll [GAME_ID]` __Roll__
+- `g!hit|stand|double|split [GAME_ID]` __Play your blackjack hand__
+- `g!leave [GAME_ID]` __Leave the game, forfeiting if it already started__
+- `g!cancel [GAME_ID]` __Cancel the game (creator or moderators only)__
+- `g!kick @user [GAME_ID]` __Remove a player before the game starts (creator or moderators only)__
+- `g!verify <GAME_ID>` __Recompute every roll of a finished game from its revealed seed__
+- `g!help` __List all available commands__
+- `g!info [GAME_ID]` __List information about the current game__
+- `g!owed` __List who owes you gold__
+- `g!owes @user` __List who a player owes gold to__
+- `g!ledger` __List the latest games played in this server__
+- `g!settle` __List the fewest payments that settle every debt in this server__
+- `g!stats [@user]` __Show a player's statistics__
+- `g!leaderboard [week|month|all]` __Show the best players of the period__
+- `g!paid @user <GOLD_AMOUNT>` __Tell a player you paid them back__
+- `g!confirm [@user]` __Confirm you received a payment__
+- `g!config [get|set] [KEY] [VALUE]` __Show or change this server's settings (Manage Server only)__
+*Every command is also available as a slash command, e.g. `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary => "Everybody rolls once, the lowest roll pays the highest the difference.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Classic**
+- Every player rolls between 0 and the amount of gold.
+- The lowest roll owes the highest roll the difference between both rolls.
+- Players tied for the highest or lowest roll reroll to break the tie.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Take turns rolling below the previous roll, whoever rolls 1 pays the wager.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Players roll in the order they joined, starting between 1 and the amount of gold.
+- Each roll is between 1 and the previous roll.
+- Whoever rolls a 1 owes the whole amount to the player who rolled before them.
+"#,
+        MessageKey::BlackjackSummary => "Play your hands against the dealer, the creator banks every hand.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- The creator is the house and banks every hand. Everybody else gets dealt a hand worth the amount of gold.
+- `g!hit` draws a card, `g!stand` keeps the hand, `g!double` doubles the wager for one last card and `g!split` splits a pair into two hands.
+- Once every hand is played, the dealer draws to 17. Beat the dealer without going over 21 to win the wager from the house, a blackjack pays 3 to 2.
+- Cards are drawn from 6 decks with the game's seed.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- No players have joined yet",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Ongoing Game!__\nFor **{amount}** gold!\n\n*Players who have already joined*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
+        MessageKey::InfoClassicOngoing => "Game is ongoing!\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} still needs to roll! (i.e.: `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll!__\nFor **{amount}** gold!\n\n*Players in turn order*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Death roll is ongoing!\n{player}, roll between **1** and **{max}**! (i.e.: `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack!__\nFor **{amount}** gold a hand, {house} is the house!\n\n*Players who have already joined*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to deal the cards",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack!__\n{house} is the house, **{amount}** gold a hand.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Dealer* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** for {amount} gold {status}",
+        MessageKey::InfoDone => "{debtor} owes {creditor} {amount} gold!",
+        MessageKey::InfoCancelled => "The game was cancelled.",
+        MessageKey::InfoRolls => "{info}\n\n*Rolls*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "{player}'s game",
+
+        MessageKey::FieldPlayers => "Players",
+        MessageKey::FieldRolls => "Rolls",
+        MessageKey::FieldWager => "Wager",
+        MessageKey::FieldWinner => "Winner",
+        MessageKey::FieldLoser => "Loser",
+        MessageKey::FieldDifference => "Difference",
+        MessageKey::ButtonJoin => "Join",
+        MessageKey::ButtonStart => "Start",
+        MessageKey::ButtonRoll => "Roll",
+        MessageKey::ButtonHit => "Hit",
+        MessageKey::ButtonStand => "Stand",
+        MessageKey::ButtonDouble => "Double",
+        MessageKey::ButtonSplit => "Split",
+        MessageKey::ButtonInfo => "Info",
+        MessageKey::ButtonConfirm => "Confirm",
+    }
+}
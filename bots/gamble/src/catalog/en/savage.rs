@@ -0,0 +1,231 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame =>
+            ":man_facepalming: {player}, bro there is already an ongoing game. (i.e.: `g!info`)",
+        MessageKey::SeveralGames =>
+            "{player}, there's more than one game here, genius. Add the id of yours to the command: {games}",
+        MessageKey::NotEnoughPlayers => ":upside_down: {player}, there needs to be at least {count} players.",
+        MessageKey::GoldAmountTooSmall =>
+            ":pinched_fingers: {player}, what are you broke? Gamble at least {amount} gold.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            "{player}, __you absolute dipshit__, what are you rolling for? (i.e.: `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            "{player}, __you're a lost cause__, you gotta create a game first before requesting info. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            "{player}, __you absolute mongoloid__, you gotta create a game first before playing. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            "{player}, __you absolute donut__, you gotta create a game first before joining one. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            ":weary: {player}, let the game end first and then join the next one.",
+        MessageKey::PlayerAlreadyPartOfGame => ":zany_face: {player}, you're already part of the game dipshit.",
+        MessageKey::PlayerCannotRoll => ":unamused: {player}, it's not the right time to roll.",
+        MessageKey::PlayerAlreadyRolled =>
+            ":expresionless: {player}, you think rolling twice is going to help your cause?",
+        MessageKey::PlayersMatchedLowestRoll => "{player}, you matched the lowest roll. Please reroll.",
+        MessageKey::PlayersMatchedHighestRoll => "{player}, you matched the highest roll. Please reroll.",
+        MessageKey::NoWinnersFound => ":thinking: wtf, no winners were found.. but the game is done? Holy fuck.",
+        MessageKey::UnknownCommand => "{player}, is this your first time? (i.e.: `g!help`)",
+        MessageKey::MissingPlayerMention =>
+            "{player}, who are you talking about? Mention a player. (i.e.: `g!owes @someone`)",
+        MessageKey::LedgerOnlyAvailableInGuilds =>
+            "{player}, the ledger only exists in servers, there's nobody to owe in here.",
+        MessageKey::LedgerUnavailable =>
+            ":floppy_disk: {player}, the ledger is unavailable right now, try again later.",
+        MessageKey::CannotPayYourself => ":clown: {player}, paying yourself back? Nice try.",
+        MessageKey::InvalidPaymentAmount => "{player}, how much did you pay? (i.e.: `g!paid @someone 500`)",
+        MessageKey::NothingOwed =>
+            ":face_with_raised_eyebrow: {player}, you don't owe {payee} anything (or it's already waiting on confirmation).",
+        MessageKey::PaymentExceedsDebt =>
+            ":money_mouth: {player}, that's more than you owe. You only owe **{amount}** gold.",
+        MessageKey::NoPendingPayments => "{player}, nobody is waiting on you to confirm a payment.",
+        MessageKey::UnknownPeriod =>
+            "{player}, pick a period that exists. (i.e.: `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode => "{player}, never heard of that game. (i.e.: `g!modes`)",
+        MessageKey::PlayerNotPartOfGame => ":eyes: {player}, you're not even playing. Wait for the next one.",
+        MessageKey::NotYourTurn => ":hand_splayed: {player}, wait your turn, it's {current}'s roll.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame =>
+            "{player}, leave what? There's no game here. (i.e.: `g!create`)",
+        MessageKey::PlayerCannotCancelAnInexistentGame => "{player}, there's nothing to cancel, __genius__.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame =>
+            "{player}, kick them from what? There's no game here.",
+        MessageKey::OnlyCreatorOrModerator =>
+            ":police_officer: {player}, only whoever created the game or a moderator can do that.",
+        MessageKey::CannotKickFromOngoingGame =>
+            "{player}, the game already started, too late to kick anybody. (i.e.: `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => "{player}, {kicked} isn't even in the game.",
+        MessageKey::WinnerCannotLeave =>
+            ":trophy: {player}, you already won, stick around and collect your gold.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            "{player}, __genius__, there's no table. Who are you playing against? (i.e.: `g!create blackjack`)",
+        MessageKey::NoHandToPlay => ":unamused: {player}, you don't have a hand to play. Sit down first.",
+        MessageKey::CannotDouble =>
+            ":no_entry: {player}, doubling down is for your first two cards, not whatever that is.",
+        MessageKey::CannotSplit =>
+            ":no_entry: {player}, that's not a pair. Or you've split enough already, 4 hands max.",
+        MessageKey::HouseCannotLeave => ":bank: {player}, the house doesn't get to run off mid-hand. Sit tight.",
+        MessageKey::GameAlreadyOver => "{player}, this game is already over.",
+        MessageKey::MissingGameId => "{player}, which game? (i.e.: `g!verify <GAME_ID>`)",
+        MessageKey::UnknownGameId => "{player}, no finished game with id `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds =>
+            "{player}, there's nothing to configure in here, settings only exist in servers.",
+        MessageKey::OnlyGuildManagers =>
+            ":police_officer: {player}, only members who can manage the server can touch its settings.",
+        MessageKey::UnknownConfigKey =>
+            "{player}, that's not a setting. Pick one of {keys}. (i.e.: `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            "{player}, that's not a valid `{key}`. (i.e.: `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            ":floppy_disk: {player}, the settings can't be saved right now, try again later.",
+
+        MessageKey::Started => "Game started :rocket:! Type `g!roll` or click **Roll**!",
+        MessageKey::ShowJoinInfo =>
+            "Type `g!join` or click **Join** to join the game!\n:lock: Game `{game_id}`, seed hash `{seed_hash}`",
+        MessageKey::Done =>
+            "__A winner has emerged!__\n{payouts}\n:unlock: Seed `{seed}` (i.e.: `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} owes {creditor} **{amount}** gold.",
+        MessageKey::NoPayouts => ":handshake: Nobody owes anybody anything. How boring.",
+        MessageKey::Verified =>
+            ":mag: __Game `{game_id}`__\nSeed `{seed}`\nHash `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} rolled **{value}** between {min} and {max} {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Every roll matches the revealed seed.",
+        MessageKey::VerifiedUnfair => ":x: This game does **not** match its revealed seed.",
+        MessageKey::TieBreak => "Tie-break",
+        MessageKey::Dealer => "Dealer",
+        MessageKey::PlayerRolled => "{player} rolled a {roll}!",
+        MessageKey::OwedTo => ":money_with_wings: __Owed to {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => "Nobody owes {creditor} anything.",
+        MessageKey::OwedBy => ":money_with_wings: __Owed by {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => "{debtor} doesn't owe anybody anything.",
+        MessageKey::DebtLine => "- {debtor} owes {creditor} **{amount}** gold",
+        MessageKey::Ledger => ":ledger: __Latest games__\n{outcomes}",
+        MessageKey::LedgerEmpty => "The ledger is empty, go gamble!",
+        MessageKey::OutcomeLine => "- {time} {creditor} won **{amount}** gold from {debtor}",
+        MessageKey::PaymentPending =>
+            ":hourglass: {payee}, {payer} says they paid you **{amount}** gold. Type `g!confirm` or click **Confirm** once you've received it.",
+        MessageKey::PaymentsConfirmed => ":white_check_mark: {payee} confirmed receiving **{amount}** gold from {payer}.",
+        MessageKey::Settlement =>
+            ":scales: __Settle up__\nThese payments clear every debt in the server:\n{debts}\n*Use `g!paid @user <GOLD_AMOUNT>` once you've paid.*",
+        MessageKey::SettlementEmpty => ":handshake: Everybody is square, nothing to settle.",
+        MessageKey::Stats =>
+            ":bar_chart: __Stats for {player}__\n- Games played: **{games}**\n- Wins: **{wins}**\n- Losses: **{losses}**\n- Net: **{net}** gold\n- Biggest win: **{biggest_win}** gold\n- Biggest loss: **{biggest_loss}** gold",
+        MessageKey::StatsNoGames => "{player} hasn't finished a game yet.",
+        MessageKey::Leaderboard => ":trophy: __Leaderboard {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => "No games were finished {period}.",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** gold ({wins}W / {losses}L)",
+        MessageKey::PeriodWeek => "this week",
+        MessageKey::PeriodMonth => "this month",
+        MessageKey::PeriodAll => "of all time",
+        MessageKey::ShowModes =>
+            ":game_die: __Game modes__\n{modes}\n*Create one with `g!create <MODE> <GOLD_AMOUNT>`, learn more with `g!modes <MODE>`.*",
+        MessageKey::ModeLine => "- `{name}` {summary} *({min_players}+ players, {min_wager}+ gold)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- At least **{min_players}** players and **{min_wager}** gold.\n*i.e.: `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled => ":hourglass: Nobody started the game in time, so it was cancelled.",
+        MessageKey::AutoRolled => ":hourglass: {player} took too long, so I rolled for them: **{roll}**!",
+        MessageKey::AutoPlayed => ":hourglass: {player} took too long, so I stood their hands for them.",
+        MessageKey::Forfeited => ":hourglass: {player} took too long to roll and forfeits.",
+        MessageKey::Left => ":door: {player} left the game.",
+        MessageKey::LeftAndForfeited => ":chicken: {player} chickened out mid-game and forfeits.",
+        MessageKey::Kicked => ":boot: {player} got kicked from the game.",
+        MessageKey::Cancelled => ":x: The game was cancelled.",
+        MessageKey::ShowConfig =>
+            ":gear: __Server settings__\n{settings}\n*Change one with `g!config set <KEY> <VALUE>`, or reset it with `default`.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => ":gear: `{key}` is `{value}`.",
+        MessageKey::ConfigUpdated => ":gear: {player} set `{key}` to `{value}`.",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Great way to lose gold in your favorite game.*
+- `g!create [MODE] <GOLD_AMOUNT>`  __Create a game in a channel__
+- `g!modes [MODE]` __List the game modes, or explain one__
+- `g!join [GAME_ID]` __Join a new game__
+- `g!play [GAME_ID]` __Start a new game__
+- `g!roll [GAME_ID]` __Roll__
+- `g!hit|stand|double|split [GAME_ID]` __Play your blackjack hand__
+- `g!leave [GAME_ID]` __Leave the game, forfeiting if it already started__
+- `g!cancel [GAME_ID]` __Cancel the game (creator or moderators only)__
+- `g!kick @user [GAME_ID]` __Remove a player before the game starts (creator or moderators only)__
+- `g!verify <GAME_ID>` __Recompute every roll of a finished game from its revealed seed__
+- `g!help` __List all available commands__
+- `g!info [GAME_ID]` __List information about the current game__
+- `g!owed` __List who owes you gold__
+- `g!owes @user` __List who a player owes gold to__
+- `g!ledger` __List the latest games played in this server__
+- `g!settle` __List the fewest payments that settle every debt in this server__
+- `g!stats [@user]` __Show a player's statistics__
+- `g!leaderboard [week|month|all]` __Show the best players of the period__
+- `g!paid @user <GOLD_AMOUNT>` __Tell a player you paid them back__
+- `g!confirm [@user]` __Confirm you received a payment__
+- `g!config [get|set] [KEY] [VALUE]` __Show or change this server's settings (Manage Server only)__
+*Every command is also available as a slash command, e.g. `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary => "Everybody rolls once, the lowest roll pays the highest the difference.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Classic**
+- Every player rolls between 0 and the amount of gold.
+- The lowest roll owes the highest roll the difference between both rolls.
+- Players tied for the highest or lowest roll reroll to break the tie.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Take turns rolling below the previous roll, whoever rolls 1 pays the wager.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Players roll in the order they joined, starting between 1 and the amount of gold.
+- Each roll is between 1 and the previous roll.
+- Whoever rolls a 1 owes the whole amount to the player who rolled before them.
+"#,
+        MessageKey::BlackjackSummary => "Play your hands against the dealer, the creator banks every hand.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- The creator is the house and banks every hand. Everybody else gets dealt a hand worth the amount of gold.
+- `g!hit` draws a card, `g!stand` keeps the hand, `g!double` doubles the wager for one last card and `g!split` splits a pair into two hands.
+- Once every hand is played, the dealer draws to 17. Beat the dealer without going over 21 to win the wager from the house, a blackjack pays 3 to 2.
+- Cards are drawn from 6 decks with the game's seed.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- No players have joined yet",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Ongoing Game!__\nFor **{amount}** gold!\n\n*Players who have already joined*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
+        MessageKey::InfoClassicOngoing => "Game is ongoing!\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} still needs to roll! (i.e.: `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll!__\nFor **{amount}** gold!\n\n*Players in turn order*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Death roll is ongoing!\n{player}, roll between **1** and **{max}**! (i.e.: `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack!__\nFor **{amount}** gold a hand, {house} is the house!\n\n*Players who have already joined*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to deal the cards",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack!__\n{house} is the house, **{amount}** gold a hand.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Dealer* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** for {amount} gold {status}",
+        MessageKey::InfoDone => "{debtor} owes {creditor} {amount} gold!",
+        MessageKey::InfoCancelled => "The game was cancelled.",
+        MessageKey::InfoRolls => "{info}\n\n*Rolls*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "{player}'s game",
+
+        MessageKey::FieldPlayers => "Players",
+        MessageKey::FieldRolls => "Rolls",
+        MessageKey::FieldWager => "Wager",
+        MessageKey::FieldWinner => "Winner",
+        MessageKey::FieldLoser => "Loser",
+        MessageKey::FieldDifference => "Difference",
+        MessageKey::ButtonJoin => "Join",
+        MessageKey::ButtonStart => "Start",
+        MessageKey::ButtonRoll => "Roll",
+        MessageKey::ButtonHit => "Hit",
+        MessageKey::ButtonStand => "Stand",
+        MessageKey::ButtonDouble => "Double",
+        MessageKey::ButtonSplit => "Split",
+        MessageKey::ButtonInfo => "Info",
+        MessageKey::ButtonConfirm => "Confirm",
+    }
+}
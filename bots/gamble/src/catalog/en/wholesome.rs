@@ -0,0 +1,238 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame =>
+            ":sparkles: {player}, there's already a game going on, come have a look! (i.e.: `g!info`)",
+        MessageKey::SeveralGames =>
+            ":sparkles: {player}, there are a few games going on here! Just add the id of the one you mean: {games}",
+        MessageKey::NotEnoughPlayers =>
+            ":people_hugging: {player}, it's more fun with friends! Let's wait for at least {count} players.",
+        MessageKey::GoldAmountTooSmall =>
+            ":coin: {player}, let's make it exciting! The smallest wager is {amount} gold.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            ":seedling: {player}, there's no game to roll in yet, why not start one? (i.e.: `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            ":seedling: {player}, there's no game to show yet, why not start one? (i.e.: `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            ":seedling: {player}, there's no game to start yet, why not create one? (i.e.: `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            ":seedling: {player}, there's no game to join yet, why not create one? (i.e.: `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            ":hourglass: {player}, this game is already underway, you'll be first in line for the next one!",
+        MessageKey::PlayerAlreadyPartOfGame => ":blush: {player}, good news, you're already in the game!",
+        MessageKey::PlayerCannotRoll => ":relaxed: {player}, hold that thought, it's not time to roll just yet.",
+        MessageKey::PlayerAlreadyRolled => ":relaxed: {player}, you've already rolled, fingers crossed!",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, you matched the lowest roll. Please reroll, good luck!",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, you matched the highest roll. Please reroll, good luck!",
+        MessageKey::NoWinnersFound =>
+            ":thinking: Oh dear, the game is over but no winner could be found. Sorry about that!",
+        MessageKey::UnknownCommand =>
+            ":wave: {player}, I don't know that one, but here's everything I can do! (i.e.: `g!help`)",
+        MessageKey::MissingPlayerMention =>
+            ":wave: {player}, who did you have in mind? Mention a player. (i.e.: `g!owes @someone`)",
+        MessageKey::LedgerOnlyAvailableInGuilds =>
+            ":house: {player}, the ledger lives in servers, come play with friends there!",
+        MessageKey::LedgerUnavailable =>
+            ":floppy_disk: {player}, the ledger is taking a little nap, please try again later.",
+        MessageKey::CannotPayYourself => ":blush: {player}, you can't pay yourself back, but nice thought!",
+        MessageKey::InvalidPaymentAmount =>
+            ":coin: {player}, how much did you pay? (i.e.: `g!paid @someone 500`)",
+        MessageKey::NothingOwed =>
+            ":tada: {player}, good news, you don't owe {payee} anything (or it's already waiting on confirmation)!",
+        MessageKey::PaymentExceedsDebt =>
+            ":heart: {player}, that's generous, but you only owe **{amount}** gold.",
+        MessageKey::NoPendingPayments => ":sparkles: {player}, you're all caught up, no payments to confirm!",
+        MessageKey::UnknownPeriod =>
+            ":calendar: {player}, pick one of these periods. (i.e.: `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode =>
+            ":game_die: {player}, I don't know that game, here are the ones I know! (i.e.: `g!modes`)",
+        MessageKey::PlayerNotPartOfGame =>
+            ":eyes: {player}, you're not in this game, but you're welcome in the next one!",
+        MessageKey::NotYourTurn => ":hourglass: {player}, hang tight, it's {current}'s turn to roll.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame =>
+            ":seedling: {player}, there's no game to leave, why not start one? (i.e.: `g!create`)",
+        MessageKey::PlayerCannotCancelAnInexistentGame => ":seedling: {player}, there's no game to cancel.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame => ":seedling: {player}, there's no game here.",
+        MessageKey::OnlyCreatorOrModerator =>
+            ":shield: {player}, only the game's creator or a moderator can do that.",
+        MessageKey::CannotKickFromOngoingGame =>
+            ":shield: {player}, the game has already started, so everyone gets to finish it. (i.e.: `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => ":eyes: {player}, {kicked} isn't in this game.",
+        MessageKey::WinnerCannotLeave =>
+            ":trophy: {player}, congratulations on winning! Stick around to collect your gold.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            ":seedling: {player}, there's no table yet, why not open one? (i.e.: `g!create blackjack`)",
+        MessageKey::NoHandToPlay => ":relaxed: {player}, you don't have a hand to play right now.",
+        MessageKey::CannotDouble => ":relaxed: {player}, doubling down only works on your first two cards.",
+        MessageKey::CannotSplit => ":relaxed: {player}, only pairs can be split, into 4 hands at most.",
+        MessageKey::HouseCannotLeave => ":bank: {player}, the table needs its house! Stay until every hand is played.",
+        MessageKey::GameAlreadyOver => ":checkered_flag: {player}, this game is already over.",
+        MessageKey::MissingGameId =>
+            ":mag: {player}, which game should I check? (i.e.: `g!verify <GAME_ID>`)",
+        MessageKey::UnknownGameId => ":mag: {player}, I couldn't find a finished game with id `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds => ":house: {player}, settings live in servers.",
+        MessageKey::OnlyGuildManagers =>
+            ":shield: {player}, only members who can manage the server can change its settings.",
+        MessageKey::UnknownConfigKey =>
+            ":gear: {player}, I don't know that setting. Try one of {keys}. (i.e.: `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            ":gear: {player}, that value doesn't fit `{key}`. (i.e.: `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            ":floppy_disk: {player}, the settings can't be saved right now, please try again later.",
+
+        MessageKey::Started => "Game started :rocket:! Type `g!roll` or click **Roll**, and good luck everyone!",
+        MessageKey::ShowJoinInfo =>
+            "Type `g!join` or click **Join** to join the fun!\n:lock: Game `{game_id}`, seed hash `{seed_hash}`",
+        MessageKey::Done =>
+            "__Good game everyone!__\n{payouts}\n:unlock: Seed `{seed}` (i.e.: `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} owes {creditor} **{amount}** gold.",
+        MessageKey::NoPayouts => ":handshake: Nobody owes anybody anything, everyone's square!",
+        MessageKey::Verified =>
+            ":mag: __Game `{game_id}`__\nSeed `{seed}`\nHash `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} rolled **{value}** between {min} and {max} {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Every roll matches the revealed seed. All fair and square!",
+        MessageKey::VerifiedUnfair => ":x: This game does **not** match its revealed seed.",
+        MessageKey::TieBreak => "Tie-break",
+        MessageKey::Dealer => "Dealer",
+        MessageKey::PlayerRolled => "{player} rolled a {roll}!",
+        MessageKey::OwedTo => ":money_with_wings: __Owed to {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => ":sparkles: Nobody owes {creditor} anything.",
+        MessageKey::OwedBy => ":money_with_wings: __Owed by {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => ":sparkles: {debtor} doesn't owe anybody anything, what a hero!",
+        MessageKey::DebtLine => "- {debtor} owes {creditor} **{amount}** gold",
+        MessageKey::Ledger => ":ledger: __Latest games__\n{outcomes}",
+        MessageKey::LedgerEmpty => ":ledger: The ledger is empty, time for a friendly game!",
+        MessageKey::OutcomeLine => "- {time} {creditor} won **{amount}** gold from {debtor}",
+        MessageKey::PaymentPending =>
+            ":hourglass: {payee}, {payer} says they paid you **{amount}** gold. Type `g!confirm` or click **Confirm** once you've received it.",
+        MessageKey::PaymentsConfirmed =>
+            ":white_check_mark: {payee} confirmed receiving **{amount}** gold from {payer}. Thank you!",
+        MessageKey::Settlement =>
+            ":scales: __Settle up__\nThese payments clear every debt in the server:\n{debts}\n*Use `g!paid @user <GOLD_AMOUNT>` once you've paid.*",
+        MessageKey::SettlementEmpty => ":handshake: Everybody is square, lovely!",
+        MessageKey::Stats =>
+            ":bar_chart: __Stats for {player}__\n- Games played: **{games}**\n- Wins: **{wins}**\n- Losses: **{losses}**\n- Net: **{net}** gold\n- Biggest win: **{biggest_win}** gold\n- Biggest loss: **{biggest_loss}** gold",
+        MessageKey::StatsNoGames => ":seedling: {player} hasn't finished a game yet, the first one is always special!",
+        MessageKey::Leaderboard => ":trophy: __Leaderboard {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => ":seedling: No games were finished {period}, yet!",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** gold ({wins}W / {losses}L)",
+        MessageKey::PeriodWeek => "this week",
+        MessageKey::PeriodMonth => "this month",
+        MessageKey::PeriodAll => "of all time",
+        MessageKey::ShowModes =>
+            ":game_die: __Game modes__\n{modes}\n*Create one with `g!create <MODE> <GOLD_AMOUNT>`, learn more with `g!modes <MODE>`.*",
+        MessageKey::ModeLine => "- `{name}` {summary} *({min_players}+ players, {min_wager}+ gold)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- At least **{min_players}** players and **{min_wager}** gold.\n*i.e.: `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled =>
+            ":hourglass: The game wasn't started in time, so I tidied it away. Start a new one whenever you like!",
+        MessageKey::AutoRolled =>
+            ":hourglass: {player} must be busy, so I rolled for them: **{roll}**!",
+        MessageKey::AutoPlayed => ":hourglass: {player} must be busy, so I stood their hands for them.",
+        MessageKey::Forfeited => ":hourglass: {player} must be busy, so they sit this one out.",
+        MessageKey::Left => ":wave: {player} left the game. See you next time!",
+        MessageKey::LeftAndForfeited => ":wave: {player} had to leave mid-game and forfeits. See you next time!",
+        MessageKey::Kicked => ":wave: {player} was removed from the game.",
+        MessageKey::Cancelled => ":leaves: The game was cancelled. Start a new one whenever you like!",
+        MessageKey::ShowConfig =>
+            ":gear: __Server settings__\n{settings}\n*Change one with `g!config set <KEY> <VALUE>`, or reset it with `default`.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => ":gear: `{key}` is `{value}`.",
+        MessageKey::ConfigUpdated => ":gear: {player} set `{key}` to `{value}`. Thank you!",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*A friendly way to roll dice with your friends!*
+- `g!create [MODE] <GOLD_AMOUNT>`  __Create a game in a channel__
+- `g!modes [MODE]` __List the game modes, or explain one__
+- `g!join [GAME_ID]` __Join a new game__
+- `g!play [GAME_ID]` __Start a new game__
+- `g!roll [GAME_ID]` __Roll__
+- `g!hit|stand|double|split [GAME_ID]` __Play your blackjack hand__
+- `g!leave [GAME_ID]` __Leave the game, forfeiting if it already started__
+- `g!cancel [GAME_ID]` __Cancel the game (creator or moderators only)__
+- `g!kick @user [GAME_ID]` __Remove a player before the game starts (creator or moderators only)__
+- `g!verify <GAME_ID>` __Recompute every roll of a finished game from its revealed seed__
+- `g!help` __List all available commands__
+- `g!info [GAME_ID]` __List information about the current game__
+- `g!owed` __List who owes you gold__
+- `g!owes @user` __List who a player owes gold to__
+- `g!ledger` __List the latest games played in this server__
+- `g!settle` __List the fewest payments that settle every debt in this server__
+- `g!stats [@user]` __Show a player's statistics__
+- `g!leaderboard [week|month|all]` __Show the best players of the period__
+- `g!paid @user <GOLD_AMOUNT>` __Tell a player you paid them back__
+- `g!confirm [@user]` __Confirm you received a payment__
+- `g!config [get|set] [KEY] [VALUE]` __Show or change this server's settings (Manage Server only)__
+*Every command is also available as a slash command, e.g. `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary => "Everybody rolls once, the lowest roll pays the highest the difference.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Classic**
+- Every player rolls between 0 and the amount of gold.
+- The lowest roll owes the highest roll the difference between both rolls.
+- Players tied for the highest or lowest roll reroll to break the tie.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Take turns rolling below the previous roll, whoever rolls 1 pays the wager.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Players roll in the order they joined, starting between 1 and the amount of gold.
+- Each roll is between 1 and the previous roll.
+- Whoever rolls a 1 owes the whole amount to the player who rolled before them.
+"#,
+        MessageKey::BlackjackSummary => "Play your hands against the dealer, the creator banks every hand.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- The creator is the house and banks every hand. Everybody else gets dealt a hand worth the amount of gold.
+- `g!hit` draws a card, `g!stand` keeps the hand, `g!double` doubles the wager for one last card and `g!split` splits a pair into two hands.
+- Once every hand is played, the dealer draws to 17. Beat the dealer without going over 21 to win the wager from the house, a blackjack pays 3 to 2.
+- Cards are drawn from 6 decks with the game's seed.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- No players have joined yet",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Ongoing Game!__\nFor **{amount}** gold!\n\n*Players who have already joined*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
+        MessageKey::InfoClassicOngoing => "Game is ongoing!\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} still needs to roll! (i.e.: `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll!__\nFor **{amount}** gold!\n\n*Players in turn order*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Death roll is ongoing!\n{player}, roll between **1** and **{max}**! (i.e.: `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack!__\nFor **{amount}** gold a hand, {house} is the house!\n\n*Players who have already joined*\n{players}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to deal the cards",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack!__\n{house} is the house, **{amount}** gold a hand.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Dealer* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** for {amount} gold {status}",
+        MessageKey::InfoDone => "{debtor} owes {creditor} {amount} gold!",
+        MessageKey::InfoCancelled => "The game was cancelled.",
+        MessageKey::InfoRolls => "{info}\n\n*Rolls*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "{player}'s game",
+
+        MessageKey::FieldPlayers => "Players",
+        MessageKey::FieldRolls => "Rolls",
+        MessageKey::FieldWager => "Wager",
+        MessageKey::FieldWinner => "Winner",
+        MessageKey::FieldLoser => "Loser",
+        MessageKey::FieldDifference => "Difference",
+        MessageKey::ButtonJoin => "Join",
+        MessageKey::ButtonStart => "Start",
+        MessageKey::ButtonRoll => "Roll",
+        MessageKey::ButtonHit => "Hit",
+        MessageKey::ButtonStand => "Stand",
+        MessageKey::ButtonDouble => "Double",
+        MessageKey::ButtonSplit => "Split",
+        MessageKey::ButtonInfo => "Info",
+        MessageKey::ButtonConfirm => "Confirm",
+    }
+}
@@ -0,0 +1,3 @@
+pub mod neutral;
+pub mod savage;
+pub mod wholesome;
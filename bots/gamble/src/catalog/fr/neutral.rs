@@ -0,0 +1,230 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame => "{player}, une partie est déjà en cours. (ex. : `g!info`)",
+        MessageKey::SeveralGames =>
+            "{player}, il y a plusieurs parties ici. Ajoutez l'identifiant de la partie voulue à la commande : {games}",
+        MessageKey::NotEnoughPlayers => "{player}, la partie nécessite au moins {count} joueurs.",
+        MessageKey::GoldAmountTooSmall => "{player}, la mise minimale est de {amount} pièces d'or.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            "{player}, il n'y a aucune partie dans laquelle lancer les dés. (ex. : `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            "{player}, il n'y a aucune partie à afficher. (ex. : `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            "{player}, il n'y a aucune partie à lancer. (ex. : `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            "{player}, il n'y a aucune partie à rejoindre. (ex. : `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            "{player}, cette partie a déjà commencé. Vous pourrez rejoindre la suivante.",
+        MessageKey::PlayerAlreadyPartOfGame => "{player}, vous avez déjà rejoint cette partie.",
+        MessageKey::PlayerCannotRoll => "{player}, vous ne pouvez pas lancer les dés pour le moment.",
+        MessageKey::PlayerAlreadyRolled => "{player}, vous avez déjà lancé les dés ce tour-ci.",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, vous êtes à égalité pour le plus petit lancer. Relancez les dés.",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, vous êtes à égalité pour le plus grand lancer. Relancez les dés.",
+        MessageKey::NoWinnersFound => "La partie est terminée, mais aucun gagnant n'a pu être déterminé.",
+        MessageKey::UnknownCommand => "{player}, cette commande n'existe pas. (ex. : `g!help`)",
+        MessageKey::MissingPlayerMention => "{player}, mentionnez un joueur. (ex. : `g!owes @quelquun`)",
+        MessageKey::LedgerOnlyAvailableInGuilds => "{player}, le registre n'est disponible que sur les serveurs.",
+        MessageKey::LedgerUnavailable =>
+            "{player}, le registre est indisponible pour le moment, réessayez plus tard.",
+        MessageKey::CannotPayYourself => "{player}, vous ne pouvez pas vous payer vous-même.",
+        MessageKey::InvalidPaymentAmount =>
+            "{player}, indiquez le montant payé. (ex. : `g!paid @quelquun 500`)",
+        MessageKey::NothingOwed =>
+            "{player}, vous ne devez rien à {payee} (ou le paiement attend déjà une confirmation).",
+        MessageKey::PaymentExceedsDebt =>
+            "{player}, c'est plus que ce que vous devez. Vous devez **{amount}** pièces d'or.",
+        MessageKey::NoPendingPayments => "{player}, vous n'avez aucun paiement à confirmer.",
+        MessageKey::UnknownPeriod => "{player}, période inconnue. (ex. : `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode => "{player}, mode de jeu inconnu. (ex. : `g!modes`)",
+        MessageKey::PlayerNotPartOfGame => "{player}, vous ne participez pas à cette partie.",
+        MessageKey::NotYourTurn => "{player}, c'est au tour de {current} de lancer les dés.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame => "{player}, il n'y a aucune partie à quitter.",
+        MessageKey::PlayerCannotCancelAnInexistentGame => "{player}, il n'y a aucune partie à annuler.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame =>
+            "{player}, il n'y a aucune partie dont exclure quelqu'un.",
+        MessageKey::OnlyCreatorOrModerator =>
+            "{player}, seuls le créateur de la partie et les modérateurs peuvent faire cela.",
+        MessageKey::CannotKickFromOngoingGame =>
+            "{player}, les joueurs ne peuvent plus être exclus une fois la partie commencée. (ex. : `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => "{player}, {kicked} ne participe pas à cette partie.",
+        MessageKey::WinnerCannotLeave => "{player}, vous avez déjà gagné et ne pouvez pas quitter la partie.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            "{player}, il n'y a aucune partie dans laquelle jouer une main. (ex. : `g!create blackjack`)",
+        MessageKey::NoHandToPlay => "{player}, vous n'avez aucune main à jouer pour le moment.",
+        MessageKey::CannotDouble => "{player}, vous ne pouvez doubler que sur vos deux premières cartes.",
+        MessageKey::CannotSplit => "{player}, vous ne pouvez séparer qu'une paire, en 4 mains au plus.",
+        MessageKey::HouseCannotLeave =>
+            "{player}, vous êtes la banque et ne pouvez pas partir pendant que des mains sont en jeu.",
+        MessageKey::GameAlreadyOver => "{player}, cette partie est déjà terminée.",
+        MessageKey::MissingGameId =>
+            "{player}, indiquez l'identifiant d'une partie. (ex. : `g!verify <ID_PARTIE>`)",
+        MessageKey::UnknownGameId => "{player}, aucune partie terminée avec l'identifiant `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds =>
+            "{player}, les paramètres ne sont disponibles que sur les serveurs.",
+        MessageKey::OnlyGuildManagers =>
+            "{player}, seuls les membres pouvant gérer le serveur peuvent modifier ses paramètres.",
+        MessageKey::UnknownConfigKey =>
+            "{player}, paramètre inconnu. Paramètres disponibles : {keys}. (ex. : `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            "{player}, valeur invalide pour `{key}`. (ex. : `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            "{player}, les paramètres ne peuvent pas être enregistrés pour le moment, réessayez plus tard.",
+
+        MessageKey::Started => "La partie a commencé. Tapez `g!roll` ou cliquez sur **Roll**.",
+        MessageKey::ShowJoinInfo =>
+            "Tapez `g!join` ou cliquez sur **Join** pour rejoindre la partie.\n:lock: Partie `{game_id}`, empreinte de la graine `{seed_hash}`",
+        MessageKey::Done =>
+            "__La partie est terminée.__\n{payouts}\n:unlock: Graine `{seed}` (ex. : `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} doit **{amount}** pièces d'or à {creditor}.",
+        MessageKey::NoPayouts => ":handshake: Personne ne doit rien à personne.",
+        MessageKey::Verified =>
+            ":mag: __Partie `{game_id}`__\nGraine `{seed}`\nEmpreinte `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} a obtenu **{value}** entre {min} et {max} {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Chaque lancer correspond à la graine révélée.",
+        MessageKey::VerifiedUnfair => ":x: Cette partie ne correspond **pas** à sa graine révélée.",
+        MessageKey::TieBreak => "Départage",
+        MessageKey::Dealer => "Croupier",
+        MessageKey::PlayerRolled => "{player} a obtenu {roll}.",
+        MessageKey::OwedTo => "__Dû à {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => "Personne ne doit rien à {creditor}.",
+        MessageKey::OwedBy => "__Dû par {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => "{debtor} ne doit rien à personne.",
+        MessageKey::DebtLine => "- {debtor} doit **{amount}** pièces d'or à {creditor}",
+        MessageKey::Ledger => "__Dernières parties__\n{outcomes}",
+        MessageKey::LedgerEmpty => "Aucune partie n'a encore été enregistrée.",
+        MessageKey::OutcomeLine => "- {time} {creditor} a gagné **{amount}** pièces d'or contre {debtor}",
+        MessageKey::PaymentPending =>
+            "{payee}, {payer} dit vous avoir payé **{amount}** pièces d'or. Tapez `g!confirm` ou cliquez sur **Confirm** une fois reçues.",
+        MessageKey::PaymentsConfirmed =>
+            "{payee} a confirmé avoir reçu **{amount}** pièces d'or de {payer}.",
+        MessageKey::Settlement =>
+            "__Règlement__\nCes paiements soldent toutes les dettes du serveur :\n{debts}\n*Utilisez `g!paid @joueur <MONTANT>` une fois le paiement effectué.*",
+        MessageKey::SettlementEmpty => "Il n'y a aucune dette à régler.",
+        MessageKey::Stats =>
+            "__Statistiques de {player}__\n- Parties jouées : **{games}**\n- Victoires : **{wins}**\n- Défaites : **{losses}**\n- Bilan : **{net}** pièces d'or\n- Plus gros gain : **{biggest_win}** pièces d'or\n- Plus grosse perte : **{biggest_loss}** pièces d'or",
+        MessageKey::StatsNoGames => "{player} n'a encore terminé aucune partie.",
+        MessageKey::Leaderboard => "__Classement {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => "Aucune partie n'a été terminée {period}.",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** pièces d'or ({wins}V / {losses}D)",
+        MessageKey::PeriodWeek => "cette semaine",
+        MessageKey::PeriodMonth => "ce mois-ci",
+        MessageKey::PeriodAll => "de tous les temps",
+        MessageKey::ShowModes =>
+            "__Modes de jeu__\n{modes}\n*Créez-en une avec `g!create <MODE> <MONTANT>`, découvrez-en plus avec `g!modes <MODE>`.*",
+        MessageKey::ModeLine =>
+            "- `{name}` {summary} *({min_players}+ joueurs, {min_wager}+ pièces d'or)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- Au moins **{min_players}** joueurs et **{min_wager}** pièces d'or.\n*ex. : `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled => "La partie a été annulée car personne ne l'a lancée à temps.",
+        MessageKey::AutoRolled =>
+            "{player} n'a pas lancé les dés à temps, ils ont été lancés à sa place : **{roll}**.",
+        MessageKey::AutoPlayed => "{player} n'a pas joué à temps et reste sur ses mains.",
+        MessageKey::Forfeited => "{player} n'a pas lancé les dés à temps et déclare forfait.",
+        MessageKey::Left => "{player} a quitté la partie.",
+        MessageKey::LeftAndForfeited => "{player} a quitté la partie et déclare forfait.",
+        MessageKey::Kicked => "{player} a été retiré de la partie.",
+        MessageKey::Cancelled => "La partie a été annulée.",
+        MessageKey::ShowConfig =>
+            "__Paramètres du serveur__\n{settings}\n*Modifiez-en un avec `g!config set <CLÉ> <VALEUR>`, ou réinitialisez-le avec `default`.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => "`{key}` vaut `{value}`.",
+        MessageKey::ConfigUpdated => "{player} a défini `{key}` sur `{value}`.",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Lancez les dés contre d'autres joueurs pour de l'or.*
+- `g!create [MODE] <MONTANT>`  __Créer une partie dans un salon__
+- `g!modes [MODE]` __Lister les modes de jeu, ou en expliquer un__
+- `g!join [ID_PARTIE]` __Rejoindre une nouvelle partie__
+- `g!play [ID_PARTIE]` __Lancer une nouvelle partie__
+- `g!roll [ID_PARTIE]` __Lancer les dés__
+- `g!hit|stand|double|split [ID_PARTIE]` __Jouer sa main de blackjack__
+- `g!leave [ID_PARTIE]` __Quitter la partie, en déclarant forfait si elle a commencé__
+- `g!cancel [ID_PARTIE]` __Annuler la partie (créateur ou modérateurs uniquement)__
+- `g!kick @joueur [ID_PARTIE]` __Retirer un joueur avant le début de la partie (créateur ou modérateurs uniquement)__
+- `g!verify <ID_PARTIE>` __Recalculer chaque lancer d'une partie terminée à partir de sa graine révélée__
+- `g!help` __Lister toutes les commandes__
+- `g!info [ID_PARTIE]` __Afficher les informations de la partie en cours__
+- `g!owed` __Lister qui vous doit de l'or__
+- `g!owes @joueur` __Lister à qui un joueur doit de l'or__
+- `g!ledger` __Lister les dernières parties jouées sur ce serveur__
+- `g!settle` __Lister le moins de paiements possible pour solder toutes les dettes du serveur__
+- `g!stats [@joueur]` __Afficher les statistiques d'un joueur__
+- `g!leaderboard [week|month|all]` __Afficher les meilleurs joueurs de la période__
+- `g!paid @joueur <MONTANT>` __Indiquer à un joueur que vous l'avez remboursé__
+- `g!confirm [@joueur]` __Confirmer la réception d'un paiement__
+- `g!config [get|set] [CLÉ] [VALEUR]` __Afficher ou modifier les paramètres du serveur (Gérer le serveur uniquement)__
+*Chaque commande existe aussi en commande slash, ex. : `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary =>
+            "Chacun lance les dés une fois, le plus petit lancer paie la différence au plus grand.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Classique**
+- Chaque joueur lance les dés entre 0 et le montant de la mise.
+- Le plus petit lancer doit au plus grand la différence entre les deux lancers.
+- Les joueurs à égalité pour le plus grand ou le plus petit lancer relancent pour se départager.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Lancez chacun votre tour sous le lancer précédent, celui qui fait 1 paie la mise.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Les joueurs lancent dans l'ordre d'arrivée, en commençant entre 1 et le montant de la mise.
+- Chaque lancer se fait entre 1 et le lancer précédent.
+- Celui qui fait 1 doit toute la mise au joueur qui a lancé avant lui.
+"#,
+        MessageKey::BlackjackSummary => "Jouez vos mains contre le croupier, le créateur fait la banque.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- Le créateur fait la banque. Les autres joueurs reçoivent chacun une main qui mise le montant d'or.
+- `g!hit` tire une carte, `g!stand` garde la main, `g!double` double la mise pour une dernière carte et `g!split` sépare une paire en deux mains.
+- Une fois toutes les mains jouées, le croupier tire jusqu'à 17. Battez le croupier sans dépasser 21 pour gagner la mise de la banque, un blackjack paie 3 pour 2.
+- Les cartes sont tirées de 6 jeux avec la graine de la partie.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- Aucun joueur n'a encore rejoint",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Partie en attente__\nPour **{amount}** pièces d'or.\n\n*Joueurs ayant rejoint*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour lancer la partie",
+        MessageKey::InfoClassicOngoing => "La partie est en cours.\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} doit encore lancer les dés. (ex. : `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll__\nPour **{amount}** pièces d'or.\n\n*Joueurs dans l'ordre de jeu*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour lancer la partie",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Le death roll est en cours.\n{player}, lancez entre **1** et **{max}**. (ex. : `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack__\nPour **{amount}** pièces d'or la main, {house} fait la banque.\n\n*Joueurs ayant rejoint*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour distribuer les cartes",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack__\n{house} fait la banque, **{amount}** pièces d'or la main.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Croupier* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** pour {amount} pièces d'or {status}",
+        MessageKey::InfoDone => "{debtor} doit {amount} pièces d'or à {creditor}.",
+        MessageKey::InfoCancelled => "La partie a été annulée.",
+        MessageKey::InfoRolls => "{info}\n\n*Lancers*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "Partie de {player}",
+
+        MessageKey::FieldPlayers => "Joueurs",
+        MessageKey::FieldRolls => "Lancers",
+        MessageKey::FieldWager => "Mise",
+        MessageKey::FieldWinner => "Gagnant",
+        MessageKey::FieldLoser => "Perdant",
+        MessageKey::FieldDifference => "Différence",
+        MessageKey::ButtonJoin => "Rejoindre",
+        MessageKey::ButtonStart => "Commencer",
+        MessageKey::ButtonRoll => "Lancer",
+        MessageKey::ButtonHit => "Carte",
+        MessageKey::ButtonStand => "Rester",
+        MessageKey::ButtonDouble => "Doubler",
+        MessageKey::ButtonSplit => "Séparer",
+        MessageKey::ButtonInfo => "Infos",
+        MessageKey::ButtonConfirm => "Confirmer",
+    }
+}
@@ -0,0 +1,235 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame =>
+            ":man_facepalming: {player}, frérot, y a déjà une partie en cours. (ex. : `g!info`)",
+        MessageKey::SeveralGames =>
+            "{player}, y a plus d'une partie ici, génie. Ajoute l'id de la tienne à la commande : {games}",
+        MessageKey::NotEnoughPlayers => ":upside_down: {player}, il faut au moins {count} joueurs.",
+        MessageKey::GoldAmountTooSmall =>
+            ":pinched_fingers: {player}, t'es fauché ou quoi ? Mise au moins {amount} pièces d'or.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            "{player}, __espèce d'abruti__, tu lances les dés pour quoi au juste ? (ex. : `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            "{player}, __t'es un cas désespéré__, crée une partie avant de demander des infos. (ex. : `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            "{player}, __espèce de cornichon__, crée une partie avant de la lancer. (ex. : `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            "{player}, __espèce de beignet__, crée une partie avant d'en rejoindre une. (ex. : `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            ":weary: {player}, laisse la partie se finir et rejoins la suivante.",
+        MessageKey::PlayerAlreadyPartOfGame => ":zany_face: {player}, t'es déjà dans la partie, banane.",
+        MessageKey::PlayerCannotRoll => ":unamused: {player}, c'est pas le moment de lancer les dés.",
+        MessageKey::PlayerAlreadyRolled =>
+            ":expresionless: {player}, tu crois vraiment que lancer deux fois va t'aider ?",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, tu es à égalité pour le plus petit lancer. Relance les dés.",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, tu es à égalité pour le plus grand lancer. Relance les dés.",
+        MessageKey::NoWinnersFound =>
+            ":thinking: Sérieux, aucun gagnant.. mais la partie est finie ? C'est quoi ce bordel.",
+        MessageKey::UnknownCommand => "{player}, c'est ta première fois ? (ex. : `g!help`)",
+        MessageKey::MissingPlayerMention =>
+            "{player}, tu parles de qui ? Mentionne un joueur. (ex. : `g!owes @quelquun`)",
+        MessageKey::LedgerOnlyAvailableInGuilds =>
+            "{player}, le registre n'existe que sur les serveurs, y a personne à qui devoir ici.",
+        MessageKey::LedgerUnavailable =>
+            ":floppy_disk: {player}, le registre est indisponible, réessaie plus tard.",
+        MessageKey::CannotPayYourself => ":clown: {player}, te rembourser toi-même ? Bien essayé.",
+        MessageKey::InvalidPaymentAmount => "{player}, t'as payé combien ? (ex. : `g!paid @quelquun 500`)",
+        MessageKey::NothingOwed =>
+            ":face_with_raised_eyebrow: {player}, tu dois rien à {payee} (ou ça attend déjà une confirmation).",
+        MessageKey::PaymentExceedsDebt =>
+            ":money_mouth: {player}, c'est plus que ce que tu dois. Tu dois seulement **{amount}** pièces d'or.",
+        MessageKey::NoPendingPayments => "{player}, personne n'attend que tu confirmes un paiement.",
+        MessageKey::UnknownPeriod =>
+            "{player}, choisis une période qui existe. (ex. : `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode => "{player}, jamais entendu parler de ce jeu. (ex. : `g!modes`)",
+        MessageKey::PlayerNotPartOfGame => ":eyes: {player}, tu joues même pas. Attends la prochaine.",
+        MessageKey::NotYourTurn => ":hand_splayed: {player}, attends ton tour, c'est à {current} de lancer.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame =>
+            "{player}, quitter quoi ? Y a aucune partie ici. (ex. : `g!create`)",
+        MessageKey::PlayerCannotCancelAnInexistentGame => "{player}, y a rien à annuler, __génie__.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame =>
+            "{player}, l'exclure de quoi ? Y a aucune partie ici.",
+        MessageKey::OnlyCreatorOrModerator =>
+            ":police_officer: {player}, seul le créateur de la partie ou un modérateur peut faire ça.",
+        MessageKey::CannotKickFromOngoingGame =>
+            "{player}, la partie a déjà commencé, trop tard pour exclure qui que ce soit. (ex. : `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => "{player}, {kicked} est même pas dans la partie.",
+        MessageKey::WinnerCannotLeave =>
+            ":trophy: {player}, t'as déjà gagné, reste là et récupère ton or.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            "{player}, __génie__, il n'y a pas de table. Tu joues contre qui ? (ex. : `g!create blackjack`)",
+        MessageKey::NoHandToPlay => ":unamused: {player}, t'as aucune main à jouer. Assieds-toi d'abord.",
+        MessageKey::CannotDouble => ":no_entry: {player}, on double sur ses deux premières cartes, pas sur ce bazar.",
+        MessageKey::CannotSplit => ":no_entry: {player}, c'est pas une paire. Ou t'as déjà assez séparé, 4 mains max.",
+        MessageKey::HouseCannotLeave => ":bank: {player}, la banque ne se barre pas en pleine main. Reste assis.",
+        MessageKey::GameAlreadyOver => "{player}, cette partie est déjà finie.",
+        MessageKey::MissingGameId => "{player}, quelle partie ? (ex. : `g!verify <ID_PARTIE>`)",
+        MessageKey::UnknownGameId => "{player}, aucune partie terminée avec l'identifiant `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds =>
+            "{player}, y a rien à configurer ici, les paramètres n'existent que sur les serveurs.",
+        MessageKey::OnlyGuildManagers =>
+            ":police_officer: {player}, seuls les membres qui peuvent gérer le serveur touchent à ses paramètres.",
+        MessageKey::UnknownConfigKey =>
+            "{player}, c'est pas un paramètre. Choisis parmi {keys}. (ex. : `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            "{player}, c'est pas une valeur valide pour `{key}`. (ex. : `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            ":floppy_disk: {player}, impossible d'enregistrer les paramètres pour l'instant, réessaie plus tard.",
+
+        MessageKey::Started => "La partie commence :rocket: ! Tape `g!roll` ou clique sur **Roll** !",
+        MessageKey::ShowJoinInfo =>
+            "Tape `g!join` ou clique sur **Join** pour rejoindre la partie !\n:lock: Partie `{game_id}`, empreinte de la graine `{seed_hash}`",
+        MessageKey::Done =>
+            "__Un gagnant est apparu !__\n{payouts}\n:unlock: Graine `{seed}` (ex. : `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} doit **{amount}** pièces d'or à {creditor}.",
+        MessageKey::NoPayouts => ":handshake: Personne ne doit rien à personne. Quel ennui.",
+        MessageKey::Verified =>
+            ":mag: __Partie `{game_id}`__\nGraine `{seed}`\nEmpreinte `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} a obtenu **{value}** entre {min} et {max} {check}",
+        MessageKey::VerifiedFair => ":white_check_mark: Chaque lancer correspond à la graine révélée.",
+        MessageKey::VerifiedUnfair => ":x: Cette partie ne correspond **pas** à sa graine révélée.",
+        MessageKey::TieBreak => "Départage",
+        MessageKey::Dealer => "Croupier",
+        MessageKey::PlayerRolled => "{player} a fait {roll} !",
+        MessageKey::OwedTo => ":money_with_wings: __Dû à {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => "Personne ne doit rien à {creditor}.",
+        MessageKey::OwedBy => ":money_with_wings: __Dû par {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => "{debtor} doit rien à personne.",
+        MessageKey::DebtLine => "- {debtor} doit **{amount}** pièces d'or à {creditor}",
+        MessageKey::Ledger => ":ledger: __Dernières parties__\n{outcomes}",
+        MessageKey::LedgerEmpty => "Le registre est vide, allez jouer !",
+        MessageKey::OutcomeLine => "- {time} {creditor} a gagné **{amount}** pièces d'or contre {debtor}",
+        MessageKey::PaymentPending =>
+            ":hourglass: {payee}, {payer} dit t'avoir payé **{amount}** pièces d'or. Tape `g!confirm` ou clique sur **Confirm** une fois reçues.",
+        MessageKey::PaymentsConfirmed =>
+            ":white_check_mark: {payee} a confirmé avoir reçu **{amount}** pièces d'or de {payer}.",
+        MessageKey::Settlement =>
+            ":scales: __Règlement__\nCes paiements soldent toutes les dettes du serveur :\n{debts}\n*Utilise `g!paid @joueur <MONTANT>` une fois que t'as payé.*",
+        MessageKey::SettlementEmpty => ":handshake: Tout le monde est quitte, rien à régler.",
+        MessageKey::Stats =>
+            ":bar_chart: __Statistiques de {player}__\n- Parties jouées : **{games}**\n- Victoires : **{wins}**\n- Défaites : **{losses}**\n- Bilan : **{net}** pièces d'or\n- Plus gros gain : **{biggest_win}** pièces d'or\n- Plus grosse perte : **{biggest_loss}** pièces d'or",
+        MessageKey::StatsNoGames => "{player} n'a encore fini aucune partie.",
+        MessageKey::Leaderboard => ":trophy: __Classement {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => "Aucune partie terminée {period}.",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** pièces d'or ({wins}V / {losses}D)",
+        MessageKey::PeriodWeek => "cette semaine",
+        MessageKey::PeriodMonth => "ce mois-ci",
+        MessageKey::PeriodAll => "de tous les temps",
+        MessageKey::ShowModes =>
+            ":game_die: __Modes de jeu__\n{modes}\n*Crée une partie avec `g!create <MODE> <MONTANT>`, découvre un mode avec `g!modes <MODE>`.*",
+        MessageKey::ModeLine =>
+            "- `{name}` {summary} *({min_players}+ joueurs, {min_wager}+ pièces d'or)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- Au moins **{min_players}** joueurs et **{min_wager}** pièces d'or.\n*ex. : `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled => ":hourglass: Personne n'a lancé la partie à temps, elle est annulée.",
+        MessageKey::AutoRolled => ":hourglass: {player} a traîné, alors j'ai lancé à sa place : **{roll}** !",
+        MessageKey::AutoPlayed => ":hourglass: {player} a traîné, alors je reste sur ses mains à sa place.",
+        MessageKey::Forfeited => ":hourglass: {player} a mis trop de temps à lancer et déclare forfait.",
+        MessageKey::Left => ":door: {player} a quitté la partie.",
+        MessageKey::LeftAndForfeited => ":chicken: {player} s'est dégonflé en pleine partie et déclare forfait.",
+        MessageKey::Kicked => ":boot: {player} s'est fait virer de la partie.",
+        MessageKey::Cancelled => ":x: La partie a été annulée.",
+        MessageKey::ShowConfig =>
+            ":gear: __Paramètres du serveur__\n{settings}\n*Modifie-en un avec `g!config set <CLÉ> <VALEUR>`, ou réinitialise-le avec `default`.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => ":gear: `{key}` vaut `{value}`.",
+        MessageKey::ConfigUpdated => ":gear: {player} a défini `{key}` sur `{value}`.",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Le meilleur moyen de perdre ton or dans ton jeu préféré.*
+- `g!create [MODE] <MONTANT>`  __Créer une partie dans un salon__
+- `g!modes [MODE]` __Lister les modes de jeu, ou en expliquer un__
+- `g!join [ID_PARTIE]` __Rejoindre une nouvelle partie__
+- `g!play [ID_PARTIE]` __Lancer une nouvelle partie__
+- `g!roll [ID_PARTIE]` __Lancer les dés__
+- `g!hit|stand|double|split [ID_PARTIE]` __Jouer sa main de blackjack__
+- `g!leave [ID_PARTIE]` __Quitter la partie, en déclarant forfait si elle a commencé__
+- `g!cancel [ID_PARTIE]` __Annuler la partie (créateur ou modérateurs uniquement)__
+- `g!kick @joueur [ID_PARTIE]` __Retirer un joueur avant le début de la partie (créateur ou modérateurs uniquement)__
+- `g!verify <ID_PARTIE>` __Recalculer chaque lancer d'une partie terminée à partir de sa graine révélée__
+- `g!help` __Lister toutes les commandes__
+- `g!info [ID_PARTIE]` __Afficher les informations de la partie en cours__
+- `g!owed` __Lister qui te doit de l'or__
+- `g!owes @joueur` __Lister à qui un joueur doit de l'or__
+- `g!ledger` __Lister les dernières parties jouées sur ce serveur__
+- `g!settle` __Lister le moins de paiements possible pour solder toutes les dettes du serveur__
+- `g!stats [@joueur]` __Afficher les statistiques d'un joueur__
+- `g!leaderboard [week|month|all]` __Afficher les meilleurs joueurs de la période__
+- `g!paid @joueur <MONTANT>` __Dire à un joueur que tu l'as remboursé__
+- `g!confirm [@joueur]` __Confirmer la réception d'un paiement__
+- `g!config [get|set] [CLÉ] [VALEUR]` __Afficher ou modifier les paramètres du serveur (Gérer le serveur uniquement)__
+*Chaque commande existe aussi en commande slash, ex. : `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary =>
+            "Chacun lance les dés une fois, le plus petit lancer paie la différence au plus grand.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Classique**
+- Chaque joueur lance les dés entre 0 et le montant de la mise.
+- Le plus petit lancer doit au plus grand la différence entre les deux lancers.
+- Les joueurs à égalité pour le plus grand ou le plus petit lancer relancent pour se départager.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Lancez chacun votre tour sous le lancer précédent, celui qui fait 1 paie la mise.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Les joueurs lancent dans l'ordre d'arrivée, en commençant entre 1 et le montant de la mise.
+- Chaque lancer se fait entre 1 et le lancer précédent.
+- Celui qui fait 1 doit toute la mise au joueur qui a lancé avant lui.
+"#,
+        MessageKey::BlackjackSummary => "Jouez vos mains contre le croupier, le créateur fait la banque.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- Le créateur fait la banque. Les autres joueurs reçoivent chacun une main qui mise le montant d'or.
+- `g!hit` tire une carte, `g!stand` garde la main, `g!double` double la mise pour une dernière carte et `g!split` sépare une paire en deux mains.
+- Une fois toutes les mains jouées, le croupier tire jusqu'à 17. Battez le croupier sans dépasser 21 pour gagner la mise de la banque, un blackjack paie 3 pour 2.
+- Les cartes sont tirées de 6 jeux avec la graine de la partie.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- Personne n'a encore rejoint",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Partie en cours !__\nPour **{amount}** pièces d'or !\n\n*Joueurs ayant déjà rejoint*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour lancer la partie",
+        MessageKey::InfoClassicOngoing => "La partie est en cours !\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} doit encore lancer les dés ! (ex. : `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll !__\nPour **{amount}** pièces d'or !\n\n*Joueurs dans l'ordre de jeu*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour lancer la partie",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Le death roll est en cours !\n{player}, lance entre **1** et **{max}** ! (ex. : `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack !__\nPour **{amount}** pièces d'or la main, {house} fait la banque !\n\n*Joueurs ayant déjà rejoint*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour distribuer les cartes",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack !__\n{house} fait la banque, **{amount}** pièces d'or la main.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Croupier* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** pour {amount} pièces d'or {status}",
+        MessageKey::InfoDone => "{debtor} doit {amount} pièces d'or à {creditor} !",
+        MessageKey::InfoCancelled => "La partie a été annulée.",
+        MessageKey::InfoRolls => "{info}\n\n*Lancers*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "Partie de {player}",
+
+        MessageKey::FieldPlayers => "Joueurs",
+        MessageKey::FieldRolls => "Lancers",
+        MessageKey::FieldWager => "Mise",
+        MessageKey::FieldWinner => "Gagnant",
+        MessageKey::FieldLoser => "Perdant",
+        MessageKey::FieldDifference => "Différence",
+        MessageKey::ButtonJoin => "Rejoindre",
+        MessageKey::ButtonStart => "Commencer",
+        MessageKey::ButtonRoll => "Lancer",
+        MessageKey::ButtonHit => "Carte",
+        MessageKey::ButtonStand => "Rester",
+        MessageKey::ButtonDouble => "Doubler",
+        MessageKey::ButtonSplit => "Séparer",
+        MessageKey::ButtonInfo => "Infos",
+        MessageKey::ButtonConfirm => "Confirmer",
+    }
+}
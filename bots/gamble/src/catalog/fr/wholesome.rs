@@ -0,0 +1,247 @@
+use crate::messages::MessageKey;
+
+pub fn template(key: MessageKey) -> &'static str {
+    match key {
+        MessageKey::CannotInitGame =>
+            ":sparkles: {player}, une partie est déjà en cours, viens jeter un œil ! (ex. : `g!info`)",
+        MessageKey::SeveralGames =>
+            ":sparkles: {player}, plusieurs parties sont en cours ici ! Ajoute simplement l'id de celle que tu veux : {games}",
+        MessageKey::NotEnoughPlayers =>
+            ":people_hugging: {player}, c'est plus amusant à plusieurs ! Attendons au moins {count} joueurs.",
+        MessageKey::GoldAmountTooSmall =>
+            ":coin: {player}, rendons ça palpitant ! La mise minimale est de {amount} pièces d'or.",
+        MessageKey::PlayerCannotRollOnAnInexistentGame =>
+            ":seedling: {player}, il n'y a pas encore de partie, pourquoi ne pas en créer une ? (ex. : `g!create`)",
+        MessageKey::PlayerCannotRequestInfoOnInexistentGame =>
+            ":seedling: {player}, il n'y a pas encore de partie à afficher, pourquoi ne pas en créer une ? (ex. : `g!create`)",
+        MessageKey::PlayerCannotPlayOnInexistentGame =>
+            ":seedling: {player}, il n'y a pas encore de partie à lancer, pourquoi ne pas en créer une ? (ex. : `g!create`)",
+        MessageKey::PlayerCannotJoinAnInexistentGame =>
+            ":seedling: {player}, il n'y a pas encore de partie à rejoindre, pourquoi ne pas en créer une ? (ex. : `g!create`)",
+        MessageKey::PlayerCannotJoinOngoingGame =>
+            ":hourglass: {player}, cette partie a déjà commencé, tu seras le premier de la suivante !",
+        MessageKey::PlayerAlreadyPartOfGame => ":blush: {player}, bonne nouvelle, tu es déjà dans la partie !",
+        MessageKey::PlayerCannotRoll =>
+            ":relaxed: {player}, patience, ce n'est pas encore le moment de lancer les dés.",
+        MessageKey::PlayerAlreadyRolled => ":relaxed: {player}, tu as déjà lancé, croisons les doigts !",
+        MessageKey::PlayersMatchedLowestRoll =>
+            "{player}, tu es à égalité pour le plus petit lancer. Relance les dés, bonne chance !",
+        MessageKey::PlayersMatchedHighestRoll =>
+            "{player}, tu es à égalité pour le plus grand lancer. Relance les dés, bonne chance !",
+        MessageKey::NoWinnersFound =>
+            ":thinking: Oh là là, la partie est finie mais aucun gagnant n'a été trouvé. Désolé !",
+        MessageKey::UnknownCommand =>
+            ":wave: {player}, je ne connais pas celle-là, mais voici tout ce que je sais faire ! (ex. : `g!help`)",
+        MessageKey::MissingPlayerMention =>
+            ":wave: {player}, à qui penses-tu ? Mentionne un joueur. (ex. : `g!owes @quelquun`)",
+        MessageKey::LedgerOnlyAvailableInGuilds =>
+            ":house: {player}, le registre vit sur les serveurs, viens jouer avec tes amis là-bas !",
+        MessageKey::LedgerUnavailable =>
+            ":floppy_disk: {player}, le registre fait une petite sieste, réessaie plus tard.",
+        MessageKey::CannotPayYourself =>
+            ":blush: {player}, tu ne peux pas te rembourser toi-même, mais c'est gentil d'y penser !",
+        MessageKey::InvalidPaymentAmount =>
+            ":coin: {player}, combien as-tu payé ? (ex. : `g!paid @quelquun 500`)",
+        MessageKey::NothingOwed =>
+            ":tada: {player}, bonne nouvelle, tu ne dois rien à {payee} (ou le paiement attend déjà une confirmation) !",
+        MessageKey::PaymentExceedsDebt =>
+            ":heart: {player}, c'est généreux, mais tu ne dois que **{amount}** pièces d'or.",
+        MessageKey::NoPendingPayments => ":sparkles: {player}, tout est à jour, aucun paiement à confirmer !",
+        MessageKey::UnknownPeriod =>
+            ":calendar: {player}, choisis l'une de ces périodes. (ex. : `g!leaderboard week|month|all`)",
+        MessageKey::UnknownGameMode =>
+            ":game_die: {player}, je ne connais pas ce jeu, voici ceux que je connais ! (ex. : `g!modes`)",
+        MessageKey::PlayerNotPartOfGame =>
+            ":eyes: {player}, tu n'es pas dans cette partie, mais tu es le bienvenu dans la suivante !",
+        MessageKey::NotYourTurn => ":hourglass: {player}, patience, c'est au tour de {current} de lancer.",
+        MessageKey::PlayerCannotLeaveAnInexistentGame =>
+            ":seedling: {player}, il n'y a aucune partie à quitter, pourquoi ne pas en créer une ? (ex. : `g!create`)",
+        MessageKey::PlayerCannotCancelAnInexistentGame => ":seedling: {player}, il n'y a aucune partie à annuler.",
+        MessageKey::PlayerCannotKickFromAnInexistentGame => ":seedling: {player}, il n'y a aucune partie ici.",
+        MessageKey::OnlyCreatorOrModerator =>
+            ":shield: {player}, seuls le créateur de la partie et les modérateurs peuvent faire cela.",
+        MessageKey::CannotKickFromOngoingGame =>
+            ":shield: {player}, la partie a commencé, alors tout le monde va jusqu'au bout. (ex. : `g!cancel`)",
+        MessageKey::KickedPlayerNotPartOfGame => ":eyes: {player}, {kicked} n'est pas dans cette partie.",
+        MessageKey::WinnerCannotLeave =>
+            ":trophy: {player}, bravo pour ta victoire ! Reste un peu pour récupérer ton or.",
+        MessageKey::PlayerCannotActOnAnInexistentGame =>
+            ":seedling: {player}, il n'y a pas encore de table, pourquoi ne pas en ouvrir une ? (ex. : `g!create blackjack`)",
+        MessageKey::NoHandToPlay => ":relaxed: {player}, tu n'as pas de main à jouer pour le moment.",
+        MessageKey::CannotDouble => ":relaxed: {player}, on ne peut doubler que sur ses deux premières cartes.",
+        MessageKey::CannotSplit => ":relaxed: {player}, seules les paires peuvent être séparées, en 4 mains au plus.",
+        MessageKey::HouseCannotLeave =>
+            ":bank: {player}, la table a besoin de sa banque ! Reste jusqu'à la fin des mains.",
+        MessageKey::GameAlreadyOver => ":checkered_flag: {player}, cette partie est déjà terminée.",
+        MessageKey::MissingGameId =>
+            ":mag: {player}, quelle partie dois-je vérifier ? (ex. : `g!verify <ID_PARTIE>`)",
+        MessageKey::UnknownGameId =>
+            ":mag: {player}, je n'ai trouvé aucune partie terminée avec l'identifiant `{game_id}`.",
+        MessageKey::ConfigOnlyAvailableInGuilds => ":house: {player}, les paramètres vivent sur les serveurs.",
+        MessageKey::OnlyGuildManagers =>
+            ":shield: {player}, seuls les membres pouvant gérer le serveur peuvent modifier ses paramètres.",
+        MessageKey::UnknownConfigKey =>
+            ":gear: {player}, je ne connais pas ce paramètre. Essaie l'un de {keys}. (ex. : `g!config get prefix`)",
+        MessageKey::InvalidConfigValue =>
+            ":gear: {player}, cette valeur ne convient pas à `{key}`. (ex. : `g!config set {key} default`)",
+        MessageKey::ConfigUnavailable =>
+            ":floppy_disk: {player}, les paramètres ne peuvent pas être enregistrés pour l'instant, réessaie plus tard.",
+
+        MessageKey::Started =>
+            "La partie commence :rocket: ! Tape `g!roll` ou clique sur **Roll**, et bonne chance à tous !",
+        MessageKey::ShowJoinInfo =>
+            "Tape `g!join` ou clique sur **Join** pour te joindre à la fête !\n:lock: Partie `{game_id}`, empreinte de la graine `{seed_hash}`",
+        MessageKey::Done =>
+            "__Bien joué tout le monde !__\n{payouts}\n:unlock: Graine `{seed}` (ex. : `g!verify {game_id}`)",
+        MessageKey::PayoutLine => ":coin: {debtor} doit **{amount}** pièces d'or à {creditor}.",
+        MessageKey::NoPayouts => ":handshake: Personne ne doit rien à personne, tout le monde est quitte !",
+        MessageKey::Verified =>
+            ":mag: __Partie `{game_id}`__\nGraine `{seed}`\nEmpreinte `{hash}` {hash_check}\n{rolls}\n{verdict}",
+        MessageKey::VerifiedRoll => "- #{nonce} {player} a obtenu **{value}** entre {min} et {max} {check}",
+        MessageKey::VerifiedFair =>
+            ":white_check_mark: Chaque lancer correspond à la graine révélée. Tout est en règle !",
+        MessageKey::VerifiedUnfair => ":x: Cette partie ne correspond **pas** à sa graine révélée.",
+        MessageKey::TieBreak => "Départage",
+        MessageKey::Dealer => "Croupier",
+        MessageKey::PlayerRolled => "{player} a fait {roll} !",
+        MessageKey::OwedTo => ":money_with_wings: __Dû à {creditor}__\n{debts}",
+        MessageKey::OwedToNobody => ":sparkles: Personne ne doit rien à {creditor}.",
+        MessageKey::OwedBy => ":money_with_wings: __Dû par {debtor}__\n{debts}",
+        MessageKey::OwedByNobody => ":sparkles: {debtor} ne doit rien à personne, quel héros !",
+        MessageKey::DebtLine => "- {debtor} doit **{amount}** pièces d'or à {creditor}",
+        MessageKey::Ledger => ":ledger: __Dernières parties__\n{outcomes}",
+        MessageKey::LedgerEmpty => ":ledger: Le registre est vide, c'est l'heure d'une partie entre amis !",
+        MessageKey::OutcomeLine => "- {time} {creditor} a gagné **{amount}** pièces d'or contre {debtor}",
+        MessageKey::PaymentPending =>
+            ":hourglass: {payee}, {payer} dit t'avoir payé **{amount}** pièces d'or. Tape `g!confirm` ou clique sur **Confirm** une fois reçues.",
+        MessageKey::PaymentsConfirmed =>
+            ":white_check_mark: {payee} a confirmé avoir reçu **{amount}** pièces d'or de {payer}. Merci !",
+        MessageKey::Settlement =>
+            ":scales: __Règlement__\nCes paiements soldent toutes les dettes du serveur :\n{debts}\n*Utilise `g!paid @joueur <MONTANT>` une fois le paiement effectué.*",
+        MessageKey::SettlementEmpty => ":handshake: Tout le monde est quitte, parfait !",
+        MessageKey::Stats =>
+            ":bar_chart: __Statistiques de {player}__\n- Parties jouées : **{games}**\n- Victoires : **{wins}**\n- Défaites : **{losses}**\n- Bilan : **{net}** pièces d'or\n- Plus gros gain : **{biggest_win}** pièces d'or\n- Plus grosse perte : **{biggest_loss}** pièces d'or",
+        MessageKey::StatsNoGames =>
+            ":seedling: {player} n'a encore terminé aucune partie, la première est toujours spéciale !",
+        MessageKey::Leaderboard => ":trophy: __Classement {period}__\n{rows}",
+        MessageKey::LeaderboardEmpty => ":seedling: Aucune partie terminée {period}, pour l'instant !",
+        MessageKey::LeaderboardRow => "{rank}. {player} **{net}** pièces d'or ({wins}V / {losses}D)",
+        MessageKey::PeriodWeek => "cette semaine",
+        MessageKey::PeriodMonth => "ce mois-ci",
+        MessageKey::PeriodAll => "de tous les temps",
+        MessageKey::ShowModes =>
+            ":game_die: __Modes de jeu__\n{modes}\n*Crée une partie avec `g!create <MODE> <MONTANT>`, découvre un mode avec `g!modes <MODE>`.*",
+        MessageKey::ModeLine =>
+            "- `{name}` {summary} *({min_players}+ joueurs, {min_wager}+ pièces d'or)*",
+        MessageKey::ShowModeHelp =>
+            "{help}\n- Au moins **{min_players}** joueurs et **{min_wager}** pièces d'or.\n*ex. : `g!create {name} {wager}`*",
+        MessageKey::IdleCancelled =>
+            ":hourglass: La partie n'a pas été lancée à temps, alors je l'ai rangée. Créez-en une nouvelle quand vous voulez !",
+        MessageKey::AutoRolled => ":hourglass: {player} doit être occupé, alors j'ai lancé pour lui : **{roll}** !",
+        MessageKey::AutoPlayed => ":hourglass: {player} doit être occupé, alors je reste sur ses mains pour lui.",
+        MessageKey::Forfeited => ":hourglass: {player} doit être occupé, il passe son tour pour cette partie.",
+        MessageKey::Left => ":wave: {player} a quitté la partie. À la prochaine !",
+        MessageKey::LeftAndForfeited =>
+            ":wave: {player} a dû partir en pleine partie et déclare forfait. À la prochaine !",
+        MessageKey::Kicked => ":wave: {player} a été retiré de la partie.",
+        MessageKey::Cancelled => ":leaves: La partie a été annulée. Créez-en une nouvelle quand vous voulez !",
+        MessageKey::ShowConfig =>
+            ":gear: __Paramètres du serveur__\n{settings}\n*Modifie-en un avec `g!config set <CLÉ> <VALEUR>`, ou réinitialise-le avec `default`.*",
+        MessageKey::ConfigLine => "- `{key}` {value}",
+        MessageKey::ConfigValue => ":gear: `{key}` vaut `{value}`.",
+        MessageKey::ConfigUpdated => ":gear: {player} a défini `{key}` sur `{value}`. Merci !",
+        MessageKey::Help =>
+            r#"
+:moneybag: **Gamble Game!**
+*Une façon conviviale de lancer les dés entre amis !*
+- `g!create [MODE] <MONTANT>`  __Créer une partie dans un salon__
+- `g!modes [MODE]` __Lister les modes de jeu, ou en expliquer un__
+- `g!join [ID_PARTIE]` __Rejoindre une nouvelle partie__
+- `g!play [ID_PARTIE]` __Lancer une nouvelle partie__
+- `g!roll [ID_PARTIE]` __Lancer les dés__
+- `g!hit|stand|double|split [ID_PARTIE]` __Jouer sa main de blackjack__
+- `g!leave [ID_PARTIE]` __Quitter la partie, en déclarant forfait si elle a commencé__
+- `g!cancel [ID_PARTIE]` __Annuler la partie (créateur ou modérateurs uniquement)__
+- `g!kick @joueur [ID_PARTIE]` __Retirer un joueur avant le début de la partie (créateur ou modérateurs uniquement)__
+- `g!verify <ID_PARTIE>` __Recalculer chaque lancer d'une partie terminée à partir de sa graine révélée__
+- `g!help` __Lister toutes les commandes__
+- `g!info [ID_PARTIE]` __Afficher les informations de la partie en cours__
+- `g!owed` __Lister qui te doit de l'or__
+- `g!owes @joueur` __Lister à qui un joueur doit de l'or__
+- `g!ledger` __Lister les dernières parties jouées sur ce serveur__
+- `g!settle` __Lister le moins de paiements possible pour solder toutes les dettes du serveur__
+- `g!stats [@joueur]` __Afficher les statistiques d'un joueur__
+- `g!leaderboard [week|month|all]` __Afficher les meilleurs joueurs de la période__
+- `g!paid @joueur <MONTANT>` __Dire à un joueur que tu l'as remboursé__
+- `g!confirm [@joueur]` __Confirmer la réception d'un paiement__
+- `g!config [get|set] [CLÉ] [VALEUR]` __Afficher ou modifier les paramètres du serveur (Gérer le serveur uniquement)__
+*Chaque commande existe aussi en commande slash, ex. : `/gamble create`.*
+"#,
+
+        MessageKey::ClassicSummary =>
+            "Chacun lance les dés une fois, le plus petit lancer paie la différence au plus grand.",
+        MessageKey::ClassicHelp =>
+            r#"
+:game_die: **Classique**
+- Chaque joueur lance les dés entre 0 et le montant de la mise.
+- Le plus petit lancer doit au plus grand la différence entre les deux lancers.
+- Les joueurs à égalité pour le plus grand ou le plus petit lancer relancent pour se départager.
+"#,
+        MessageKey::DeathRollSummary =>
+            "Lancez chacun votre tour sous le lancer précédent, celui qui fait 1 paie la mise.",
+        MessageKey::DeathRollHelp =>
+            r#"
+:skull: **Death Roll**
+- Les joueurs lancent dans l'ordre d'arrivée, en commençant entre 1 et le montant de la mise.
+- Chaque lancer se fait entre 1 et le lancer précédent.
+- Celui qui fait 1 doit toute la mise au joueur qui a lancé avant lui.
+"#,
+        MessageKey::BlackjackSummary => "Jouez vos mains contre le croupier, le créateur fait la banque.",
+        MessageKey::BlackjackHelp =>
+            r#"
+:black_joker: **Blackjack**
+- Le créateur fait la banque. Les autres joueurs reçoivent chacun une main qui mise le montant d'or.
+- `g!hit` tire une carte, `g!stand` garde la main, `g!double` double la mise pour une dernière carte et `g!split` sépare une paire en deux mains.
+- Une fois toutes les mains jouées, le croupier tire jusqu'à 17. Battez le croupier sans dépasser 21 pour gagner la mise de la banque, un blackjack paie 3 pour 2.
+- Les cartes sont tirées de 6 jeux avec la graine de la partie.
+"#,
+
+        MessageKey::InfoPlayer => "- {player}",
+        MessageKey::InfoNoPlayers => "- Personne n'a encore rejoint, sois le premier !",
+        MessageKey::InfoClassicWaiting =>
+            ":moneybag: __Partie en cours !__\nPour **{amount}** pièces d'or !\n\n*Joueurs ayant déjà rejoint*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour lancer la partie",
+        MessageKey::InfoClassicOngoing => "La partie est en cours !\n{players}",
+        MessageKey::InfoPendingRoll => "- {player} doit encore lancer les dés ! (ex. : `g!roll`)",
+        MessageKey::InfoDeathRollWaiting =>
+            ":skull: __Death Roll !__\nPour **{amount}** pièces d'or !\n\n*Joueurs dans l'ordre de jeu*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour lancer la partie",
+        MessageKey::InfoDeathRollOngoing =>
+            ":skull: Le death roll est en cours !\n{player}, lance entre **1** et **{max}** ! (ex. : `g!roll`)",
+        MessageKey::InfoBlackjackWaiting =>
+            ":black_joker: __Blackjack !__\nPour **{amount}** pièces d'or la main, {house} fait la banque !\n\n*Joueurs ayant déjà rejoint*\n{players}\n\n*Prochaines étapes*\n- `g!join` pour rejoindre\n- `g!play` pour distribuer les cartes",
+        MessageKey::InfoBlackjackTable =>
+            ":black_joker: __Blackjack !__\n{house} fait la banque, **{amount}** pièces d'or la main.\n\n{dealer}\n{hands}",
+        MessageKey::InfoDealerHand => "*Croupier* {cards} **{total}**",
+        MessageKey::InfoHand => "- {player} {cards} **{total}** pour {amount} pièces d'or {status}",
+        MessageKey::InfoDone => "{debtor} doit {amount} pièces d'or à {creditor} !",
+        MessageKey::InfoCancelled => "La partie a été annulée.",
+        MessageKey::InfoRolls => "{info}\n\n*Lancers*\n{rolls}",
+        MessageKey::InfoRollLine => "- {player} **{roll}**",
+        MessageKey::ThreadName => "Partie de {player}",
+
+        MessageKey::FieldPlayers => "Joueurs",
+        MessageKey::FieldRolls => "Lancers",
+        MessageKey::FieldWager => "Mise",
+        MessageKey::FieldWinner => "Gagnant",
+        MessageKey::FieldLoser => "Perdant",
+        MessageKey::FieldDifference => "Différence",
+        MessageKey::ButtonJoin => "Rejoindre",
+        MessageKey::ButtonStart => "Commencer",
+        MessageKey::ButtonRoll => "Lancer",
+        MessageKey::ButtonHit => "Carte",
+        MessageKey::ButtonStand => "Rester",
+        MessageKey::ButtonDouble => "Doubler",
+        MessageKey::ButtonSplit => "Séparer",
+        MessageKey::ButtonInfo => "Infos",
+        MessageKey::ButtonConfirm => "Confirmer",
+    }
+}
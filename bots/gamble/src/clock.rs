@@ -0,0 +1,22 @@
+use std::sync::{ Arc, atomic::{ AtomicU64, Ordering } };
+
+use crate::utils::unix_now;
+
+/// What time it is for the games: the system's time, moved forward by whatever was skipped with
+/// `advance` so the CLI simulator can fast-forward to idle timeouts. Clones share the same time.
+#[derive(Debug, Clone, Default)]
+pub struct Clock {
+    /// Seconds skipped with `advance`.
+    skipped: Arc<AtomicU64>,
+}
+
+impl Clock {
+    /// Unix timestamp in seconds.
+    pub fn now(&self) -> u64 {
+        unix_now() + self.skipped.load(Ordering::Relaxed)
+    }
+
+    pub fn advance(&self, seconds: u64) {
+        self.skipped.fetch_add(seconds, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,428 @@
+use twilight_model::{
+    application::{
+        command::{ Command, CommandType },
+        interaction::{
+            application_command::{ CommandData, CommandOptionValue },
+            message_component::MessageComponentInteractionData,
+        },
+    },
+    channel::message::{ Component, component::{ ActionRow, Button, ButtonStyle } },
+};
+use twilight_util::builder::command::{
+    CommandBuilder,
+    IntegerBuilder,
+    StringBuilder,
+    SubCommandBuilder,
+    UserBuilder,
+};
+
+use crate::{
+    game_mode::{ GAME_MODES, GameMode },
+    guild_config::CONFIG_KEYS,
+    messages::{ MessageKey, Messages },
+    types::PlayerId,
+};
+
+pub const COMMAND_PREFIX: &str = "g!";
+pub const SLASH_COMMAND_NAME: &str = "gamble";
+const BUTTON_ID_PREFIX: &str = "gamble:";
+/// Discord doesn't allow more buttons than this in an action row.
+const BUTTONS_PER_ROW: usize = 5;
+
+/// A command and its arguments, independent of whether it came from a `g!` message or a slash command.
+pub struct ParsedCommand {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+fn game_mode_choices() -> Vec<(&'static str, String)> {
+    GAME_MODES.iter()
+        .map(|mode| (mode.name, mode.name.to_string()))
+        .collect()
+}
+
+/// Which game of the channel a command is for, when there is more than one.
+fn game_option() -> StringBuilder {
+    StringBuilder::new("game", "Id of the game, if there is more than one in this channel")
+}
+
+/// Builds the `/gamble` application command and its subcommands.
+pub fn gamble_command() -> Command {
+    CommandBuilder::new(SLASH_COMMAND_NAME, "Great way to lose gold in your favorite game.", CommandType::ChatInput)
+        .option(
+            SubCommandBuilder::new("create", "Create a game in this channel")
+                .option(
+                    IntegerBuilder::new("amount", "Amount of gold to gamble").required(true).min_value(0)
+                )
+                .option(
+                    StringBuilder::new("mode", "Game mode, classic by default").choices(
+                        game_mode_choices()
+                    )
+                )
+        )
+        .option(
+            SubCommandBuilder::new("modes", "List the game modes, or explain one").option(
+                StringBuilder::new("mode", "Game mode to explain").choices(game_mode_choices())
+            )
+        )
+        .option(SubCommandBuilder::new("join", "Join a game in this channel").option(game_option()))
+        .option(SubCommandBuilder::new("play", "Start a game in this channel").option(game_option()))
+        .option(SubCommandBuilder::new("roll", "Roll").option(game_option()))
+        .option(SubCommandBuilder::new("hit", "Draw a card to your blackjack hand").option(game_option()))
+        .option(SubCommandBuilder::new("stand", "Keep your blackjack hand").option(game_option()))
+        .option(
+            SubCommandBuilder::new("double", "Double your wager for one last card").option(game_option())
+        )
+        .option(SubCommandBuilder::new("split", "Split a pair into two hands").option(game_option()))
+        .option(SubCommandBuilder::new("info", "List information about a game").option(game_option()))
+        .option(
+            SubCommandBuilder::new("leave", "Leave the game, forfeiting if it already started").option(
+                game_option()
+            )
+        )
+        .option(
+            SubCommandBuilder::new("cancel", "Cancel the game (creator or moderators only)").option(
+                game_option()
+            )
+        )
+        .option(
+            SubCommandBuilder::new(
+                "kick",
+                "Remove a player before the game starts (creator or moderators only)"
+            )
+                .option(UserBuilder::new("user", "Player to kick").required(true))
+                .option(game_option())
+        )
+        .option(
+            SubCommandBuilder::new(
+                "verify",
+                "Recompute every roll of a finished game from its revealed seed"
+            ).option(StringBuilder::new("game", "Id of the finished game").required(true))
+        )
+        .option(SubCommandBuilder::new("owed", "List who owes you gold"))
+        .option(
+            SubCommandBuilder::new("owes", "List who a player owes gold to").option(
+                UserBuilder::new("user", "Player to look up").required(true)
+            )
+        )
+        .option(SubCommandBuilder::new("ledger", "List the latest games played in this server"))
+        .option(
+            SubCommandBuilder::new(
+                "settle",
+                "List the fewest payments that settle every debt in this server"
+            )
+        )
+        .option(
+            SubCommandBuilder::new("stats", "Show a player's statistics").option(
+                UserBuilder::new("user", "Player to look up, yourself by default")
+            )
+        )
+        .option(
+            SubCommandBuilder::new("leaderboard", "Show the best players of the period").option(
+                StringBuilder::new("period", "Period to rank, this week by default").choices([
+                    ("week", "week"),
+                    ("month", "month"),
+                    ("all", "all"),
+                ])
+            )
+        )
+        .option(
+            SubCommandBuilder::new("paid", "Tell a player you paid them back")
+                .option(UserBuilder::new("user", "Player you paid").required(true))
+                .option(
+                    IntegerBuilder::new("amount", "Amount of gold you paid").required(true).min_value(1)
+                )
+        )
+        .option(
+            SubCommandBuilder::new("confirm", "Confirm you received a payment").option(
+                UserBuilder::new("user", "Player who paid you")
+            )
+        )
+        .option(
+            SubCommandBuilder::new("config", "Show or change this server's settings (Manage Server only)")
+                .option(
+                    StringBuilder::new("action", "Whether to read or change a setting").choices([
+                        ("get", "get"),
+                        ("set", "set"),
+                    ])
+                )
+                .option(
+                    StringBuilder::new("key", "Setting to read or change").choices(
+                        CONFIG_KEYS.iter().map(|key| (*key, key.to_string()))
+                    )
+                )
+                .option(StringBuilder::new("value", "New value, or `default` to reset it"))
+        )
+        .option(SubCommandBuilder::new("help", "List all available commands"))
+        .build()
+}
+
+/// Parses a message starting with the guild's prefix, e.g. `g!create 5000`.
+pub fn parse_prefixed_message(content: &str, prefix: &str) -> Option<ParsedCommand> {
+    let mut parts = content.trim().split_ascii_whitespace();
+    let name = parts.next()?.strip_prefix(prefix)?;
+
+    Some(ParsedCommand {
+        name: name.to_string(),
+        args: parts.map(String::from).collect(),
+    })
+}
+
+/// Parses a `/gamble <subcommand>` interaction, flattening its typed options into arguments in the
+/// order the subcommand declares them. Subcommands and options `/gamble` doesn't declare are turned
+/// down.
+pub fn parse_command_data(data: &CommandData) -> Option<ParsedCommand> {
+    if data.name != SLASH_COMMAND_NAME {
+        return None;
+    }
+
+    let subcommand = data.options.first()?;
+
+    let CommandOptionValue::SubCommand(options) = &subcommand.value else {
+        return None;
+    };
+
+    let declared = gamble_command()
+        .options
+        .into_iter()
+        .find(|option| option.name == subcommand.name)?
+        .options
+        .unwrap_or_default();
+
+    if options.iter().any(|option| declared.iter().all(|declared| declared.name != option.name)) {
+        return None;
+    }
+
+    let args = declared
+        .iter()
+        .filter_map(|declared| options.iter().find(|option| option.name == declared.name))
+        .filter_map(|option| {
+            match &option.value {
+                CommandOptionValue::Integer(value) => Some(value.to_string()),
+                CommandOptionValue::String(value) => Some(value.clone()),
+                CommandOptionValue::User(user_id) => Some(format!("<@{}>", user_id)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    Some(ParsedCommand {
+        name: subcommand.name.clone(),
+        args,
+    })
+}
+
+fn button(command: &str, label: &str, style: ButtonStyle) -> Component {
+    Component::Button(Button {
+        custom_id: Some(format!("{}{}", BUTTON_ID_PREFIX, command)),
+        disabled: false,
+        emoji: None,
+        label: Some(label.to_string()),
+        style,
+        url: None,
+        sku_id: None,
+    })
+}
+
+/// Join, Start, Roll and Info buttons attached to game announcements and boards, scoped to the
+/// game. Modes with moves of their own get a button for each instead of Roll. Labels are in the
+/// guild's language.
+pub fn game_buttons(game_id: &str, mode: &GameMode, messages: &Messages) -> Vec<Component> {
+    let moves = if mode.actions.is_empty() { &[("roll", MessageKey::ButtonRoll)][..] } else { mode.actions };
+    let label = |key: MessageKey| messages.get(key, &[]);
+
+    let mut buttons = vec![
+        button(&format!("join:{}", game_id), &label(MessageKey::ButtonJoin), ButtonStyle::Primary),
+        button(&format!("play:{}", game_id), &label(MessageKey::ButtonStart), ButtonStyle::Success)
+    ];
+
+    buttons.extend(
+        moves
+            .iter()
+            .map(|(action, key)| button(&format!("{}:{}", action, game_id), &label(*key), ButtonStyle::Danger))
+    );
+    buttons.push(button(&format!("info:{}", game_id), &label(MessageKey::ButtonInfo), ButtonStyle::Secondary));
+
+    return buttons
+        .chunks(BUTTONS_PER_ROW)
+        .map(|components| Component::ActionRow(ActionRow { components: components.to_vec() }))
+        .collect();
+}
+
+/// Confirm button attached to a payment claim, scoped to the player who made the claim.
+pub fn confirm_payment_buttons(payer: &PlayerId, messages: &Messages) -> Vec<Component> {
+    let label = messages.get(MessageKey::ButtonConfirm, &[]);
+
+    vec![
+        Component::ActionRow(ActionRow {
+            components: vec![
+                button(&format!("confirm:<@{}>", payer), &label, ButtonStyle::Success)
+            ],
+        })
+    ]
+}
+
+/// Parses a click on one of the buttons, e.g. `gamble:roll:<game id>` or `gamble:confirm:<@payer id>`.
+pub fn parse_component_data(data: &MessageComponentInteractionData) -> Option<ParsedCommand> {
+    let mut parts = data.custom_id.strip_prefix(BUTTON_ID_PREFIX)?.split(':');
+    let name = parts.next()?;
+    let args = parts.map(String::from).collect::<Vec<String>>();
+
+    if name.is_empty() || args.iter().any(String::is_empty) {
+        return None;
+    }
+
+    Some(ParsedCommand {
+        name: name.to_string(),
+        args,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::{
+        application::interaction::application_command::CommandDataOption,
+        channel::message::component::ComponentType,
+        id::Id,
+    };
+
+    use super::*;
+
+    fn slash(subcommand: &str, options: Vec<(&str, CommandOptionValue)>) -> CommandData {
+        let options = options
+            .into_iter()
+            .map(|(name, value)| CommandDataOption { name: name.to_string(), value })
+            .collect();
+
+        CommandData {
+            guild_id: None,
+            id: Id::new(1),
+            name: SLASH_COMMAND_NAME.to_string(),
+            kind: CommandType::ChatInput,
+            options: vec![CommandDataOption {
+                name: subcommand.to_string(),
+                value: CommandOptionValue::SubCommand(options),
+            }],
+            resolved: None,
+            target_id: None,
+        }
+    }
+
+    fn click(custom_id: &str) -> MessageComponentInteractionData {
+        MessageComponentInteractionData {
+            custom_id: custom_id.to_string(),
+            component_type: ComponentType::Button,
+            resolved: None,
+            values: vec![],
+        }
+    }
+
+    fn parsed(command: Option<ParsedCommand>) -> Option<(String, Vec<String>)> {
+        command.map(|command| (command.name, command.args))
+    }
+
+    #[test]
+    fn every_subcommand_parses() {
+        for subcommand in gamble_command().options {
+            assert_eq!(
+                parsed(parse_command_data(&slash(&subcommand.name, vec![]))),
+                Some((subcommand.name.clone(), vec![])),
+                "{} doesn't parse",
+                subcommand.name
+            );
+        }
+    }
+
+    #[test]
+    fn options_become_arguments_in_the_order_they_are_declared() {
+        let create = slash(
+            "create",
+            vec![
+                ("mode", CommandOptionValue::String("deathroll".into())),
+                ("amount", CommandOptionValue::Integer(500))
+            ]
+        );
+        assert_eq!(
+            parsed(parse_command_data(&create)),
+            Some(("create".into(), vec!["500".into(), "deathroll".into()]))
+        );
+
+        let kick = slash(
+            "kick",
+            vec![
+                ("game", CommandOptionValue::String("1a2b3c4d".into())),
+                ("user", CommandOptionValue::User(Id::new(102)))
+            ]
+        );
+        assert_eq!(
+            parsed(parse_command_data(&kick)),
+            Some(("kick".into(), vec!["<@102>".into(), "1a2b3c4d".into()]))
+        );
+    }
+
+    #[test]
+    fn missing_options_are_left_for_the_command_to_report() {
+        let paid = slash("paid", vec![("amount", CommandOptionValue::Integer(100))]);
+
+        assert_eq!(parsed(parse_command_data(&paid)), Some(("paid".into(), vec!["100".into()])));
+    }
+
+    #[test]
+    fn undeclared_options_and_subcommands_are_turned_down() {
+        let unknown_option = slash("roll", vec![("amount", CommandOptionValue::Integer(100))]);
+        assert!(parse_command_data(&unknown_option).is_none());
+
+        assert!(parse_command_data(&slash("steal", vec![])).is_none());
+
+        let mut not_a_subcommand = slash("roll", vec![]);
+        not_a_subcommand.options[0].value = CommandOptionValue::String("roll".into());
+        assert!(parse_command_data(&not_a_subcommand).is_none());
+
+        let mut no_subcommand = slash("roll", vec![]);
+        no_subcommand.options.clear();
+        assert!(parse_command_data(&no_subcommand).is_none());
+
+        let mut other_command = slash("roll", vec![]);
+        other_command.name = "other".into();
+        assert!(parse_command_data(&other_command).is_none());
+    }
+
+    #[test]
+    fn buttons_carry_their_game_or_payer() {
+        assert_eq!(
+            parsed(parse_component_data(&click("gamble:roll:1a2b3c4d"))),
+            Some(("roll".into(), vec!["1a2b3c4d".into()]))
+        );
+        assert_eq!(
+            parsed(parse_component_data(&click("gamble:confirm:<@101>"))),
+            Some(("confirm".into(), vec!["<@101>".into()]))
+        );
+    }
+
+    #[test]
+    fn malformed_button_ids_are_turned_down() {
+        for custom_id in ["roll:1a2b3c4d", "other:roll:1a2b3c4d", "gamble:", "gamble::1a2b3c4d", "gamble:roll:"] {
+            assert!(parse_component_data(&click(custom_id)).is_none(), "{} parses", custom_id);
+        }
+    }
+
+    #[test]
+    fn game_buttons_parse_back_into_their_command() {
+        let mode = &GAME_MODES[0];
+
+        for row in game_buttons("1a2b3c4d", mode, &Messages::default()) {
+            let Component::ActionRow(row) = row else {
+                panic!("buttons come in action rows");
+            };
+
+            for button in row.components {
+                let Component::Button(button) = button else {
+                    panic!("action rows only hold buttons");
+                };
+
+                let (_, args) = parsed(parse_component_data(&click(&button.custom_id.unwrap()))).unwrap();
+                assert_eq!(args, vec!["1a2b3c4d".to_string()]);
+            }
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use std::{ collections::HashMap, sync::{ Arc, Mutex } };
+
+use tokio::{
+    sync::mpsc::{ UnboundedReceiver, UnboundedSender, error::SendError, unbounded_channel },
+    task::JoinHandle,
+};
+use tracing::error;
+use twilight_model::{
+    application::interaction::Interaction,
+    id::{ Id, marker::{ ChannelMarker, MessageMarker } },
+};
+
+use crate::{
+    Board,
+    IDLE_CHECK_INTERVAL,
+    Reply,
+    build_board,
+    commands::ParsedCommand,
+    gamble_game_manager::{ CommandContext, GambleGameManager },
+    guild_config::GuildConfig,
+    handle_command,
+    messages::MessageKey,
+    map_responses,
+    options::IdleTimeouts,
+    respond_to_interaction,
+    transport::MessageSink,
+};
+
+/// A command for a channel's task, along with how to answer it.
+pub(crate) enum Job {
+    Message(CommandContext, ParsedCommand),
+    Interaction(CommandContext, ParsedCommand, Box<Interaction>),
+    /// A `create` to play in a new thread off the command's message, named after its author.
+    Thread(CommandContext, ParsedCommand, Id<MessageMarker>, String),
+    /// Picks the channel's games back up from their snapshots, as the channel's previous task died.
+    Restore,
+}
+
+/// A channel's task and the mailbox feeding it.
+type ChannelTask = (UnboundedSender<Job>, JoinHandle<()>);
+
+/// Runs the games of each channel in their own task, so a busy channel or a slow HTTP call only
+/// holds up that channel. Games played in a thread run in the thread's task.
+///
+/// Tasks are started on the first command sent to a channel (or on startup for channels with
+/// restored games) and end once the channel has no games left and goes quiet, or on `shutdown`.
+/// A task that died is started again on the channel's next command.
+/// Clones share the same tasks, which lets a channel's task hand the games it opens threads for to
+/// the thread's task.
+pub struct Dispatcher<S> {
+    shared: Arc<Shared<S>>,
+}
+
+struct Shared<S> {
+    tasks: Mutex<HashMap<Id<ChannelMarker>, ChannelTask>>,
+    /// Forked for every new channel so they all share the same ledger, store and seeds.
+    template: GambleGameManager,
+    sink: Arc<S>,
+    timeouts: IdleTimeouts,
+}
+
+impl<S> Clone for Dispatcher<S> {
+    fn clone(&self) -> Self {
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl<S: MessageSink + 'static> Dispatcher<S> {
+    /// Starts a task for each channel `manager` has games in.
+    pub fn new(manager: GambleGameManager, sink: Arc<S>, timeouts: IdleTimeouts) -> Self {
+        let dispatcher = Self {
+            shared: Arc::new(Shared {
+                tasks: Default::default(),
+                template: manager.fork(),
+                sink,
+                timeouts,
+            }),
+        };
+
+        for (channel_id, manager) in manager.into_channels() {
+            match channel_id.parse::<Id<ChannelMarker>>() {
+                Ok(channel_id) => {
+                    let task = dispatcher.spawn(channel_id, manager);
+                    dispatcher.shared.tasks.lock().unwrap().insert(channel_id, task);
+                }
+                Err(why) => error!(?why, channel_id, "gamble: restored game has an invalid channel id"),
+            }
+        }
+
+        dispatcher
+    }
+
+    fn spawn(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        manager: GambleGameManager
+    ) -> ChannelTask {
+        let (sender, jobs) = unbounded_channel();
+        let task = tokio::spawn(run_channel(channel_id, manager, jobs, self.clone()));
+
+        (sender, task)
+    }
+
+    /// Needed before dispatching, to tell which messages are commands.
+    pub(crate) fn guild_config(&self, guild_id: Option<&String>) -> GuildConfig {
+        self.shared.template.guild_config(guild_id)
+    }
+
+    pub(crate) fn dispatch(&self, channel_id: Id<ChannelMarker>, job: Job) {
+        let mut tasks = self.shared.tasks.lock().unwrap();
+        let (sender, _) = tasks
+            .entry(channel_id)
+            .or_insert_with(|| self.spawn(channel_id, self.shared.template.fork()));
+
+        let Err(SendError(job)) = sender.send(job) else {
+            return;
+        };
+
+        // Only a panic ends a task without forgetting it, and its games went down with it.
+        error!(%channel_id, "gamble: channel task died, starting it again");
+        let (sender, task) = self.spawn(channel_id, self.shared.template.fork());
+
+        // The new task is alive and its mailbox is open, so neither can fail.
+        let _ = sender.send(Job::Restore);
+        let _ = sender.send(job);
+
+        tasks.insert(channel_id, (sender, task));
+    }
+
+    /// Forgets the task of a channel without games, unless a command for it arrived meanwhile.
+    /// Returns whether the task can end.
+    fn retire(&self, channel_id: Id<ChannelMarker>, jobs: &UnboundedReceiver<Job>) -> bool {
+        // Commands are queued under the same lock, so none can slip in once the task is forgotten.
+        let mut tasks = self.shared.tasks.lock().unwrap();
+
+        if !jobs.is_empty() {
+            return false;
+        }
+
+        // After `shutdown` took it, the entry may belong to the channel's next task.
+        if tasks.get(&channel_id).is_some_and(|(_, task)| task.id() == tokio::task::id()) {
+            tasks.remove(&channel_id);
+        }
+
+        return true;
+    }
+
+    /// Lets every channel task finish the commands it already received, then stops them.
+    pub async fn shutdown(self) {
+        // Finishing a command can start the task of a new thread, so go again until none are left.
+        loop {
+            let tasks = std::mem::take(&mut *self.shared.tasks.lock().unwrap());
+
+            if tasks.is_empty() {
+                return;
+            }
+
+            for (channel_id, (sender, task)) in tasks {
+                drop(sender);
+
+                if let Err(why) = task.await {
+                    error!(?why, %channel_id, "gamble: channel task panicked");
+                }
+            }
+        }
+    }
+}
+
+/// Answers the commands of one channel in order, and checks its games for idle players. Ends once
+/// the channel went a whole `IDLE_CHECK_INTERVAL` without games or commands.
+async fn run_channel<S: MessageSink + 'static>(
+    channel_id: Id<ChannelMarker>,
+    mut manager: GambleGameManager,
+    mut jobs: UnboundedReceiver<Job>,
+    dispatcher: Dispatcher<S>
+) {
+    let (sink, timeouts) = (dispatcher.shared.sink.clone(), dispatcher.shared.timeouts);
+    let mut interval = tokio::time::interval(IDLE_CHECK_INTERVAL);
+    // Idle updates aren't answering anybody, so they go by the latest command's permissions.
+    let mut can_embed = false;
+    // Whether a command came in since the last check.
+    let mut active = true;
+
+    loop {
+        tokio::select! {
+            job = jobs.recv() => {
+                active = true;
+
+                match job {
+                    Some(Job::Message(ctx, command)) => {
+                        can_embed = ctx.can_embed;
+                        let Some((replies, board)) = off_runtime(channel_id, &mut manager, move |manager| {
+                            handle_command(manager, &ctx, command)
+                        }).await else {
+                            continue;
+                        };
+                        post_board(sink.as_ref(), channel_id, &mut manager, board).await;
+                        send_replies(sink.as_ref(), channel_id, replies).await;
+                    }
+                    Some(Job::Interaction(ctx, command, interaction)) => {
+                        can_embed = ctx.can_embed;
+                        let Some((replies, board)) = off_runtime(channel_id, &mut manager, move |manager| {
+                            handle_command(manager, &ctx, command)
+                        }).await else {
+                            continue;
+                        };
+                        post_board(sink.as_ref(), channel_id, &mut manager, board).await;
+                        respond_to_interaction(sink.as_ref(), &interaction, replies).await;
+                    }
+                    Some(Job::Thread(ctx, command, message_id, author)) => {
+                        let guild_id = ctx.guild_id.clone();
+                        let Some(name) = off_runtime(channel_id, &mut manager, move |manager| {
+                            manager
+                                .guild_config(guild_id.as_ref())
+                                .messages()
+                                .get(MessageKey::ThreadName, &[("player", &author)])
+                        }).await else {
+                            continue;
+                        };
+
+                        match sink.create_thread(channel_id, message_id, &name).await {
+                            Ok(thread_id) => {
+                                let ctx = CommandContext { channel_id: thread_id.to_string(), ..ctx };
+                                dispatcher.dispatch(thread_id, Job::Message(ctx, command));
+                            }
+                            // Better a game in the channel than no game at all.
+                            Err(why) => {
+                                error!(?why, "gamble: failed to open a thread for the game");
+                                can_embed = ctx.can_embed;
+                                let Some((replies, board)) = off_runtime(channel_id, &mut manager, move |manager| {
+                                    handle_command(manager, &ctx, command)
+                                }).await else {
+                                    continue;
+                                };
+                                post_board(sink.as_ref(), channel_id, &mut manager, board).await;
+                                send_replies(sink.as_ref(), channel_id, replies).await;
+                            }
+                        }
+                    }
+                    Some(Job::Restore) => {
+                        restore_channel(channel_id, &mut manager).await;
+                    }
+                    None => {
+                        return;
+                    }
+                }
+            }
+            _ = interval.tick() => {
+                if !manager.has_games() {
+                    if !active && dispatcher.retire(channel_id, &jobs) {
+                        return;
+                    }
+
+                    active = false;
+                    continue;
+                }
+
+                let updates = off_runtime(channel_id, &mut manager, move |manager| {
+                    expire_idle_games(manager, &timeouts, can_embed)
+                }).await.unwrap_or_default();
+
+                for (board, replies) in updates {
+                    post_board(sink.as_ref(), channel_id, &mut manager, board).await;
+                    send_replies(sink.as_ref(), channel_id, replies).await;
+                }
+            }
+        }
+    }
+}
+
+/// Runs `work` on a blocking thread, as it reads and writes the database. The manager is lent to
+/// it for the duration.
+///
+/// When `work` panics, the manager goes down with it: the channel's games are restored from their
+/// last snapshots instead, and `None` is returned.
+async fn off_runtime<T: Send + 'static>(
+    channel_id: Id<ChannelMarker>,
+    manager: &mut GambleGameManager,
+    work: impl FnOnce(&mut GambleGameManager) -> T + Send + 'static
+) -> Option<T> {
+    let mut lent = std::mem::replace(manager, manager.fork());
+    let task = tokio::task::spawn_blocking(move || {
+        let result = work(&mut lent);
+        (lent, result)
+    });
+
+    match task.await {
+        Ok((lent, result)) => {
+            *manager = lent;
+            return Some(result);
+        }
+        Err(why) => {
+            error!(?why, %channel_id, "gamble: command panicked, restoring the channel's games");
+            restore_channel(channel_id, manager).await;
+            return None;
+        }
+    }
+}
+
+/// Replaces the games of `manager` with the channel's last snapshots.
+async fn restore_channel(channel_id: Id<ChannelMarker>, manager: &mut GambleGameManager) {
+    let mut restored = manager.fork();
+    let task = tokio::task::spawn_blocking(move || {
+        restored.restore_channel(&channel_id.to_string());
+        restored
+    });
+
+    match task.await {
+        Ok(restored) => *manager = restored,
+        Err(why) => error!(?why, %channel_id, "gamble: failed to restore the channel's games"),
+    }
+}
+
+/// Applies the idle timeouts, returning the board to update and the replies to post for every
+/// game they affected.
+fn expire_idle_games(
+    manager: &mut GambleGameManager,
+    timeouts: &IdleTimeouts,
+    can_embed: bool
+) -> Vec<(Option<Board>, Vec<Reply>)> {
+    return manager
+        .expire_idle_games(timeouts)
+        .into_iter()
+        .map(|update| {
+            let config = manager.guild_config(update.guild_id.as_ref());
+            let board = build_board(
+                manager,
+                Some(&update.game_id),
+                true,
+                &update.creator,
+                &update.responses,
+                &config,
+                can_embed
+            );
+            let replies = map_responses(&update.creator, update.responses, &config, can_embed);
+
+            (board.map(|board| Board { message_id: update.board, ..board }), replies)
+        })
+        .collect();
+}
+
+/// Edits the board of a game in place, or posts it if the game doesn't have one yet.
+async fn post_board(
+    sink: &impl MessageSink,
+    channel_id: Id<ChannelMarker>,
+    manager: &mut GambleGameManager,
+    board: Option<Board>
+) {
+    let Some(board) = board else {
+        return;
+    };
+
+    match board.message_id.and_then(|message_id| message_id.parse::<Id<MessageMarker>>().ok()) {
+        Some(message_id) => {
+            if let Err(why) = sink.edit_message(channel_id, message_id, &board.reply).await {
+                error!(?why, "gamble: failed to update board");
+            }
+        }
+        // Games that end before their board was posted don't get one.
+        None if board.finished => {}
+        None => {
+            match sink.send_message(channel_id, &board.reply).await {
+                Ok(message_id) => {
+                    let game_id = board.game_id;
+                    off_runtime(channel_id, manager, move |manager| {
+                        manager.set_board(&game_id, message_id.to_string())
+                    }).await;
+                }
+                Err(why) => error!(?why, "gamble: failed to post board"),
+            }
+        }
+    }
+}
+
+async fn send_replies(sink: &impl MessageSink, channel_id: Id<ChannelMarker>, replies: Vec<Reply>) {
+    for reply in replies {
+        if let Err(why) = sink.send_message(channel_id, &reply).await {
+            error!(?why, "gamble: failed to send message");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{ ALICE, BOB, CHANNEL_ID, ctx, manager };
+
+    #[tokio::test]
+    async fn a_panicking_command_does_not_lose_the_channels_games() {
+        let mut manager = manager();
+        manager.execute(&ctx(ALICE), "create", vec!["500"]).unwrap();
+        let game_id = manager.find_game(&ctx(ALICE), &[]).unwrap();
+
+        let result = off_runtime(CHANNEL_ID.parse().unwrap(), &mut manager, |_| panic!("the command went wrong")).await;
+        assert!(result.is_none());
+
+        manager.execute(&ctx(BOB), "join", vec![]).unwrap();
+        assert_eq!(manager.game(&game_id).unwrap().players, vec![ALICE.to_string(), BOB.to_string()]);
+    }
+}
@@ -0,0 +1,89 @@
+use twilight_util::builder::embed::{ EmbedBuilder, EmbedFieldBuilder };
+
+use crate::{
+    gamble_game::GameStatus,
+    gamble_game_manager::GGMResponse,
+    guild_config::GuildConfig,
+    messages::MessageKey,
+    utils::{ fmt_amount, fmt_discord_name },
+};
+
+const WAITING_COLOR: u32 = 0x3498db;
+const ONGOING_COLOR: u32 = 0xf1c40f;
+const DONE_COLOR: u32 = 0x2ecc71;
+const CANCELLED_COLOR: u32 = 0xe74c3c;
+
+fn status_color(status: &GameStatus) -> u32 {
+    match status {
+        GameStatus::INITIATED => WAITING_COLOR,
+        GameStatus::ONGOING => ONGOING_COLOR,
+        GameStatus::DONE => DONE_COLOR,
+        GameStatus::CANCELLED => CANCELLED_COLOR,
+    }
+}
+
+/// The embed a response is posted as, colored by the status of its game. Only game
+/// announcements, rolls and results get one; the plain text message goes in its description.
+pub fn build_embed(response: &GGMResponse, config: &GuildConfig) -> Option<EmbedBuilder> {
+    let messages = config.messages();
+    let field = |key: MessageKey, value: String| EmbedFieldBuilder::new(messages.template(key), value);
+
+    match response {
+        GGMResponse::ShowJoinInfo(..) => {
+            return Some(EmbedBuilder::new().color(WAITING_COLOR));
+        }
+        GGMResponse::Started |
+        GGMResponse::PlayerRolled(_) |
+        GGMResponse::AutoRolled(..) |
+        GGMResponse::Played(_) |
+        GGMResponse::AutoPlayed(_) => {
+            return Some(EmbedBuilder::new().color(ONGOING_COLOR));
+        }
+        GGMResponse::Done(settlement, _, snapshot) => {
+            let mut embed = EmbedBuilder::new().color(DONE_COLOR);
+
+            // Settlements with several transfers already list them all in the description.
+            if let [debt] = settlement.as_slice() {
+                embed = embed
+                    .field(field(MessageKey::FieldWinner, fmt_discord_name(&debt.creditor)).inline())
+                    .field(field(MessageKey::FieldLoser, fmt_discord_name(&debt.debtor)).inline())
+                    .field(field(MessageKey::FieldDifference, fmt_amount(debt.amount)).inline());
+            }
+
+            if !snapshot.rolls.is_empty() {
+                let rolls = snapshot.rolls
+                    .iter()
+                    .map(|(player_id, value)| format!("{} **{}**", fmt_discord_name(player_id), fmt_amount(*value)))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+
+                embed = embed.field(field(MessageKey::FieldRolls, rolls));
+            }
+
+            return Some(embed);
+        }
+        GGMResponse::ShowGeneralInfo(snapshot) => {
+            let players = snapshot.players
+                .iter()
+                .map(fmt_discord_name)
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            let mut embed = EmbedBuilder::new()
+                .color(status_color(&snapshot.status))
+                .field(field(MessageKey::FieldWager, fmt_amount(snapshot.wager)).inline());
+
+            if !players.is_empty() {
+                embed = embed.field(field(MessageKey::FieldPlayers, players).inline());
+            }
+
+            return Some(embed);
+        }
+        GGMResponse::Cancelled(_) | GGMResponse::IdleCancelled(_) => {
+            return Some(EmbedBuilder::new().color(CANCELLED_COLOR));
+        }
+        _ => {
+            return None;
+        }
+    }
+}
@@ -1,9 +1,12 @@
+use tracing::error;
+
 use crate::types::PlayerId;
 
 #[derive(Debug)]
 pub enum GameError {
     CannotInitGame,
-    GameAlreadyExists,
+    /// The user didn't say which of these games the command is for.
+    SeveralGames(Vec<String>),
     NotEnoughPlayers(u64),
     GoldAmountTooSmall(u64),
     PlayerCannotRollOnAnInexistentGame,
@@ -18,4 +21,43 @@ pub enum GameError {
     PlayersMatchedHighestRoll(Vec<PlayerId>),
     NoWinnersFound,
     UnknownCommand,
+    MissingPlayerMention,
+    LedgerOnlyAvailableInGuilds,
+    LedgerUnavailable,
+    CannotPayYourself,
+    InvalidPaymentAmount,
+    NothingOwed(PlayerId),
+    PaymentExceedsDebt(u64),
+    NoPendingPayments,
+    UnknownPeriod,
+    UnknownGameMode,
+    PlayerNotPartOfGame,
+    NotYourTurn(PlayerId),
+    PlayerCannotLeaveAnInexistentGame,
+    PlayerCannotCancelAnInexistentGame,
+    PlayerCannotKickFromAnInexistentGame,
+    OnlyCreatorOrModerator,
+    CannotKickFromOngoingGame,
+    KickedPlayerNotPartOfGame(PlayerId),
+    WinnerCannotLeave,
+    PlayerCannotActOnAnInexistentGame,
+    NoHandToPlay,
+    CannotDouble,
+    CannotSplit,
+    HouseCannotLeave,
+    GameAlreadyOver,
+    MissingGameId,
+    UnknownGameId(String),
+    ConfigOnlyAvailableInGuilds,
+    OnlyGuildManagers,
+    UnknownConfigKey,
+    InvalidConfigValue(String),
+    ConfigUnavailable,
+}
+
+impl From<rusqlite::Error> for GameError {
+    fn from(why: rusqlite::Error) -> Self {
+        error!(?why, "gamble: ledger query failed");
+        GameError::LedgerUnavailable
+    }
 }
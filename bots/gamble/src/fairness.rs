@@ -0,0 +1,266 @@
+use std::sync::{ Arc, Mutex };
+
+use rand::Rng;
+use serde::{ Deserialize, Serialize };
+use sha2::{ Digest, Sha256 };
+
+use crate::types::{ PlayerId, RollValue };
+
+/// A roll derived from the server seed, kept so the whole game can be replayed by `g!verify`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FairRoll {
+    /// Who the roll was for. Tie-breaks that don't belong to a player use `TIE_BREAK`, and the
+    /// cards the house draws for itself use `DEALER`.
+    pub player_id: PlayerId,
+    pub nonce: u64,
+    pub min: RollValue,
+    pub max: RollValue,
+    pub value: RollValue,
+}
+
+pub const TIE_BREAK: &str = "tie-break";
+pub const DEALER: &str = "dealer";
+
+/// Commit-reveal dice: the hash of the server seed is published when the game is created and
+/// the seed itself once the game is over, so players can recompute every roll.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FairDice {
+    server_seed: String,
+    rolls: Vec<FairRoll>,
+}
+
+impl Default for FairDice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FairDice {
+    pub fn new() -> Self {
+        Self::from_seed(to_hex(&rand::rng().random::<[u8; 32]>()))
+    }
+
+    pub fn from_seed(server_seed: String) -> Self {
+        Self {
+            server_seed,
+            rolls: vec![],
+        }
+    }
+
+    pub fn seed_hash(&self) -> String {
+        hash_seed(&self.server_seed)
+    }
+
+    /// Must not be shown to players before the game is over.
+    pub fn server_seed(&self) -> &str {
+        &self.server_seed
+    }
+
+    pub fn rolls(&self) -> &[FairRoll] {
+        &self.rolls
+    }
+
+    /// Rolls between `min` and `max` inclusive. Every roll of the game uses the next nonce.
+    pub fn roll(&mut self, player_id: &str, min: RollValue, max: RollValue) -> RollValue {
+        let nonce = self.rolls.len() as u64;
+        let value = derive_roll(&self.server_seed, player_id, nonce, min, max);
+
+        self.rolls.push(FairRoll {
+            player_id: player_id.to_string(),
+            nonce,
+            min,
+            max,
+            value,
+        });
+
+        value
+    }
+}
+
+/// Hands out the server seeds of new games: random ones, or a fixed sequence so tests can
+/// predict every roll. Clones share the same sequence.
+#[derive(Clone, Default)]
+pub struct SeedSource {
+    fixed: Option<Arc<Mutex<Box<dyn Iterator<Item = String> + Send>>>>,
+}
+
+impl SeedSource {
+    /// Uses `seeds` in order, then falls back to random seeds once it runs out.
+    pub fn fixed(seeds: impl IntoIterator<Item = String, IntoIter: Send + 'static>) -> Self {
+        Self {
+            fixed: Some(Arc::new(Mutex::new(Box::new(seeds.into_iter())))),
+        }
+    }
+
+    pub fn dice(&self) -> FairDice {
+        let seed = self.fixed.as_ref().and_then(|seeds| seeds.lock().unwrap().next());
+
+        match seed {
+            Some(seed) => FairDice::from_seed(seed),
+            None => FairDice::new(),
+        }
+    }
+}
+
+/// Everything needed to check a finished game once its seed has been revealed.
+#[derive(Debug, Clone)]
+pub struct FairGame {
+    pub game_id: String,
+    pub seed_hash: String,
+    pub server_seed: String,
+    pub rolls: Vec<FairRoll>,
+}
+
+/// The result of recomputing a finished game from its revealed seed.
+pub struct Verification {
+    pub game: FairGame,
+    pub seed_matches_hash: bool,
+    /// Each recorded roll next to the value recomputed from the seed.
+    pub rolls: Vec<(FairRoll, RollValue)>,
+}
+
+impl Verification {
+    pub fn is_fair(&self) -> bool {
+        self.seed_matches_hash && self.rolls.iter().all(|(roll, value)| roll.value == *value)
+    }
+}
+
+impl FairGame {
+    pub fn verify(self) -> Verification {
+        let seed_matches_hash = hash_seed(&self.server_seed) == self.seed_hash;
+        let rolls = self.rolls
+            .iter()
+            .map(|roll| {
+                let value = derive_roll(
+                    &self.server_seed,
+                    &roll.player_id,
+                    roll.nonce,
+                    roll.min,
+                    roll.max
+                );
+                (roll.clone(), value)
+            })
+            .collect();
+
+        Verification {
+            game: self,
+            seed_matches_hash,
+            rolls,
+        }
+    }
+}
+
+pub fn hash_seed(server_seed: &str) -> String {
+    to_hex(&Sha256::digest(server_seed.as_bytes()))
+}
+
+/// `min + n % (max - min + 1)`, where `n` is the first 16 bytes (big endian) of
+/// `sha256("<server_seed>:<player_id>:<nonce>")`.
+pub fn derive_roll(
+    server_seed: &str,
+    player_id: &str,
+    nonce: u64,
+    min: RollValue,
+    max: RollValue
+) -> RollValue {
+    let digest = Sha256::digest(format!("{}:{}:{}", server_seed, player_id, nonce).as_bytes());
+    let n = u128::from_be_bytes(digest[..16].try_into().unwrap());
+    let range = ((max - min) as u128) + 1;
+
+    min + ((n % range) as RollValue)
+}
+
+/// The first of `seed-0`, `seed-1`, ... whose rolls satisfy `predicate`, to set up a game with
+/// known rolls through `SeedSource::fixed`.
+pub fn find_seed(predicate: impl Fn(&str) -> bool) -> Option<String> {
+    return (0..100_000).map(|i| format!("seed-{}", i)).find(|seed| predicate(seed));
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A finished game with a roll for each of two players.
+    fn fair_game() -> FairGame {
+        let mut dice = FairDice::from_seed("seed".into());
+        dice.roll("a", 0, 100);
+        dice.roll("b", 0, 100);
+
+        FairGame {
+            game_id: "0badf00d".into(),
+            seed_hash: dice.seed_hash(),
+            server_seed: dice.server_seed().to_string(),
+            rolls: dice.rolls().to_vec(),
+        }
+    }
+
+    #[test]
+    fn rolls_are_deterministic_and_within_bounds() {
+        for nonce in 0..200 {
+            let value = derive_roll("seed", "a", nonce, 5, 10);
+
+            assert_eq!(value, derive_roll("seed", "a", nonce, 5, 10));
+            assert!((5..=10).contains(&value));
+        }
+
+        assert_eq!(derive_roll("seed", "a", 0, 7, 7), 7);
+    }
+
+    #[test]
+    fn rolls_depend_on_the_seed_the_player_and_the_nonce() {
+        let rolls = |seed: &str, player_id: &str| {
+            (0..20).map(|nonce| derive_roll(seed, player_id, nonce, 0, 1_000_000)).collect::<Vec<RollValue>>()
+        };
+
+        assert_ne!(rolls("seed", "a"), rolls("other-seed", "a"));
+        assert_ne!(rolls("seed", "a"), rolls("seed", "b"));
+    }
+
+    #[test]
+    fn an_honest_game_is_fair() {
+        let verification = fair_game().verify();
+
+        assert!(verification.seed_matches_hash);
+        assert!(verification.rolls.iter().all(|(roll, value)| roll.value == *value));
+        assert!(verification.is_fair());
+    }
+
+    #[test]
+    fn an_altered_roll_is_caught() {
+        let mut game = fair_game();
+        game.rolls[1].value = (game.rolls[1].value + 1) % 101;
+
+        let verification = game.verify();
+
+        assert!(verification.seed_matches_hash);
+        assert_eq!(verification.rolls[0].0.value, verification.rolls[0].1);
+        assert_ne!(verification.rolls[1].0.value, verification.rolls[1].1);
+        assert!(!verification.is_fair());
+    }
+
+    #[test]
+    fn a_seed_swapped_after_the_fact_is_caught() {
+        let mut game = fair_game();
+        game.server_seed = "other-seed".into();
+
+        assert!(!game.verify().is_fair());
+    }
+
+    #[test]
+    fn a_commitment_to_another_seed_is_caught() {
+        let mut game = fair_game();
+        game.seed_hash = hash_seed("other-seed");
+
+        let verification = game.verify();
+
+        assert!(!verification.seed_matches_hash);
+        assert!(!verification.is_fair());
+    }
+}
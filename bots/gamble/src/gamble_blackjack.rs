@@ -0,0 +1,561 @@
+use serde::{ Deserialize, Serialize };
+
+use crate::{
+    cards::{ Card, Hand, Shoe, total },
+    error::GameError,
+    fairness::{ DEALER, FairDice },
+    gamble_game::{ GambleGame, GameSnapshot, GameStatus },
+    game_mode::GameRules,
+    ledger::Debt,
+    types::{ PlayerId, RollValue },
+};
+
+const DECKS: usize = 6;
+/// The dealer draws until reaching this total, soft totals included.
+const DEALER_STANDS_ON: u64 = 17;
+/// Most hands a player can split into.
+const MAX_HANDS: usize = 4;
+
+/// Every player plays their hands against the dealer at the same time. The creator is the house:
+/// they collect the wagers of the hands the dealer beats and pay out the ones that beat the dealer.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GambleBlackjack {
+    /// The creator, who banks every hand instead of playing one.
+    house: PlayerId,
+    /// Join order, which is also the order hands are dealt in.
+    players: Vec<PlayerId>,
+    status: GameStatus,
+    wager: RollValue,
+    dice: FairDice,
+    shoe: Shoe,
+    hands: Vec<Hand>,
+    dealer: Vec<Card>,
+    rules: GameRules,
+}
+
+impl GambleBlackjack {
+    pub fn new(
+        player_id: String,
+        wager: u64,
+        rules: GameRules,
+        dice: FairDice
+    ) -> Result<Self, GameError> {
+        if wager < rules.min_wager {
+            return Err(GameError::GoldAmountTooSmall(rules.min_wager));
+        }
+
+        Ok(Self {
+            house: player_id,
+            players: vec![],
+            status: GameStatus::INITIATED,
+            wager,
+            dice,
+            shoe: Shoe::new(DECKS),
+            hands: vec![],
+            dealer: vec![],
+            rules,
+        })
+    }
+
+    /// Two cards to every player and to the dealer, one at a time.
+    fn deal(&mut self) {
+        self.hands = self.players
+            .iter()
+            .map(|player_id| Hand::new(player_id.clone(), self.wager))
+            .collect();
+
+        for _ in 0..2 {
+            for index in 0..self.hands.len() {
+                self.hit(index);
+            }
+
+            let card = self.shoe.draw(&mut self.dice, DEALER);
+            self.dealer.push(card);
+        }
+
+        // Nobody gets to play against a dealer blackjack.
+        if self.is_dealer_blackjack() {
+            for hand in &mut self.hands {
+                hand.done = true;
+            }
+        }
+    }
+
+    /// Draws a card to a hand, which is done once it reaches 21 or busts.
+    fn hit(&mut self, index: usize) {
+        let card = self.shoe.draw(&mut self.dice, &self.hands[index].player_id);
+        let hand = &mut self.hands[index];
+
+        hand.cards.push(card);
+        hand.done = hand.total() >= 21;
+    }
+
+    /// The first of the player's hands they can still draw to.
+    fn current_hand(&self, player_id: &PlayerId) -> Result<usize, GameError> {
+        if self.status != GameStatus::ONGOING {
+            return Err(GameError::NoHandToPlay);
+        }
+
+        return self.hands
+            .iter()
+            .position(|hand| hand.player_id == *player_id && !hand.done)
+            .ok_or(GameError::NoHandToPlay);
+    }
+
+    fn split(&mut self, index: usize) -> Result<(), GameError> {
+        let hand = self.hands[index].clone();
+        let player_hands = self.hands
+            .iter()
+            .filter(|other| other.player_id == hand.player_id)
+            .count();
+
+        let pair = hand.cards.len() == 2 && hand.cards[0].value() == hand.cards[1].value();
+
+        if !pair || player_hands >= MAX_HANDS {
+            return Err(GameError::CannotSplit);
+        }
+
+        let card = self.hands[index].cards.pop().unwrap();
+        let mut other = Hand::new(hand.player_id, hand.wager);
+
+        other.cards.push(card);
+        other.split = true;
+        self.hands[index].split = true;
+        self.hands.insert(index + 1, other);
+
+        for index in [index, index + 1] {
+            self.hit(index);
+
+            // Split aces only get one more card each.
+            if card.rank == 1 {
+                self.hands[index].done = true;
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Stops every hand of the player, either standing them or giving them up.
+    fn end_hands(&mut self, player_id: &PlayerId, forfeit: bool) -> Result<(), GameError> {
+        self.current_hand(player_id)?;
+
+        for hand in self.hands.iter_mut().filter(|hand| hand.player_id == *player_id && !hand.done) {
+            hand.done = true;
+            hand.forfeited = forfeit;
+        }
+
+        return Ok(());
+    }
+
+    fn is_dealer_blackjack(&self) -> bool {
+        self.dealer.len() == 2 && total(&self.dealer) == 21
+    }
+
+    /// What the house owes the player for a hand, negative when the player owes the house.
+    fn hand_result(&self, hand: &Hand) -> i64 {
+        let wager = hand.wager as i64;
+        let dealer = total(&self.dealer);
+
+        if hand.forfeited || hand.is_bust() {
+            return -wager;
+        }
+
+        match (hand.is_blackjack(), self.is_dealer_blackjack()) {
+            (true, true) => 0,
+            (true, false) => (wager * 3) / 2,
+            (false, true) => -wager,
+            (false, false) if dealer > 21 || hand.total() > dealer => wager,
+            (false, false) if hand.total() < dealer => -wager,
+            (false, false) => 0,
+        }
+    }
+}
+
+#[typetag::serde(name = "blackjack")]
+impl GambleGame for GambleBlackjack {
+    fn add_player(&mut self, player_id: String) -> Result<(), GameError> {
+        if self.status != GameStatus::INITIATED {
+            return Err(GameError::PlayerCannotJoinOngoingGame);
+        }
+
+        if player_id == self.house || self.players.contains(&player_id) {
+            return Err(GameError::PlayerAlreadyPartOfGame);
+        }
+
+        self.players.push(player_id);
+
+        Ok(())
+    }
+
+    fn start(&mut self) -> Result<(), GameError> {
+        if self.status != GameStatus::INITIATED {
+            return Err(GameError::CannotInitGame);
+        }
+
+        // The house counts towards the minimum even though it doesn't get a hand.
+        if self.players.len() + 1 < self.rules.min_players.try_into().unwrap() {
+            return Err(GameError::NotEnoughPlayers(self.rules.min_players));
+        }
+
+        self.deal();
+        self.status = GameStatus::ONGOING;
+
+        Ok(())
+    }
+
+    fn roll(&mut self, _player_id: String) -> Result<RollValue, GameError> {
+        Err(GameError::PlayerCannotRoll)
+    }
+
+    fn act(&mut self, player_id: PlayerId, action: &str) -> Result<(), GameError> {
+        let index = self.current_hand(&player_id)?;
+
+        match action {
+            "hit" => {
+                self.hit(index);
+            }
+            "stand" => {
+                self.hands[index].done = true;
+            }
+            "double" => {
+                if self.hands[index].cards.len() != 2 {
+                    return Err(GameError::CannotDouble);
+                }
+
+                self.hands[index].wager *= 2;
+                self.hit(index);
+                self.hands[index].done = true;
+            }
+            "split" => {
+                self.split(index)?;
+            }
+            _ => {
+                return Err(GameError::UnknownCommand);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stands the player's hands.
+    fn auto_play(&mut self, player_id: PlayerId) -> Result<Option<RollValue>, GameError> {
+        self.end_hands(&player_id, false)?;
+
+        Ok(None)
+    }
+
+    /// Gives up the player's hands, losing their wagers.
+    fn forfeit(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        self.end_hands(player_id, true)
+    }
+
+    fn remove_player(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        // There's nobody to play against without the house.
+        if *player_id == self.house {
+            match self.status {
+                GameStatus::INITIATED => {
+                    self.status = GameStatus::CANCELLED;
+                    return Ok(());
+                }
+                GameStatus::ONGOING => {
+                    return Err(GameError::HouseCannotLeave);
+                }
+                _ => {
+                    return Err(GameError::GameAlreadyOver);
+                }
+            }
+        }
+
+        let Some(index) = self.players.iter().position(|id| id == player_id) else {
+            return Err(GameError::PlayerNotPartOfGame);
+        };
+
+        match self.status {
+            GameStatus::INITIATED => {
+                self.players.remove(index);
+            }
+            GameStatus::ONGOING => {
+                for hand in self.hands.iter_mut().filter(|hand| hand.player_id == *player_id) {
+                    hand.done = true;
+                    hand.forfeited = true;
+                }
+            }
+            _ => {
+                return Err(GameError::GameAlreadyOver);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn cancel(&mut self) -> Result<(), GameError> {
+        if self.status == GameStatus::DONE || self.status == GameStatus::CANCELLED {
+            return Err(GameError::GameAlreadyOver);
+        }
+
+        self.status = GameStatus::CANCELLED;
+
+        Ok(())
+    }
+
+    /// Once every hand is done, the dealer plays theirs and the game is over.
+    fn update(&mut self) -> Result<&GameStatus, GameError> {
+        if self.status != GameStatus::ONGOING || self.hands.iter().any(|hand| !hand.done) {
+            return Ok(&self.status);
+        }
+
+        // The dealer only draws when some hand could still beat them.
+        let contested = self.hands
+            .iter()
+            .any(|hand| !hand.forfeited && !hand.is_bust() && !hand.is_blackjack());
+
+        while contested && total(&self.dealer) < DEALER_STANDS_ON {
+            let card = self.shoe.draw(&mut self.dice, DEALER);
+            self.dealer.push(card);
+        }
+
+        self.status = GameStatus::DONE;
+
+        Ok(&self.status)
+    }
+
+    fn dice(&self) -> &FairDice {
+        &self.dice
+    }
+
+    fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    fn pending_players(&self) -> Vec<PlayerId> {
+        if self.status != GameStatus::ONGOING {
+            return vec![];
+        }
+
+        self.players
+            .iter()
+            .filter(|player_id| self.hands.iter().any(|hand| hand.player_id == **player_id && !hand.done))
+            .cloned()
+            .collect()
+    }
+
+    fn snapshot(&self) -> GameSnapshot {
+        // The dealer's second card stays face down until the players are done.
+        let dealer = match self.status {
+            GameStatus::ONGOING => self.dealer.iter().take(1).copied().collect(),
+            _ => self.dealer.clone(),
+        };
+
+        GameSnapshot {
+            mode: "blackjack",
+            status: self.status.clone(),
+            wager: self.wager,
+            players: [self.house.clone()].into_iter().chain(self.players.iter().cloned()).collect(),
+            pending: self.pending_players(),
+            rolls: vec![],
+            max_roll: None,
+            settlement: self.settlement().unwrap_or_default(),
+            hands: self.hands.clone(),
+            dealer,
+        }
+    }
+
+    /// Each player's hands are netted into a single payment to or from the house.
+    fn settlement(&self) -> Option<Vec<Debt>> {
+        if self.status != GameStatus::DONE {
+            return None;
+        }
+
+        let settlement = self.players
+            .iter()
+            .filter_map(|player_id| {
+                let net = self.hands
+                    .iter()
+                    .filter(|hand| hand.player_id == *player_id)
+                    .map(|hand| self.hand_result(hand))
+                    .sum::<i64>();
+
+                let (debtor, creditor) = if net < 0 {
+                    (player_id.clone(), self.house.clone())
+                } else {
+                    (self.house.clone(), player_id.clone())
+                };
+
+                (net != 0).then_some(Debt { debtor, creditor, amount: net.unsigned_abs() })
+            })
+            .collect();
+
+        Some(settlement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WAGER: RollValue = 100;
+
+    fn cards(ranks: &[u8]) -> Vec<Card> {
+        ranks.iter().map(|&rank| Card { rank, suit: 0 }).collect()
+    }
+
+    fn hand(ranks: &[u8]) -> Hand {
+        let mut hand = Hand::new("a".into(), WAGER);
+        hand.cards = cards(ranks);
+        hand
+    }
+
+    /// A game in which `a` plays `hands` against the dealer's `dealer` cards.
+    fn ongoing(hands: Vec<Hand>, dealer: &[u8]) -> GambleBlackjack {
+        let rules = GameRules { min_players: 2, min_wager: 1 };
+        let mut game = GambleBlackjack::new("house".into(), WAGER, rules, FairDice::from_seed("seed".into())).unwrap();
+
+        game.players = vec!["a".into()];
+        game.status = GameStatus::ONGOING;
+        game.hands = hands;
+        game.dealer = cards(dealer);
+
+        game
+    }
+
+    fn result(ranks: &[u8], dealer: &[u8]) -> i64 {
+        ongoing(vec![], dealer).hand_result(&hand(ranks))
+    }
+
+    #[test]
+    fn snapshots_have_no_roll_limit_as_nobody_rolls() {
+        assert_eq!(ongoing(vec![hand(&[10, 8])], &[10, 7]).snapshot().max_roll, None);
+    }
+
+    #[test]
+    fn a_blackjack_pays_three_to_two() {
+        assert_eq!(result(&[1, 13], &[10, 10]), 150);
+    }
+
+    #[test]
+    fn equal_totals_push() {
+        assert_eq!(result(&[10, 9], &[10, 9]), 0);
+        assert_eq!(result(&[1, 13], &[1, 10]), 0);
+    }
+
+    #[test]
+    fn a_dealer_blackjack_beats_a_twenty_one_of_more_cards() {
+        assert_eq!(result(&[7, 7, 7], &[1, 10]), -100);
+    }
+
+    #[test]
+    fn a_player_who_busts_loses_even_when_the_dealer_busts() {
+        assert_eq!(result(&[10, 10, 5], &[10, 6, 10]), -100);
+    }
+
+    #[test]
+    fn hands_still_standing_win_when_the_dealer_busts() {
+        assert_eq!(result(&[10, 2], &[10, 6, 10]), 100);
+    }
+
+    #[test]
+    fn a_split_twenty_one_is_paid_even_money() {
+        let mut split = hand(&[1, 13]);
+        split.split = true;
+
+        assert_eq!(ongoing(vec![], &[10, 10]).hand_result(&split), 100);
+    }
+
+    #[test]
+    fn forfeited_hands_lose_their_wager() {
+        let mut forfeited = hand(&[10, 10]);
+        forfeited.forfeited = true;
+
+        assert_eq!(ongoing(vec![], &[10, 7]).hand_result(&forfeited), -100);
+    }
+
+    #[test]
+    fn splitting_a_pair_plays_two_hands_of_the_same_wager() {
+        let mut game = ongoing(vec![hand(&[8, 8])], &[10, 7]);
+
+        game.act("a".into(), "split").unwrap();
+
+        assert_eq!(game.hands.len(), 2);
+        for hand in &game.hands {
+            assert_eq!(hand.cards.len(), 2);
+            assert_eq!(hand.cards[0].rank, 8);
+            assert_eq!(hand.wager, WAGER);
+            assert!(hand.split);
+        }
+    }
+
+    #[test]
+    fn split_aces_get_a_single_card_each() {
+        let mut game = ongoing(vec![hand(&[1, 1])], &[10, 7]);
+
+        game.act("a".into(), "split").unwrap();
+
+        assert!(game.hands.iter().all(|hand| hand.cards.len() == 2 && hand.done));
+        assert!(game.pending_players().is_empty());
+    }
+
+    #[test]
+    fn only_pairs_split_and_only_up_to_the_hand_limit() {
+        let mut game = ongoing(vec![hand(&[8, 9])], &[10, 7]);
+        assert!(matches!(game.act("a".into(), "split"), Err(GameError::CannotSplit)));
+
+        let mut game = ongoing(vec![hand(&[8, 8]); MAX_HANDS], &[10, 7]);
+        assert!(matches!(game.act("a".into(), "split"), Err(GameError::CannotSplit)));
+        assert_eq!(game.hands.len(), MAX_HANDS);
+    }
+
+    #[test]
+    fn doubling_doubles_the_wager_for_a_single_card() {
+        let mut game = ongoing(vec![hand(&[5, 6])], &[10, 7]);
+
+        game.act("a".into(), "double").unwrap();
+
+        assert_eq!(game.hands[0].wager, 2 * WAGER);
+        assert_eq!(game.hands[0].cards.len(), 3);
+        assert!(game.hands[0].done);
+    }
+
+    #[test]
+    fn only_two_card_hands_can_double() {
+        let mut game = ongoing(vec![hand(&[2, 3, 4])], &[10, 7]);
+
+        assert!(matches!(game.act("a".into(), "double"), Err(GameError::CannotDouble)));
+        assert_eq!(game.hands[0].wager, WAGER);
+    }
+
+    #[test]
+    fn the_dealer_stands_on_seventeen_soft_ones_included() {
+        for dealer in [[10, 7], [1, 6]] {
+            let mut standing = hand(&[10, 8]);
+            standing.done = true;
+            let mut game = ongoing(vec![standing], &dealer);
+
+            assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+            assert_eq!(game.dealer, cards(&dealer));
+        }
+    }
+
+    #[test]
+    fn the_dealer_draws_to_seventeen_while_a_hand_can_beat_them() {
+        let mut standing = hand(&[10, 8]);
+        standing.done = true;
+        let mut game = ongoing(vec![standing], &[10, 6]);
+
+        game.update().unwrap();
+
+        assert!(game.dealer.len() > 2);
+        assert!(total(&game.dealer) >= DEALER_STANDS_ON);
+    }
+
+    #[test]
+    fn the_dealer_does_not_draw_against_hands_that_already_lost() {
+        let mut bust = hand(&[10, 10, 5]);
+        bust.done = true;
+        let mut game = ongoing(vec![bust], &[10, 2]);
+
+        game.update().unwrap();
+
+        assert_eq!(game.dealer, cards(&[10, 2]));
+        assert_eq!(game.settlement().unwrap(), vec![Debt::new("a", "house", WAGER)]);
+    }
+}
@@ -1,21 +1,22 @@
 use std::collections::{ HashMap, HashSet };
 
-use rand::{ SeedableRng, distr::{ Distribution, Uniform }, rngs::StdRng };
+use serde::{ Deserialize, Serialize };
 
 use crate::{
     error::GameError,
-    gamble_game::{ GambleGame, GameStatus },
+    fairness::{ FairDice, TIE_BREAK },
+    gamble_game::{ GambleGame, GameSnapshot, GameStatus },
+    game_mode::GameRules,
+    ledger::Debt,
     types::{ PlayerId, RollValue },
-    utils::fmt_amount,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GambleClassic {
     players_by_roll: HashMap<RollValue, Vec<PlayerId>>,
     players: HashSet<PlayerId>,
     status: GameStatus,
-    die: Uniform<RollValue>,
-    rng: StdRng,
+    dice: FairDice,
     winners_to_reroll: HashSet<PlayerId>,
     losers_to_reroll: HashSet<PlayerId>,
     winner: Option<PlayerId>,
@@ -23,23 +24,31 @@ pub struct GambleClassic {
     loser: Option<PlayerId>,
     losing_roll: Option<RollValue>,
     max_roll: RollValue,
+    rules: GameRules,
+    /// Players who gave up their roll. They lose ties instead of rerolling.
+    forfeited: HashSet<PlayerId>,
+    /// Everybody still taking part in the game, whether or not they have rolled this round.
+    joined: HashSet<PlayerId>,
 }
 
-const MIN_GOLD_AMOUNT: u64 = 100;
-const MIN_AMOUNT_OF_PLAYERS: u64 = 2; // TODO: put this back at 2
+const FORFEITED_ROLL: RollValue = 0;
 
 impl GambleClassic {
-    pub fn new(player_id: String, max_roll_value: u64) -> Result<Self, GameError> {
-        if max_roll_value < MIN_GOLD_AMOUNT {
-            return Err(GameError::GoldAmountTooSmall(MIN_GOLD_AMOUNT));
+    pub fn new(
+        player_id: String,
+        max_roll_value: u64,
+        rules: GameRules,
+        dice: FairDice
+    ) -> Result<Self, GameError> {
+        if max_roll_value < rules.min_wager {
+            return Err(GameError::GoldAmountTooSmall(rules.min_wager));
         }
 
         Ok(Self {
             players_by_roll: Default::default(),
-            players: HashSet::from_iter([player_id]),
+            players: HashSet::from_iter([player_id.clone()]),
             status: GameStatus::INITIATED,
-            die: Uniform::new_inclusive(0, max_roll_value).unwrap(),
-            rng: StdRng::from_os_rng(),
+            dice,
             winners_to_reroll: Default::default(),
             losers_to_reroll: Default::default(),
             winner: None,
@@ -47,14 +56,43 @@ impl GambleClassic {
             max_roll: max_roll_value,
             losing_roll: Default::default(),
             winning_roll: Default::default(),
+            rules,
+            forfeited: Default::default(),
+            joined: HashSet::from_iter([player_id]),
         })
     }
 
     fn players_are_done_rolling(&self) -> bool {
-        self.players.len() == 0
+        self.players.is_empty()
+    }
+
+    /// Picks a single player out of those who matched a roll, or returns who needs to reroll.
+    ///
+    /// Forfeited players never win a tie and always lose one. When only forfeited players are
+    /// left in a tie they can't reroll, so one of them is picked at random. Players who left are
+    /// never handed the win: `update` leaves them out of the contenders.
+    fn break_tie(&mut self, matched: Vec<PlayerId>, losing: bool) -> Result<PlayerId, Vec<PlayerId>> {
+        let (mut forfeited, played): (Vec<PlayerId>, Vec<PlayerId>) = matched
+            .into_iter()
+            .partition(|player_id| self.forfeited.contains(player_id));
+
+        let prefer_forfeited = if losing { !forfeited.is_empty() } else { played.is_empty() };
+
+        if !prefer_forfeited {
+            return match played.len() {
+                1 => Ok(played[0].clone()),
+                _ => Err(played),
+            };
+        }
+
+        forfeited.sort();
+        let index = self.dice.roll(TIE_BREAK, 0, (forfeited.len() - 1) as RollValue) as usize;
+
+        Ok(forfeited.swap_remove(index))
     }
 }
 
+#[typetag::serde(name = "classic")]
 impl GambleGame for GambleClassic {
     fn add_player(&mut self, player_id: String) -> Result<(), GameError> {
         if self.status != GameStatus::INITIATED {
@@ -66,6 +104,7 @@ impl GambleGame for GambleClassic {
         }
 
         self.players.insert(player_id.clone());
+        self.joined.insert(player_id);
 
         Ok(())
     }
@@ -75,8 +114,8 @@ impl GambleGame for GambleClassic {
             return Err(GameError::CannotInitGame);
         }
 
-        if self.players.len() < MIN_AMOUNT_OF_PLAYERS.try_into().unwrap() {
-            return Err(GameError::NotEnoughPlayers(MIN_AMOUNT_OF_PLAYERS));
+        if self.players.len() < self.rules.min_players.try_into().unwrap() {
+            return Err(GameError::NotEnoughPlayers(self.rules.min_players));
         }
 
         self.status = GameStatus::ONGOING;
@@ -96,124 +135,346 @@ impl GambleGame for GambleClassic {
         // Removing the player indicates they've now rolled.
         self.players.remove(&player_id);
 
-        let roll_value = self.die.sample(&mut self.rng);
+        let roll_value = self.dice.roll(&player_id, 0, self.max_roll);
 
-        if let Some(players) = self.players_by_roll.get_mut(&roll_value) {
-            players.push(player_id);
-        } else {
-            self.players_by_roll.insert(roll_value, vec![player_id]);
-        }
+        self.players_by_roll.entry(roll_value).or_default().push(player_id);
 
         Ok(roll_value)
     }
 
+    fn forfeit(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        if self.status != GameStatus::ONGOING {
+            return Err(GameError::PlayerCannotRoll);
+        }
+
+        if !self.players.remove(player_id) {
+            return Err(GameError::PlayerAlreadyRolled);
+        }
+
+        self.forfeited.insert(player_id.clone());
+        self.players_by_roll.entry(FORFEITED_ROLL).or_default().push(player_id.clone());
+
+        Ok(())
+    }
+
+    fn remove_player(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        match self.status {
+            GameStatus::INITIATED => {
+                if !self.players.remove(player_id) {
+                    return Err(GameError::PlayerNotPartOfGame);
+                }
+
+                self.joined.remove(player_id);
+
+                if self.players.is_empty() {
+                    self.status = GameStatus::CANCELLED;
+                }
+
+                return Ok(());
+            }
+            GameStatus::ONGOING => {}
+            _ => {
+                return Err(GameError::GameAlreadyOver);
+            }
+        }
+
+        if !self.joined.contains(player_id) {
+            return Err(GameError::PlayerNotPartOfGame);
+        }
+
+        // The winner has nothing left to do but wait for the losers' reroll.
+        if self.winner.as_ref() == Some(player_id) {
+            return Err(GameError::WinnerCannotLeave);
+        }
+
+        self.joined.remove(player_id);
+        self.winners_to_reroll.remove(player_id);
+        self.forfeited.insert(player_id.clone());
+
+        // Nobody is left to win or to pay.
+        if self.joined.is_empty() {
+            self.status = GameStatus::CANCELLED;
+            return Ok(());
+        }
+
+        // A player still expected to roll this round gives up their roll, and one who already
+        // rolled has it replaced. Either way they end up with the worst roll of the round.
+        if self.players.remove(player_id) {
+            self.players_by_roll.entry(FORFEITED_ROLL).or_default().push(player_id.clone());
+            return Ok(());
+        }
+
+        let rolled = self.players_by_roll
+            .iter()
+            .find(|(_, players)| players.contains(player_id))
+            .map(|(roll_value, _)| *roll_value);
+
+        if let Some(roll_value) = rolled {
+            let players = self.players_by_roll.get_mut(&roll_value).unwrap();
+            players.retain(|id| id != player_id);
+
+            if players.is_empty() {
+                self.players_by_roll.remove(&roll_value);
+            }
+
+            self.players_by_roll.entry(FORFEITED_ROLL).or_default().push(player_id.clone());
+        }
+
+        // Players waiting on the losers' reroll stay in `losers_to_reroll`: being marked as
+        // forfeited is enough for them to lose it. Anybody else is already out of contention.
+        Ok(())
+    }
+
+    fn cancel(&mut self) -> Result<(), GameError> {
+        if self.status == GameStatus::DONE || self.status == GameStatus::CANCELLED {
+            return Err(GameError::GameAlreadyOver);
+        }
+
+        self.status = GameStatus::CANCELLED;
+
+        Ok(())
+    }
+
     fn update(&mut self) -> Result<&GameStatus, GameError> {
         // if not ongoing or players still rolling continue
         if self.status != GameStatus::ONGOING || !self.players_are_done_rolling() {
             return Ok(&self.status);
         }
 
-        let mut rolls = self.players_by_roll.keys().collect::<Vec<&RollValue>>();
-        rolls.sort();
+        let round = std::mem::take(&mut self.players_by_roll);
 
-        let highest_roll = rolls[rolls.len() - 1];
-        let winners = self.players_by_roll.get(highest_roll).unwrap().clone();
-        let lowest_roll = rolls[0];
-        let losers = self.players_by_roll.get(lowest_roll).unwrap().clone();
+        let highest_roll = *round.keys().max().unwrap();
+        let lowest_roll = *round.keys().min().unwrap();
 
-        // Done only once to avoid applying rerolls later on.
-        if self.winning_roll.is_none() {
-            self.winning_roll = Some(*highest_roll);
-        }
+        // Until the winner is known, every round (first roll or winners' reroll) decides the winner.
+        // Once it is known, the remaining rounds are the losers' reroll.
+        if self.winner.is_none() {
+            // Done only once to avoid applying rerolls later on.
+            if self.winning_roll.is_none() {
+                self.winning_roll = Some(highest_roll);
+                self.losing_roll = Some(lowest_roll);
+                self.losers_to_reroll = HashSet::from_iter(round[&lowest_roll].clone());
+            }
 
-        if self.losing_roll.is_none() {
-            self.losing_roll = Some(*lowest_roll);
-        }
+            let mut contenders = round[&highest_roll]
+                .iter()
+                .filter(|player_id| self.joined.contains(*player_id))
+                .cloned()
+                .collect::<Vec<PlayerId>>();
 
-        self.winners_to_reroll = HashSet::from_iter(winners.clone());
-        self.losers_to_reroll = HashSet::from_iter(losers.clone());
-        self.players_by_roll.clear();
+            // Everybody tied for the win left, so it goes to the players still in the game.
+            if contenders.is_empty() {
+                contenders = self.joined.iter().cloned().collect();
+                contenders.sort();
+            }
 
-        if self.winners_to_reroll.len() > 1 {
-            self.players_by_roll.clear();
-            self.players = self.winners_to_reroll.clone();
-            return Err(GameError::PlayersMatchedHighestRoll(winners));
+            match self.break_tie(contenders, false) {
+                Ok(winner) => {
+                    self.winners_to_reroll.clear();
+                    // When everybody tied, the winner is also among the lowest rolls.
+                    self.losers_to_reroll.remove(&winner);
+                    self.winner = Some(winner);
+                }
+                Err(winners) => {
+                    self.winners_to_reroll = HashSet::from_iter(winners.clone());
+                    self.players = self.winners_to_reroll.clone();
+                    return Err(GameError::PlayersMatchedHighestRoll(winners));
+                }
+            }
         } else {
-            self.winner = self.winners_to_reroll.iter().last().cloned();
+            self.losers_to_reroll = HashSet::from_iter(round[&lowest_roll].clone());
         }
 
-        if self.losers_to_reroll.len() > 1 {
-            self.players_by_roll.clear();
-            self.players = self.losers_to_reroll.clone();
-            return Err(GameError::PlayersMatchedLowestRoll(losers.clone()));
-        } else {
-            self.loser = self.losers_to_reroll.iter().last().cloned();
+        let mut losers = self.losers_to_reroll.iter().cloned().collect::<Vec<PlayerId>>();
+
+        // The winner was the only one left to pay, so one of the players who left pays instead.
+        if losers.is_empty() {
+            losers = self.forfeited
+                .iter()
+                .filter(|player_id| !self.joined.contains(*player_id))
+                .cloned()
+                .collect();
         }
 
-        if self.winner.is_some() && self.loser.is_some() {
-            self.status = GameStatus::DONE;
+        match self.break_tie(losers, true) {
+            Ok(loser) => {
+                self.losers_to_reroll.clear();
+                self.loser = Some(loser);
+            }
+            Err(losers) => {
+                self.players = self.losers_to_reroll.clone();
+                return Err(GameError::PlayersMatchedLowestRoll(losers));
+            }
         }
 
+        self.status = GameStatus::DONE;
+
         Ok(&self.status)
     }
 
-    /**
-     * @deprecated gamble_classic should have no knowledge of discord formatting.
-     * @todo make a mapper function for each game style to generate info based on game state.
-     */
-    fn info(&self) -> String {
+    fn dice(&self) -> &FairDice {
+        &self.dice
+    }
+
+    fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    fn pending_players(&self) -> Vec<PlayerId> {
         match self.status {
-            GameStatus::INITIATED => {
-                let joined_players = self.players
-                    .iter()
-                    .map(|player_id| format!("- <@{}>", player_id))
-                    .collect::<Vec<String>>();
-
-                let joined_players_message = format!("*Players who have already joined*\n{}", if
-                    joined_players.len() > 0
-                {
-                    joined_players.join("\n")
-                } else {
-                    "- No players have joined yet".into()
-                });
-
-                return format!(
-                    ":moneybag: __Ongoing Game!__\nFor **{}** gold!\n\n{}\n\n*Next steps*\n- `g!join` to join\n- `g!play` to start the game",
-                    fmt_amount(self.max_roll),
-                    joined_players_message
-                );
-            }
-            GameStatus::ONGOING => {
-                let players_that_still_need_to_roll: String = self.players
-                    .iter()
-                    .map(|player_id| {
-                        format!("- <@{}> still needs to roll! (i.e.: g!roll)", player_id)
-                    })
-                    .collect::<Vec<String>>()
-                    .join("\n");
-
-                return format!("Game is ongoing!\n{}", players_that_still_need_to_roll);
-            }
-            GameStatus::DONE => {
-                return format!(
-                    "<@{}> owes <@{}> {} gold!",
-                    self.loser.clone().unwrap(),
-                    self.winner.clone().unwrap(),
-                    fmt_amount(self.max_roll)
-                );
-            }
+            GameStatus::ONGOING => self.players.iter().cloned().collect(),
+            _ => vec![],
         }
     }
 
-    fn wl(&self) -> Option<(PlayerId, PlayerId, RollValue)> {
+    fn snapshot(&self) -> GameSnapshot {
+        let mut players = self.joined.iter().cloned().collect::<Vec<PlayerId>>();
+        players.sort();
+
+        let mut pending = self.pending_players();
+        pending.sort();
+
+        GameSnapshot {
+            mode: "classic",
+            status: self.status.clone(),
+            wager: self.max_roll,
+            players,
+            pending,
+            rolls: GameSnapshot::rolls_of(&self.dice),
+            max_roll: Some(self.max_roll),
+            settlement: self.settlement().unwrap_or_default(),
+            hands: vec![],
+            dealer: vec![],
+        }
+    }
+
+    /// The lowest roll owes the highest roll the difference between both rolls.
+    fn settlement(&self) -> Option<Vec<Debt>> {
         if self.winner.is_some() && self.loser.is_some() {
-            return Some((
-                self.winner.clone().unwrap(),
-                self.loser.clone().unwrap(),
-                self.winning_roll.unwrap() - self.losing_roll.unwrap(),
-            ));
+            return Some(vec![Debt {
+                debtor: self.loser.clone().unwrap(),
+                creditor: self.winner.clone().unwrap(),
+                amount: self.winning_roll.unwrap() - self.losing_roll.unwrap(),
+            }]);
         }
 
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fairness::{ derive_roll, find_seed };
+
+    const MAX_ROLL: RollValue = 100;
+
+    fn roll(seed: &str, player_id: &str, nonce: u64) -> RollValue {
+        derive_roll(seed, player_id, nonce, 0, MAX_ROLL)
+    }
+
+    /// A started game in which `a`, `b` and `c` rolled, in that order.
+    fn rolled_game(seed: String) -> GambleClassic {
+        let rules = GameRules { min_players: 2, min_wager: 1 };
+        let mut game = GambleClassic::new("a".into(), MAX_ROLL, rules, FairDice::from_seed(seed)).unwrap();
+
+        for player_id in ["b", "c"] {
+            game.add_player(player_id.into()).unwrap();
+        }
+
+        game.start().unwrap();
+
+        for player_id in ["a", "b", "c"] {
+            game.roll(player_id.into()).unwrap();
+        }
+
+        game
+    }
+
+    #[test]
+    fn leaving_the_winners_reroll_hands_the_win_to_the_other_player() {
+        // a and b tie for the highest roll.
+        let seed = find_seed(|seed| {
+            roll(seed, "a", 0) == roll(seed, "b", 1) && roll(seed, "b", 1) > roll(seed, "c", 2)
+        }).unwrap();
+        let mut game = rolled_game(seed.clone());
+
+        assert!(matches!(game.update(), Err(GameError::PlayersMatchedHighestRoll(_))));
+
+        game.remove_player(&"a".into()).unwrap();
+        assert_eq!(game.pending_players(), vec!["b".to_string()]);
+
+        game.roll("b".into()).unwrap();
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+
+        assert_eq!(
+            game.settlement().unwrap(),
+            vec![Debt::new("c", "b", roll(&seed, "b", 1) - roll(&seed, "c", 2))]
+        );
+    }
+
+    #[test]
+    fn leaving_the_losers_reroll_loses_it() {
+        // b and c tie for the lowest roll.
+        let seed = find_seed(|seed| {
+            roll(seed, "a", 0) > roll(seed, "b", 1) && roll(seed, "b", 1) == roll(seed, "c", 2)
+        }).unwrap();
+        let mut game = rolled_game(seed.clone());
+
+        assert!(matches!(game.update(), Err(GameError::PlayersMatchedLowestRoll(_))));
+
+        game.remove_player(&"b".into()).unwrap();
+        assert_eq!(game.pending_players(), vec!["c".to_string()]);
+
+        game.roll("c".into()).unwrap();
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+
+        assert_eq!(
+            game.settlement().unwrap(),
+            vec![Debt::new("b", "a", roll(&seed, "a", 0) - roll(&seed, "b", 1))]
+        );
+    }
+
+    #[test]
+    fn players_who_left_the_winners_reroll_never_win_it() {
+        // a and b tie for the highest roll.
+        let seed = find_seed(|seed| {
+            roll(seed, "a", 0) == roll(seed, "b", 1) && roll(seed, "b", 1) > roll(seed, "c", 2)
+        }).unwrap();
+        let mut game = rolled_game(seed);
+
+        assert!(game.update().is_err());
+
+        game.remove_player(&"a".into()).unwrap();
+        game.remove_player(&"b".into()).unwrap();
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+
+        let settlement = game.settlement().unwrap();
+        assert_eq!(settlement[0].creditor, "c");
+        assert!(["a", "b"].contains(&settlement[0].debtor.as_str()));
+    }
+
+    #[test]
+    fn everybody_leaving_cancels_the_game() {
+        let mut game = rolled_game(find_seed(|_| true).unwrap());
+
+        for player_id in ["a", "b", "c"] {
+            game.remove_player(&player_id.into()).unwrap();
+        }
+
+        assert_eq!(game.update().unwrap(), &GameStatus::CANCELLED);
+        assert!(game.settlement().is_none());
+    }
+
+    #[test]
+    fn the_winner_cannot_leave_the_losers_reroll() {
+        // b and c tie for the lowest roll.
+        let seed = find_seed(|seed| {
+            roll(seed, "a", 0) > roll(seed, "b", 1) && roll(seed, "b", 1) == roll(seed, "c", 2)
+        }).unwrap();
+        let mut game = rolled_game(seed);
+
+        assert!(game.update().is_err());
+        assert!(matches!(game.remove_player(&"a".into()), Err(GameError::WinnerCannotLeave)));
+    }
+}
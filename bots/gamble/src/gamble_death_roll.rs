@@ -0,0 +1,324 @@
+use serde::{ Deserialize, Serialize };
+
+use crate::{
+    error::GameError,
+    fairness::FairDice,
+    gamble_game::{ GambleGame, GameSnapshot, GameStatus },
+    game_mode::GameRules,
+    ledger::Debt,
+    types::{ PlayerId, RollValue },
+};
+
+/// Players take turns rolling 1..previous roll, starting from the wager.
+/// Whoever rolls a 1 owes the wager to the player who rolled right before them.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GambleDeathRoll {
+    /// Join order, which is also the turn order.
+    players: Vec<PlayerId>,
+    status: GameStatus,
+    wager: RollValue,
+    max_roll: RollValue,
+    turn: usize,
+    dice: FairDice,
+    winner: Option<PlayerId>,
+    loser: Option<PlayerId>,
+    rules: GameRules,
+}
+const LOSING_ROLL: RollValue = 1;
+
+impl GambleDeathRoll {
+    pub fn new(
+        player_id: String,
+        wager: u64,
+        rules: GameRules,
+        dice: FairDice
+    ) -> Result<Self, GameError> {
+        if wager < rules.min_wager {
+            return Err(GameError::GoldAmountTooSmall(rules.min_wager));
+        }
+
+        Ok(Self {
+            players: vec![player_id],
+            status: GameStatus::INITIATED,
+            wager,
+            max_roll: wager,
+            turn: 0,
+            dice,
+            winner: None,
+            loser: None,
+            rules,
+        })
+    }
+
+    fn current_player(&self) -> &PlayerId {
+        &self.players[self.turn]
+    }
+
+    fn previous_player(&self) -> &PlayerId {
+        &self.players[(self.turn + self.players.len() - 1) % self.players.len()]
+    }
+}
+
+#[typetag::serde(name = "deathroll")]
+impl GambleGame for GambleDeathRoll {
+    fn add_player(&mut self, player_id: String) -> Result<(), GameError> {
+        if self.status != GameStatus::INITIATED {
+            return Err(GameError::PlayerCannotJoinOngoingGame);
+        }
+
+        if self.players.contains(&player_id) {
+            return Err(GameError::PlayerAlreadyPartOfGame);
+        }
+
+        self.players.push(player_id);
+
+        Ok(())
+    }
+
+    fn start(&mut self) -> Result<(), GameError> {
+        if self.status != GameStatus::INITIATED {
+            return Err(GameError::CannotInitGame);
+        }
+
+        if self.players.len() < self.rules.min_players.try_into().unwrap() {
+            return Err(GameError::NotEnoughPlayers(self.rules.min_players));
+        }
+
+        self.status = GameStatus::ONGOING;
+
+        Ok(())
+    }
+
+    fn roll(&mut self, player_id: String) -> Result<RollValue, GameError> {
+        if self.status != GameStatus::ONGOING {
+            return Err(GameError::PlayerCannotRoll);
+        }
+
+        if !self.players.contains(&player_id) {
+            return Err(GameError::PlayerNotPartOfGame);
+        }
+
+        if *self.current_player() != player_id {
+            return Err(GameError::NotYourTurn(self.current_player().clone()));
+        }
+
+        let roll_value = self.dice.roll(&player_id, LOSING_ROLL, self.max_roll);
+
+        if roll_value == LOSING_ROLL {
+            self.winner = Some(self.previous_player().clone());
+            self.loser = Some(player_id);
+        } else {
+            self.max_roll = roll_value;
+            self.turn = (self.turn + 1) % self.players.len();
+        }
+
+        Ok(roll_value)
+    }
+
+    fn forfeit(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        if self.status != GameStatus::ONGOING {
+            return Err(GameError::PlayerCannotRoll);
+        }
+
+        if self.current_player() != player_id {
+            return Err(GameError::NotYourTurn(self.current_player().clone()));
+        }
+
+        // Forfeiting is as good as rolling a 1.
+        self.winner = Some(self.previous_player().clone());
+        self.loser = Some(player_id.clone());
+
+        Ok(())
+    }
+
+    fn remove_player(&mut self, player_id: &PlayerId) -> Result<(), GameError> {
+        let Some(index) = self.players.iter().position(|id| id == player_id) else {
+            return Err(GameError::PlayerNotPartOfGame);
+        };
+
+        match self.status {
+            GameStatus::INITIATED => {
+                self.players.remove(index);
+
+                if self.players.is_empty() {
+                    self.status = GameStatus::CANCELLED;
+                }
+            }
+            GameStatus::ONGOING if self.loser.is_none() => {
+                // Leaving is as good as rolling a 1 on your own turn.
+                let previous = (index + self.players.len() - 1) % self.players.len();
+                self.winner = Some(self.players[previous].clone());
+                self.loser = Some(player_id.clone());
+            }
+            _ => {
+                return Err(GameError::GameAlreadyOver);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn cancel(&mut self) -> Result<(), GameError> {
+        if self.status == GameStatus::DONE || self.status == GameStatus::CANCELLED {
+            return Err(GameError::GameAlreadyOver);
+        }
+
+        self.status = GameStatus::CANCELLED;
+
+        Ok(())
+    }
+
+    fn update(&mut self) -> Result<&GameStatus, GameError> {
+        if self.status == GameStatus::ONGOING && self.loser.is_some() {
+            self.status = GameStatus::DONE;
+        }
+
+        Ok(&self.status)
+    }
+
+    fn dice(&self) -> &FairDice {
+        &self.dice
+    }
+
+    fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    fn pending_players(&self) -> Vec<PlayerId> {
+        match self.status {
+            GameStatus::ONGOING if self.loser.is_none() => vec![self.current_player().clone()],
+            _ => vec![],
+        }
+    }
+
+    fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            mode: "deathroll",
+            status: self.status.clone(),
+            wager: self.wager,
+            players: self.players.clone(),
+            pending: self.pending_players(),
+            rolls: GameSnapshot::rolls_of(&self.dice),
+            max_roll: Some(self.max_roll),
+            settlement: self.settlement().unwrap_or_default(),
+            hands: vec![],
+            dealer: vec![],
+        }
+    }
+
+    /// Whoever rolled the 1 owes the whole wager to the player who rolled before them.
+    fn settlement(&self) -> Option<Vec<Debt>> {
+        match (&self.winner, &self.loser) {
+            (Some(winner), Some(loser)) =>
+                Some(vec![Debt { debtor: loser.clone(), creditor: winner.clone(), amount: self.wager }]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fairness::{ derive_roll, find_seed };
+
+    const WAGER: RollValue = 100;
+
+    /// A started game between `a`, `b` and `c`, who take turns in that order.
+    fn started_game(seed: String) -> GambleDeathRoll {
+        let rules = GameRules { min_players: 2, min_wager: 1 };
+        let mut game = GambleDeathRoll::new("a".into(), WAGER, rules, FairDice::from_seed(seed)).unwrap();
+
+        for player_id in ["b", "c"] {
+            game.add_player(player_id.into()).unwrap();
+        }
+
+        game.start().unwrap();
+
+        game
+    }
+
+    /// A seed where `a` opens without losing, and `predicate` accepts both `a`'s roll and `b`'s next.
+    fn seed_where(predicate: impl Fn(RollValue, RollValue) -> bool) -> String {
+        find_seed(|seed| {
+            let a = derive_roll(seed, "a", 0, LOSING_ROLL, WAGER);
+            a > LOSING_ROLL && predicate(a, derive_roll(seed, "b", 1, LOSING_ROLL, a))
+        }).unwrap()
+    }
+
+    #[test]
+    fn players_roll_in_join_order() {
+        let mut game = started_game("seed".into());
+
+        assert!(matches!(game.roll("b".into()), Err(GameError::NotYourTurn(player_id)) if player_id == "a"));
+        assert_eq!(game.pending_players(), vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn each_roll_caps_the_next_one() {
+        let mut game = started_game(seed_where(|_, b| b > LOSING_ROLL));
+
+        let a = game.roll("a".into()).unwrap();
+        assert_eq!(game.pending_players(), vec!["b".to_string()]);
+
+        let b = game.roll("b".into()).unwrap();
+        assert!(b <= a);
+        assert_eq!(game.pending_players(), vec!["c".to_string()]);
+        assert_eq!(game.snapshot().max_roll, Some(b));
+    }
+
+    #[test]
+    fn rolling_a_one_hands_the_wager_to_the_previous_player() {
+        let mut game = started_game(seed_where(|_, b| b == LOSING_ROLL));
+
+        game.roll("a".into()).unwrap();
+        assert_eq!(game.roll("b".into()).unwrap(), LOSING_ROLL);
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+        assert_eq!(game.settlement().unwrap(), vec![Debt::new("b", "a", WAGER)]);
+    }
+
+    #[test]
+    fn forfeiting_loses_to_the_previous_player() {
+        let mut game = started_game("seed".into());
+
+        assert!(matches!(game.forfeit(&"b".into()), Err(GameError::NotYourTurn(_))));
+
+        // The first player's previous player is the last one.
+        game.forfeit(&"a".into()).unwrap();
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+        assert_eq!(game.settlement().unwrap(), vec![Debt::new("a", "c", WAGER)]);
+    }
+
+    #[test]
+    fn leaving_on_your_turn_loses_to_the_previous_player() {
+        let mut game = started_game(seed_where(|_, _| true));
+        game.roll("a".into()).unwrap();
+
+        game.remove_player(&"b".into()).unwrap();
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+        assert_eq!(game.settlement().unwrap(), vec![Debt::new("b", "a", WAGER)]);
+    }
+
+    #[test]
+    fn leaving_out_of_turn_loses_to_the_player_before_you() {
+        let mut game = started_game("seed".into());
+
+        game.remove_player(&"c".into()).unwrap();
+        assert_eq!(game.update().unwrap(), &GameStatus::DONE);
+        assert_eq!(game.settlement().unwrap(), vec![Debt::new("c", "b", WAGER)]);
+        assert!(matches!(game.roll("a".into()), Err(GameError::PlayerCannotRoll)));
+    }
+
+    #[test]
+    fn a_game_everybody_left_before_it_started_is_cancelled() {
+        let rules = GameRules { min_players: 2, min_wager: 1 };
+        let mut game = GambleDeathRoll::new("a".into(), WAGER, rules, FairDice::from_seed("seed".into())).unwrap();
+        game.add_player("b".into()).unwrap();
+
+        game.remove_player(&"a".into()).unwrap();
+        assert_eq!(game.status(), &GameStatus::INITIATED);
+
+        game.remove_player(&"b".into()).unwrap();
+        assert_eq!(game.status(), &GameStatus::CANCELLED);
+        assert!(game.settlement().is_none());
+    }
+}
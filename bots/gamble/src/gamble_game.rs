@@ -1,19 +1,88 @@
 use core::fmt;
 
-use crate::{ error::GameError, types::{ PlayerId, RollValue } };
+use serde::{ Deserialize, Serialize };
 
-#[derive(PartialEq, Clone, Debug)]
+use crate::{
+    cards::{ Card, Hand },
+    error::GameError,
+    fairness::{ FairDice, TIE_BREAK },
+    ledger::Debt,
+    types::{ PlayerId, RollValue },
+};
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum GameStatus {
     INITIATED,
     ONGOING,
     DONE,
+    CANCELLED,
+}
+
+/// A game's state at a point in time, without any formatting, so the same data can be shown as
+/// a message, an embed or JSON.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameSnapshot {
+    /// Name of the game's mode, e.g. `classic`.
+    pub mode: &'static str,
+    pub status: GameStatus,
+    pub wager: RollValue,
+    /// Players taking part in the game, in turn order for modes that have one.
+    pub players: Vec<PlayerId>,
+    /// Players the game is waiting on to roll.
+    pub pending: Vec<PlayerId>,
+    /// Every roll of a player so far, oldest first.
+    pub rolls: Vec<(PlayerId, RollValue)>,
+    /// Highest value the next roll can get, for modes played with dice.
+    pub max_roll: Option<RollValue>,
+    /// Who owes what to whom once the game is over, empty until then.
+    pub settlement: Vec<Debt>,
+    /// Hands of modes played with cards, in the order they are played.
+    pub hands: Vec<Hand>,
+    /// The dealer's cards players can see, for modes played against the house.
+    pub dealer: Vec<Card>,
 }
 
-pub trait GambleGame: fmt::Debug {
+impl GameSnapshot {
+    /// The rolls players made with the dice, leaving out tie-breaks between forfeited players.
+    pub fn rolls_of(dice: &FairDice) -> Vec<(PlayerId, RollValue)> {
+        dice.rolls()
+            .iter()
+            .filter(|roll| roll.player_id != TIE_BREAK)
+            .map(|roll| (roll.player_id.clone(), roll.value))
+            .collect()
+    }
+}
+
+/// Implementations are tagged by `mode` when snapshotted so they can be restored after a restart.
+#[typetag::serde(tag = "mode")]
+pub trait GambleGame: fmt::Debug + Send + Sync {
     fn add_player(&mut self, player_id: String) -> Result<(), GameError>;
     fn start(&mut self) -> Result<(), GameError>;
     fn roll(&mut self, player_id: String) -> Result<RollValue, GameError>;
+    /// Plays one of the mode's `actions`, e.g. `hit` in blackjack. Modes played with dice have none.
+    fn act(&mut self, _player_id: PlayerId, _action: &str) -> Result<(), GameError> {
+        Err(GameError::UnknownCommand)
+    }
+    /// Makes the pending move of a player who took too long, returning the roll it made, if any.
+    fn auto_play(&mut self, player_id: PlayerId) -> Result<Option<RollValue>, GameError> {
+        self.roll(player_id).map(Some)
+    }
+    /// Gives up the player's pending roll, counting it as the worst possible roll.
+    fn forfeit(&mut self, player_id: &PlayerId) -> Result<(), GameError>;
+    /// Takes a player out of the game. Leaving a game that already started counts as a forfeit.
+    fn remove_player(&mut self, player_id: &PlayerId) -> Result<(), GameError>;
+    /// Ends the game without a winner or a loser.
+    fn cancel(&mut self) -> Result<(), GameError>;
     fn update(&mut self) -> Result<&GameStatus, GameError>;
-    fn info(&self) -> String;
-    fn wl(&self) -> Option<(PlayerId, PlayerId, RollValue)>;
+    /// Source of every roll, so the game can be verified once it is over.
+    fn dice(&self) -> &FairDice;
+    fn status(&self) -> &GameStatus;
+    /// Players the game is currently waiting on.
+    fn pending_players(&self) -> Vec<PlayerId>;
+    fn snapshot(&self) -> GameSnapshot;
+    /// Every transfer the game's result calls for, once it is over. Modes with a single winner and
+    /// loser have one, pots and house games can have several, and nobody owing anything is none.
+    fn settlement(&self) -> Option<Vec<Debt>>;
 }
+
+pub type BoxedGambleGame = Box<dyn GambleGame>;
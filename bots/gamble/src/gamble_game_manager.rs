@@ -1,73 +1,346 @@
-use std::{ collections::HashMap };
+use std::{ collections::HashMap, sync::Arc };
+
+use rand::Rng;
+use serde::{ Deserialize, Serialize };
+use tracing::{ error, warn };
 
 use crate::{
+    clock::Clock,
     error::GameError,
-    gamble_classic::GambleClassic,
-    gamble_game::{ GambleGame, GameStatus },
+    fairness::{ FairGame, SeedSource, Verification },
+    gamble_game::{ BoxedGambleGame, GameSnapshot, GameStatus },
+    game_mode::{ DEFAULT_GAME_MODE, GAME_MODES, GameMode, find_game_mode, is_game_action },
+    game_store::GameStore,
+    guild_config::{ GuildConfig, GuildConfigStore },
+    ledger::{ Debt, Ledger, Outcome, Payment, Period, PlayerStats },
+    locale::Locale,
+    options::{ IdlePolicy, IdleTimeouts },
+    settlement::simplify_debts,
     types::{ PlayerId, RollValue },
+    utils::parse_mention,
 };
 
-type BoxedGameGame = Box<dyn GambleGame + Send + Sync>;
+/// A game along with what the manager needs to know about it outside of the game logic.
+#[derive(Serialize, Deserialize)]
+struct GameEntry {
+    /// Short public id players use to verify the game once it's over.
+    id: String,
+    /// Channel, or thread, the game is played in.
+    channel_id: String,
+    guild_id: Option<String>,
+    creator: PlayerId,
+    /// Unix timestamp in seconds of the last time a player did something in the game.
+    last_activity: u64,
+    /// Id of the message showing the game's board, once it was posted.
+    board: Option<String>,
+    game: BoxedGambleGame,
+}
 
-type GameMap = HashMap<String, BoxedGameGame>;
+/// Games by id.
+type GameMap = HashMap<String, GameEntry>;
+
+/// Whether `game_id` looks like an id `new_game_id` hands out.
+fn is_game_id(game_id: &str) -> bool {
+    game_id.len() == 8 && game_id.chars().all(|c| c.is_ascii_hexdigit())
+}
 
 pub enum GGMResponse {
     Empty,
-    ShowJoinInfo,
+    /// The game id, the hash of its server seed and the game's mode.
+    ShowJoinInfo(String, String, &'static GameMode),
     Started,
     PlayerRolled(RollValue),
-    Done((PlayerId, PlayerId, RollValue)),
-    ShowGeneralInfo(String),
-    Message(String),
+    /// A player made one of the mode's moves, leaving the game in this state.
+    Played(GameSnapshot),
+    /// The game's settlement, how to verify the game and the game's final state.
+    Done(Vec<Debt>, FairGame, GameSnapshot),
+    Verified(Verification),
+    ShowGeneralInfo(GameSnapshot),
+    OwedTo(PlayerId, Vec<Debt>),
+    OwedBy(PlayerId, Vec<Debt>),
+    Ledger(Vec<Outcome>),
+    PaymentPending(Payment),
+    PaymentsConfirmed(Vec<Payment>),
+    Settlement(Vec<Debt>),
+    Stats(PlayerStats),
+    Leaderboard(Period, Vec<PlayerStats>),
+    ShowModes(&'static [GameMode]),
+    ShowModeHelp(&'static GameMode),
+    /// Nobody started the game in time. Carries the game's final state.
+    IdleCancelled(GameSnapshot),
+    AutoRolled(PlayerId, RollValue),
+    /// The player took too long, so the game made their move for them.
+    AutoPlayed(PlayerId),
+    Forfeited(PlayerId),
+    Left(PlayerId),
+    LeftAndForfeited(PlayerId),
+    Kicked(PlayerId),
+    /// Carries the game's final state.
+    Cancelled(GameSnapshot),
+    /// Every setting of the guild.
+    ShowConfig(GuildConfig),
+    /// A setting and its value.
+    ConfigValue(String, String),
+    ConfigUpdated(String, String),
+    Help,
 }
 
+/// What happened to a game that sat idle for too long.
+pub struct IdleUpdate {
+    pub guild_id: Option<String>,
+    pub channel_id: String,
+    pub game_id: String,
+    /// Player the responses are addressed to when they don't name one themselves.
+    pub creator: PlayerId,
+    /// Id of the message showing the game's board, if it was posted.
+    pub board: Option<String>,
+    pub responses: Vec<Result<GGMResponse, GameError>>,
+}
+
+/// Where a command was sent from and who sent it.
+pub struct CommandContext {
+    pub guild_id: Option<String>,
+    pub channel_id: String,
+    pub user_id: PlayerId,
+    /// Whether the user can manage messages in the channel, which lets them moderate any game.
+    pub is_moderator: bool,
+    /// Whether the user can manage the guild, which lets them change its settings.
+    pub can_manage_guild: bool,
+    /// Language of the user's Discord client, when the command came with one.
+    pub locale: Option<Locale>,
+    /// Whether the bot can post embeds where the command was sent. Replies are plain text otherwise.
+    pub can_embed: bool,
+}
+
+/// Runs the games of one or more channels. The ledger, the snapshot store and the guild settings
+/// are shared with the managers of other channels.
 pub struct GambleGameManager {
     map: GameMap,
+    ledger: Arc<Ledger>,
+    store: Arc<GameStore>,
+    configs: Arc<GuildConfigStore>,
+    seeds: SeedSource,
+    clock: Clock,
 }
 
-impl Default for GambleGameManager {
-    fn default() -> Self {
+const LEDGER_PAGE_SIZE: usize = 10;
+const LEADERBOARD_SIZE: usize = 10;
+
+impl GambleGameManager {
+    /// A manager without any game. Call `restore` to pick up games from before the last shutdown.
+    pub fn new(ledger: Arc<Ledger>, store: Arc<GameStore>, configs: Arc<GuildConfigStore>) -> Self {
         Self {
             map: Default::default(),
+            ledger,
+            store,
+            configs,
+            seeds: Default::default(),
+            clock: Default::default(),
         }
     }
-}
 
-const HELP: &str =
-    r#"
-:moneybag: **Gamble Game!**
-*Great way to lose gold in your favorite game.*
-- `g!create <GOLD_AMOUNT>`  __Create a unique game in a channel__
-- `g!join` __Join a new game__
-- `g!play` __Start a new game__
-- `g!roll` __Roll__
-- `g!help` __List all available commands__
-- `g!info` __List information about the current game__
-"#;
+    pub fn with_seeds(mut self, seeds: SeedSource) -> Self {
+        self.seeds = seeds;
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The time every game of the manager goes by, shared with its forks.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Another manager without any game, sharing this one's ledger, stores, seeds and clock.
+    pub fn fork(&self) -> Self {
+        Self {
+            map: Default::default(),
+            ledger: self.ledger.clone(),
+            store: self.store.clone(),
+            configs: self.configs.clone(),
+            seeds: self.seeds.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+
+    /// Restores every game snapshotted in the store before the last shutdown.
+    pub fn restore(&mut self) {
+        let games = self.load_snapshots();
+        self.map.extend(games);
+    }
+
+    /// Restores the games of one channel from their last snapshots, e.g. after they were lost to a panic.
+    pub fn restore_channel(&mut self, channel_id: &str) {
+        let games = self.load_snapshots();
+        self.map.extend(games.into_iter().filter(|(_, entry)| entry.channel_id == channel_id));
+    }
+
+    fn load_snapshots(&self) -> Vec<(String, GameEntry)> {
+        match self.store.load_all::<GameEntry>() {
+            Ok(games) => {
+                return games;
+            }
+            Err(why) => {
+                error!(?why, "gamble: failed to load game snapshots");
+                return vec![];
+            }
+        }
+    }
+
+    /// Splits the games into one manager per channel.
+    pub fn into_channels(mut self) -> Vec<(String, Self)> {
+        let template = self.fork();
+        let mut channels: HashMap<String, Self> = HashMap::new();
+
+        for (game_id, entry) in self.map.drain() {
+            channels
+                .entry(entry.channel_id.clone())
+                .or_insert_with(|| template.fork())
+                .map.insert(game_id, entry);
+        }
+
+        channels.into_iter().collect()
+    }
+
+    pub fn has_games(&self) -> bool {
+        !self.map.is_empty()
+    }
+
+    /// Id of the message showing the board of a game that isn't over yet, once it was posted.
+    pub fn board(&self, game_id: &String) -> Option<String> {
+        self.map.get(game_id).and_then(|entry| entry.board.clone())
+    }
+
+    /// Remembers the message showing a game's board, so it is edited rather than posted again even
+    /// after a restart.
+    pub fn set_board(&mut self, game_id: &String, message_id: String) {
+        if let Some(entry) = self.map.get_mut(game_id) {
+            entry.board = Some(message_id);
+            self.snapshot(game_id);
+        }
+    }
+
+    /// The state of a game that isn't over yet.
+    pub fn game(&self, game_id: &String) -> Option<GameSnapshot> {
+        self.map.get(game_id).map(|entry| entry.game.snapshot())
+    }
+
+    /// The game a command with `args` is about, if it can tell: the game of the channel whose id
+    /// is one of the arguments, or else the only one the user is in, or else the channel's only one.
+    pub fn find_game(&self, ctx: &CommandContext, args: &[&str]) -> Option<String> {
+        self.resolve_game(ctx, args).ok().flatten()
+    }
+
+    /// Same as `find_game`, but fails when the user has to say which of several games they mean.
+    fn resolve_game(&self, ctx: &CommandContext, args: &[&str]) -> Result<Option<String>, GameError> {
+        let mut games = self.map
+            .iter()
+            .filter(|(_, entry)| entry.channel_id == ctx.channel_id)
+            .collect::<Vec<(&String, &GameEntry)>>();
+
+        games.sort_by_key(|(game_id, _)| *game_id);
+
+        if let Some((game_id, _)) = games.iter().find(|(game_id, _)| args.contains(&game_id.as_str())) {
+            return Ok(Some(game_id.to_string()));
+        }
+
+        let joined = games
+            .iter()
+            .filter(|(_, entry)| entry.game.snapshot().players.contains(&ctx.user_id))
+            .collect::<Vec<&(&String, &GameEntry)>>();
+
+        let candidates = if joined.is_empty() { games.iter().collect() } else { joined };
+
+        match candidates.as_slice() {
+            [] => {
+                return Ok(None);
+            }
+            [(game_id, _)] => {
+                return Ok(Some(game_id.to_string()));
+            }
+            candidates => {
+                let game_ids = candidates
+                    .iter()
+                    .map(|(game_id, _)| game_id.to_string())
+                    .collect();
+
+                return Err(GameError::SeveralGames(game_ids));
+            }
+        }
+    }
+
+    /// The settings of a guild, or the defaults outside of one.
+    pub fn guild_config(&self, guild_id: Option<&String>) -> GuildConfig {
+        self.configs.get(guild_id.map(String::as_str))
+    }
+
+    /// An id no game has had yet: not one being played, in this channel or another, nor one that
+    /// already finished.
+    fn new_game_id(&self) -> Result<String, GameError> {
+        loop {
+            let game_id = format!("{:08x}", rand::rng().random::<u32>());
+
+            if !self.map.contains_key(&game_id) &&
+                !self.store.contains(&game_id)? &&
+                self.ledger.fair_game(&game_id)?.is_none()
+            {
+                return Ok(game_id);
+            }
+        }
+    }
+
+    /// Persists the current state of a game, or forgets it once it is no longer in the map.
+    ///
+    /// Failures are only logged: the game keeps going in memory.
+    fn snapshot(&self, game_id: &String) {
+        let result = match self.map.get(game_id) {
+            Some(entry) => self.store.save(game_id, entry),
+            None => self.store.delete(game_id).map_err(Into::into),
+        };
+
+        if let Err(why) = result {
+            error!(?why, game_id, "gamble: failed to snapshot game");
+        }
+    }
 
-impl GambleGameManager {
     fn create(
         &mut self,
-        game_id: String,
-        user_id: String,
+        ctx: &CommandContext,
+        mode: &str,
         max_roll: u64
     ) -> Result<GGMResponse, GameError> {
-        if self.map.contains_key(&game_id) {
-            return Err(GameError::GameAlreadyExists);
-        }
+        let mode = find_game_mode(mode).ok_or(GameError::UnknownGameMode)?;
+        let rules = self.guild_config(ctx.guild_id.as_ref()).rules(mode);
+        let game = (mode.create)(ctx.user_id.clone(), max_roll, rules, self.seeds.dice())?;
+
+        let game_id = self.new_game_id()?;
+        let entry = GameEntry {
+            id: game_id.clone(),
+            channel_id: ctx.channel_id.clone(),
+            guild_id: ctx.guild_id.clone(),
+            creator: ctx.user_id.clone(),
+            last_activity: self.clock.now(),
+            board: None,
+            game,
+        };
 
-        let gamble_classic = GambleClassic::new(user_id, max_roll)?;
+        let response = GGMResponse::ShowJoinInfo(entry.id.clone(), entry.game.dice().seed_hash(), mode);
 
-        let boxed = Box::new(gamble_classic);
-        self.map.insert(game_id, boxed);
+        self.map.insert(game_id.clone(), entry);
+        self.snapshot(&game_id);
 
-        return Ok(GGMResponse::ShowJoinInfo);
+        return Ok(response);
     }
 
     fn join(&mut self, game_id: String, player_id: String) -> Result<GGMResponse, GameError> {
         match self.map.get_mut(&game_id) {
-            Some(game) => {
-                game.add_player(player_id)?;
+            Some(entry) => {
+                entry.game.add_player(player_id)?;
+                entry.last_activity = self.clock.now();
+                self.snapshot(&game_id);
 
                 return Ok(GGMResponse::Empty);
             }
@@ -79,8 +352,11 @@ impl GambleGameManager {
 
     fn play(&mut self, game_id: String) -> Result<GGMResponse, GameError> {
         match self.map.get_mut(&game_id) {
-            Some(game) => {
-                game.start()?;
+            Some(entry) => {
+                entry.game.start()?;
+                entry.last_activity = self.clock.now();
+                self.snapshot(&game_id);
+
                 return Ok(GGMResponse::Started);
             }
             None => {
@@ -91,8 +367,10 @@ impl GambleGameManager {
 
     fn roll(&mut self, game_id: String, player_id: String) -> Result<GGMResponse, GameError> {
         match self.map.get_mut(&game_id) {
-            Some(game) => {
-                let roll_value = game.roll(player_id.clone())?;
+            Some(entry) => {
+                let roll_value = entry.game.roll(player_id.clone())?;
+                entry.last_activity = self.clock.now();
+                self.snapshot(&game_id);
 
                 return Ok(GGMResponse::PlayerRolled(roll_value));
             }
@@ -102,10 +380,116 @@ impl GambleGameManager {
         }
     }
 
+    fn act(&mut self, game_id: String, player_id: PlayerId, action: &str) -> Result<GGMResponse, GameError> {
+        match self.map.get_mut(&game_id) {
+            Some(entry) => {
+                entry.game.act(player_id, action)?;
+                entry.last_activity = self.clock.now();
+                self.snapshot(&game_id);
+
+                return Ok(GGMResponse::Played(self.map[&game_id].game.snapshot()));
+            }
+            None => {
+                return Err(GameError::PlayerCannotActOnAnInexistentGame);
+            }
+        }
+    }
+
+    fn leave(&mut self, game_id: String, player_id: PlayerId) -> Result<GGMResponse, GameError> {
+        match self.map.get_mut(&game_id) {
+            Some(entry) => {
+                let started = *entry.game.status() == GameStatus::ONGOING;
+
+                entry.game.remove_player(&player_id)?;
+                entry.last_activity = self.clock.now();
+                self.snapshot(&game_id);
+
+                if started {
+                    return Ok(GGMResponse::LeftAndForfeited(player_id));
+                }
+
+                return Ok(GGMResponse::Left(player_id));
+            }
+            None => {
+                return Err(GameError::PlayerCannotLeaveAnInexistentGame);
+            }
+        }
+    }
+
+    fn cancel(&mut self, ctx: &CommandContext, game_id: String) -> Result<GGMResponse, GameError> {
+        match self.map.get_mut(&game_id) {
+            Some(entry) => {
+                if entry.creator != ctx.user_id && !ctx.is_moderator {
+                    return Err(GameError::OnlyCreatorOrModerator);
+                }
+
+                // `tick` removes the cancelled game and announces it.
+                entry.game.cancel()?;
+                self.snapshot(&game_id);
+
+                return Ok(GGMResponse::Empty);
+            }
+            None => {
+                return Err(GameError::PlayerCannotCancelAnInexistentGame);
+            }
+        }
+    }
+
+    fn kick(
+        &mut self,
+        ctx: &CommandContext,
+        game_id: String,
+        mention: Option<&&str>
+    ) -> Result<GGMResponse, GameError> {
+        let player_id = mention
+            .and_then(|mention| parse_mention(mention))
+            .ok_or(GameError::MissingPlayerMention)?;
+
+        match self.map.get_mut(&game_id) {
+            Some(entry) => {
+                if entry.creator != ctx.user_id && !ctx.is_moderator {
+                    return Err(GameError::OnlyCreatorOrModerator);
+                }
+
+                // Kicking someone mid-game would hand them a loss they didn't choose.
+                if *entry.game.status() != GameStatus::INITIATED {
+                    return Err(GameError::CannotKickFromOngoingGame);
+                }
+
+                entry.game.remove_player(&player_id).map_err(|why| {
+                    match why {
+                        GameError::PlayerNotPartOfGame =>
+                            GameError::KickedPlayerNotPartOfGame(player_id.clone()),
+                        why => why,
+                    }
+                })?;
+                entry.last_activity = self.clock.now();
+                self.snapshot(&game_id);
+
+                return Ok(GGMResponse::Kicked(player_id));
+            }
+            None => {
+                return Err(GameError::PlayerCannotKickFromAnInexistentGame);
+            }
+        }
+    }
+
+    fn modes(&self, mode: Option<&&str>) -> Result<GGMResponse, GameError> {
+        match mode {
+            Some(mode) => {
+                let mode = find_game_mode(mode).ok_or(GameError::UnknownGameMode)?;
+                return Ok(GGMResponse::ShowModeHelp(mode));
+            }
+            None => {
+                return Ok(GGMResponse::ShowModes(GAME_MODES));
+            }
+        }
+    }
+
     fn info(&self, game_id: String) -> Result<GGMResponse, GameError> {
         match self.map.get(&game_id) {
-            Some(game) => {
-                return Ok(GGMResponse::ShowGeneralInfo(game.info()));
+            Some(entry) => {
+                return Ok(GGMResponse::ShowGeneralInfo(entry.game.snapshot()));
             }
             None => {
                 return Err(GameError::PlayerCannotRequestInfoOnInexistentGame);
@@ -113,25 +497,239 @@ impl GambleGameManager {
         }
     }
 
-    pub fn tick(&mut self, channel_id: String) -> Result<GGMResponse, GameError> {
-        match self.map.get_mut(&channel_id) {
-            Some(game) => {
-                let status = game.update()?;
+    /// The guild's debts once netted, as `g!settle` lists them. A payment made along one of those
+    /// transfers only cancels out the pairwise debts it stands in for once netted, so whatever
+    /// reports or checks what someone owes goes by these.
+    fn netted_debts(&self, guild_id: &str) -> Result<Vec<Debt>, GameError> {
+        return Ok(simplify_debts(&self.ledger.debts(guild_id)?));
+    }
+
+    fn owed(&self, guild_id: Option<&String>, creditor: PlayerId) -> Result<GGMResponse, GameError> {
+        let guild_id = guild_id.ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let debts = self.netted_debts(guild_id)?
+            .into_iter()
+            .filter(|debt| debt.creditor == creditor)
+            .collect();
+
+        return Ok(GGMResponse::OwedTo(creditor, debts));
+    }
+
+    fn owes(&self, guild_id: Option<&String>, mention: Option<&&str>) -> Result<GGMResponse, GameError> {
+        let guild_id = guild_id.ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let debtor = mention
+            .and_then(|mention| parse_mention(mention))
+            .ok_or(GameError::MissingPlayerMention)?;
+        let debts = self.netted_debts(guild_id)?
+            .into_iter()
+            .filter(|debt| debt.debtor == debtor)
+            .collect();
+
+        return Ok(GGMResponse::OwedBy(debtor, debts));
+    }
+
+    fn ledger(&self, guild_id: Option<&String>) -> Result<GGMResponse, GameError> {
+        let guild_id = guild_id.ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let outcomes = self.ledger.recent_outcomes(guild_id, LEDGER_PAGE_SIZE)?;
+
+        return Ok(GGMResponse::Ledger(outcomes));
+    }
+
+    fn paid(&self, ctx: &CommandContext, args: &[&str]) -> Result<GGMResponse, GameError> {
+        let guild_id = ctx.guild_id.as_ref().ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let payee = args
+            .first()
+            .and_then(|mention| parse_mention(mention))
+            .ok_or(GameError::MissingPlayerMention)?;
+        let amount = args.get(1).unwrap_or(&"0").parse::<u64>().unwrap_or(0);
+
+        if payee == ctx.user_id {
+            return Err(GameError::CannotPayYourself);
+        }
+
+        if amount == 0 {
+            return Err(GameError::InvalidPaymentAmount);
+        }
+
+        let payment = Payment {
+            guild_id: guild_id.clone(),
+            channel_id: ctx.channel_id.clone(),
+            payer: ctx.user_id.clone(),
+            payee,
+            amount,
+            timestamp: self.clock.now(),
+        };
+
+        // Claims that are still waiting on confirmation count towards the debt as well.
+        // Payments go by the netted debts `g!owed` and `g!owes` show, which is how a payment to
+        // someone the payer never played against clears the debts it stands in for.
+        let remaining = self.ledger.record_payment(&payment, |debts| {
+            return simplify_debts(debts)
+                .into_iter()
+                .find(|debt| debt.debtor == payment.payer && debt.creditor == payment.payee)
+                .map(|debt| debt.amount)
+                .unwrap_or(0);
+        })?;
+
+        if remaining == 0 {
+            return Err(GameError::NothingOwed(payment.payee));
+        }
+
+        if amount > remaining {
+            return Err(GameError::PaymentExceedsDebt(remaining));
+        }
+
+        return Ok(GGMResponse::PaymentPending(payment));
+    }
+
+    fn settle(&self, guild_id: Option<&String>) -> Result<GGMResponse, GameError> {
+        let guild_id = guild_id.ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+
+        return Ok(GGMResponse::Settlement(self.netted_debts(guild_id)?));
+    }
+
+    fn stats(&self, ctx: &CommandContext, mention: Option<&&str>) -> Result<GGMResponse, GameError> {
+        let guild_id = ctx.guild_id.as_ref().ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let player_id = match mention {
+            Some(mention) => parse_mention(mention).ok_or(GameError::MissingPlayerMention)?,
+            None => ctx.user_id.clone(),
+        };
+
+        let stats = self.ledger.player_stats(guild_id, &player_id)?;
+
+        return Ok(GGMResponse::Stats(stats));
+    }
+
+    fn leaderboard(
+        &self,
+        guild_id: Option<&String>,
+        period: Option<&&str>
+    ) -> Result<GGMResponse, GameError> {
+        let guild_id = guild_id.ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let period = match period {
+            Some(period) => Period::parse(period).ok_or(GameError::UnknownPeriod)?,
+            None => Period::Week,
+        };
+
+        let stats = self.ledger.leaderboard(guild_id, period.since(self.clock.now()), LEADERBOARD_SIZE)?;
+
+        return Ok(GGMResponse::Leaderboard(period, stats));
+    }
+
+    fn confirm(&self, ctx: &CommandContext, mention: Option<&&str>) -> Result<GGMResponse, GameError> {
+        let guild_id = ctx.guild_id.as_ref().ok_or(GameError::LedgerOnlyAvailableInGuilds)?;
+        let payer = match mention {
+            Some(mention) => Some(parse_mention(mention).ok_or(GameError::MissingPlayerMention)?),
+            None => None,
+        };
+
+        let payments = self.ledger.confirm_payments(guild_id, &ctx.user_id, payer.as_ref())?;
+
+        if payments.is_empty() {
+            return Err(GameError::NoPendingPayments);
+        }
+
+        return Ok(GGMResponse::PaymentsConfirmed(payments));
+    }
+
+    fn verify(&self, game_id: Option<&&str>) -> Result<GGMResponse, GameError> {
+        // Unknown ids are quoted back to the user, so anything else than an id is turned down first.
+        let game_id = game_id.filter(|game_id| is_game_id(game_id)).ok_or(GameError::MissingGameId)?;
+        let game = self.ledger
+            .fair_game(game_id)?
+            .ok_or_else(|| GameError::UnknownGameId(game_id.to_string()))?;
+
+        return Ok(GGMResponse::Verified(game.verify()));
+    }
 
-                if *status != GameStatus::DONE {
-                    return Ok(GGMResponse::Empty);
+    fn config(&self, ctx: &CommandContext, args: &[&str]) -> Result<GGMResponse, GameError> {
+        let guild_id = ctx.guild_id.as_ref().ok_or(GameError::ConfigOnlyAvailableInGuilds)?;
+
+        if !ctx.can_manage_guild {
+            return Err(GameError::OnlyGuildManagers);
+        }
+
+        let mut config = self.configs.get(Some(guild_id));
+
+        match args {
+            [] => {
+                return Ok(GGMResponse::ShowConfig(config));
+            }
+            ["get", key] => {
+                return Ok(GGMResponse::ConfigValue(key.to_string(), config.get(key)?));
+            }
+            ["set", key, value] => {
+                config.set(key, value)?;
+
+                if let Err(why) = self.configs.save(guild_id, &config) {
+                    error!(?why, guild_id, "gamble: failed to save guild config");
+                    return Err(GameError::ConfigUnavailable);
                 }
 
-                let winner_and_loser = game.wl();
+                return Ok(GGMResponse::ConfigUpdated(key.to_string(), config.get(key)?));
+            }
+            ["set", key] => {
+                // Unknown keys are reported before the missing value.
+                config.get(key)?;
+                return Err(GameError::InvalidConfigValue(key.to_string()));
+            }
+            _ => {
+                return Err(GameError::UnknownConfigKey);
+            }
+        }
+    }
 
-                if winner_and_loser.is_none() {
-                    return Err(GameError::NoWinnersFound);
+    pub fn tick(&mut self, game_id: &String) -> Result<GGMResponse, GameError> {
+        match self.map.get_mut(game_id) {
+            Some(entry) => {
+                let status = entry.game.update().cloned();
+
+                // Updating can move players into a reroll even when it reports an error.
+                self.snapshot(game_id);
+
+                match status? {
+                    GameStatus::DONE => {}
+                    GameStatus::CANCELLED => {
+                        let snapshot = self.map.remove(game_id).unwrap().game.snapshot();
+                        self.snapshot(game_id);
+
+                        return Ok(GGMResponse::Cancelled(snapshot));
+                    }
+                    _ => {
+                        return Ok(GGMResponse::Empty);
+                    }
                 }
 
-                // Once the game is done, delete it from the map so the players can create a new one.
-                self.map.remove(&channel_id);
+                let Some(settlement) = self.map.get(game_id).and_then(|entry| entry.game.settlement()) else {
+                    return Err(GameError::NoWinnersFound);
+                };
+
+                let entry = &self.map[game_id];
+                let snapshot = entry.game.snapshot();
+
+                // The game is over, so its seed can be revealed.
+                let dice = entry.game.dice();
+                let fair_game = FairGame {
+                    game_id: entry.id.clone(),
+                    seed_hash: dice.seed_hash(),
+                    server_seed: dice.server_seed().to_string(),
+                    rolls: dice.rolls().to_vec(),
+                };
 
-                return Ok(GGMResponse::Done(winner_and_loser.unwrap()));
+                // A game that couldn't be recorded stays around, done, and the next tick tries again.
+                self.ledger.record_game(
+                    entry.guild_id.as_ref(),
+                    &entry.channel_id,
+                    &snapshot.players,
+                    &settlement,
+                    &fair_game,
+                    self.clock.now()
+                )?;
+
+                // Once the game is recorded, delete it from the map so the players can create a new one.
+                self.map.remove(game_id);
+                self.snapshot(game_id);
+
+                return Ok(GGMResponse::Done(settlement, fair_game, snapshot));
             }
             None => {
                 return Ok(GGMResponse::Empty);
@@ -139,32 +737,181 @@ impl GambleGameManager {
         }
     }
 
+    /// Cancels games nobody started in time and applies `timeouts.policy` to players who
+    /// haven't rolled in time, returning what happened in each affected channel.
+    pub fn expire_idle_games(&mut self, timeouts: &IdleTimeouts) -> Vec<IdleUpdate> {
+        let now = self.clock.now();
+        let expired = self.map
+            .iter()
+            .filter(|(_, entry)| {
+                let timeout = match entry.game.status() {
+                    GameStatus::INITIATED => timeouts.initiated,
+                    GameStatus::ONGOING => timeouts.ongoing,
+                    GameStatus::DONE | GameStatus::CANCELLED => {
+                        return false;
+                    }
+                };
+
+                now.saturating_sub(entry.last_activity) >= timeout.as_secs()
+            })
+            .map(|(game_id, _)| game_id.clone())
+            .collect::<Vec<String>>();
+
+        expired
+            .into_iter()
+            .map(|game_id| self.expire_idle_game(game_id, now, timeouts.policy))
+            .collect()
+    }
+
+    fn expire_idle_game(&mut self, game_id: String, now: u64, policy: IdlePolicy) -> IdleUpdate {
+        let entry = self.map.get_mut(&game_id).unwrap();
+        let creator = entry.creator.clone();
+        let guild_id = entry.guild_id.clone();
+        let channel_id = entry.channel_id.clone();
+        let board = entry.board.clone();
+
+        if *entry.game.status() == GameStatus::INITIATED {
+            // The game itself never learns it was cancelled, so its final state says so instead.
+            let snapshot = GameSnapshot { status: GameStatus::CANCELLED, ..entry.game.snapshot() };
+            self.map.remove(&game_id);
+            self.snapshot(&game_id);
+
+            return IdleUpdate {
+                guild_id,
+                channel_id,
+                game_id,
+                creator,
+                board,
+                responses: vec![Ok(GGMResponse::IdleCancelled(snapshot))],
+            };
+        }
+
+        let mut responses = vec![];
+
+        for player_id in entry.game.pending_players() {
+            let result = match policy {
+                IdlePolicy::AutoRoll =>
+                    entry.game
+                        .auto_play(player_id.clone())
+                        .map(|roll_value| {
+                            match roll_value {
+                                Some(roll_value) => GGMResponse::AutoRolled(player_id.clone(), roll_value),
+                                None => GGMResponse::AutoPlayed(player_id.clone()),
+                            }
+                        }),
+                IdlePolicy::Forfeit =>
+                    entry.game
+                        .forfeit(&player_id)
+                        .map(|_| GGMResponse::Forfeited(player_id.clone())),
+            };
+
+            match result {
+                Ok(response) => responses.push(Ok(response)),
+                Err(why) => warn!(?why, player_id, "gamble: failed to resolve idle player"),
+            }
+        }
+
+        entry.last_activity = now;
+        self.snapshot(&game_id);
+
+        responses.push(self.tick(&game_id));
+
+        IdleUpdate {
+            guild_id,
+            channel_id,
+            game_id,
+            creator,
+            board,
+            responses,
+        }
+    }
+
     pub fn execute(
         &mut self,
-        channel_id: String,
-        user_id: String,
+        ctx: &CommandContext,
         command: &str,
         args: Vec<&str>
     ) -> Result<GGMResponse, GameError> {
+        let user_id = ctx.user_id.clone();
+
         match command {
-            "g!create" => {
-                let max_roll = args.get(0).unwrap_or(&"0").parse::<u64>().unwrap_or(0);
-                return self.create(channel_id, user_id, max_roll);
+            "create" => {
+                // Both `g!create deathroll 5000` and `g!create 5000 deathroll` are accepted.
+                let (amounts, modes): (Vec<&str>, Vec<&str>) = args
+                    .iter()
+                    .partition(|arg| arg.chars().all(|c| c.is_ascii_digit()));
+                let max_roll = amounts.first().unwrap_or(&"0").parse::<u64>().unwrap_or(0);
+                let mode = modes.first().unwrap_or(&DEFAULT_GAME_MODE);
+                return self.create(ctx, mode, max_roll);
             }
-            "g!join" => {
-                return self.join(channel_id, user_id);
+            "join" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotJoinAnInexistentGame)?;
+                return self.join(game_id, user_id);
             }
-            "g!play" => {
-                return self.play(channel_id);
+            "play" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotPlayOnInexistentGame)?;
+                return self.play(game_id);
             }
-            "g!roll" => {
-                return self.roll(channel_id, user_id);
+            "roll" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotRollOnAnInexistentGame)?;
+                return self.roll(game_id, user_id);
             }
-            "g!help" => {
-                return Ok(GGMResponse::Message(HELP.to_string()));
+            "leave" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotLeaveAnInexistentGame)?;
+                return self.leave(game_id, user_id);
             }
-            "g!info" => {
-                return self.info(channel_id);
+            "cancel" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotCancelAnInexistentGame)?;
+                return self.cancel(ctx, game_id);
+            }
+            "kick" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotKickFromAnInexistentGame)?;
+                let mention = args.iter().find(|arg| parse_mention(arg).is_some());
+                return self.kick(ctx, game_id, mention);
+            }
+            action if is_game_action(action) => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotActOnAnInexistentGame)?;
+                return self.act(game_id, user_id, action);
+            }
+            "verify" => {
+                return self.verify(args.first());
+            }
+            "help" => {
+                return Ok(GGMResponse::Help);
+            }
+            "info" => {
+                let game_id = self.resolve_game(ctx, &args)?.ok_or(GameError::PlayerCannotRequestInfoOnInexistentGame)?;
+                return self.info(game_id);
+            }
+            "modes" => {
+                return self.modes(args.first());
+            }
+            "owed" => {
+                return self.owed(ctx.guild_id.as_ref(), user_id);
+            }
+            "owes" => {
+                return self.owes(ctx.guild_id.as_ref(), args.first());
+            }
+            "ledger" => {
+                return self.ledger(ctx.guild_id.as_ref());
+            }
+            "settle" => {
+                return self.settle(ctx.guild_id.as_ref());
+            }
+            "stats" => {
+                return self.stats(ctx, args.first());
+            }
+            "leaderboard" => {
+                return self.leaderboard(ctx.guild_id.as_ref(), args.first());
+            }
+            "paid" => {
+                return self.paid(ctx, &args);
+            }
+            "confirm" => {
+                return self.confirm(ctx, args.first());
+            }
+            "config" => {
+                return self.config(ctx, &args);
             }
             _ => {
                 return Err(GameError::UnknownCommand);
@@ -172,3 +919,215 @@ impl GambleGameManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fairness::derive_roll,
+        test_support::{ ALICE, BOB, CAROL, CHANNEL_ID, GUILD_ID, ctx, manager },
+    };
+
+    /// Records a finished game `debtor` lost `amount` gold in to `creditor`.
+    fn record_debt(manager: &GambleGameManager, debtor: &str, creditor: &str, amount: RollValue) {
+        let fair_game = FairGame {
+            game_id: manager.new_game_id().unwrap(),
+            seed_hash: String::new(),
+            server_seed: String::new(),
+            rolls: vec![],
+        };
+        let debt = Debt::new(debtor, creditor, amount);
+
+        manager.ledger
+            .record_game(Some(&GUILD_ID.into()), CHANNEL_ID, &[debtor.into(), creditor.into()], &[debt], &fair_game, 0)
+            .unwrap();
+    }
+
+    /// Starts a game between Alice and Bob in which only Alice rolled, returning its id. Their rolls
+    /// don't tie with the manager's seed.
+    fn game_waiting_on_bob(manager: &mut GambleGameManager) -> String {
+        manager.execute(&ctx(ALICE), "create", vec!["500"]).unwrap();
+        manager.execute(&ctx(BOB), "join", vec![]).unwrap();
+        manager.execute(&ctx(ALICE), "play", vec![]).unwrap();
+        manager.execute(&ctx(ALICE), "roll", vec![]).unwrap();
+
+        manager.find_game(&ctx(ALICE), &[]).unwrap()
+    }
+
+    #[test]
+    fn games_nobody_started_in_time_are_cancelled() {
+        let mut manager = manager();
+        let timeouts = IdleTimeouts::default();
+        manager.execute(&ctx(ALICE), "create", vec!["500"]).unwrap();
+
+        manager.clock().advance(timeouts.initiated.as_secs() - 1);
+        assert!(manager.expire_idle_games(&timeouts).is_empty());
+
+        manager.clock().advance(1);
+        let updates = manager.expire_idle_games(&timeouts);
+
+        assert_eq!(updates.len(), 1);
+        assert!(matches!(&updates[0].responses[..], [Ok(GGMResponse::IdleCancelled(snapshot))]
+            if snapshot.status == GameStatus::CANCELLED));
+        assert!(!manager.has_games());
+    }
+
+    #[test]
+    fn idle_players_are_rolled_for_with_the_auto_roll_policy() {
+        let mut manager = manager().with_seeds(SeedSource::fixed(["seed".to_string()]));
+        let timeouts = IdleTimeouts { policy: IdlePolicy::AutoRoll, ..Default::default() };
+        let game_id = game_waiting_on_bob(&mut manager);
+
+        manager.clock().advance(timeouts.ongoing.as_secs());
+        let updates = manager.expire_idle_games(&timeouts);
+
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].game_id, game_id);
+        assert!(matches!(&updates[0].responses[0], Ok(GGMResponse::AutoRolled(player_id, _)) if player_id == BOB));
+        assert!(matches!(&updates[0].responses[1], Ok(GGMResponse::Done(..))));
+        assert!(!manager.has_games());
+    }
+
+    #[test]
+    fn idle_players_lose_with_the_forfeit_policy() {
+        let mut manager = manager().with_seeds(SeedSource::fixed(["seed".to_string()]));
+        let timeouts = IdleTimeouts { policy: IdlePolicy::Forfeit, ..Default::default() };
+        game_waiting_on_bob(&mut manager);
+
+        manager.clock().advance(timeouts.ongoing.as_secs());
+        let updates = manager.expire_idle_games(&timeouts);
+
+        assert!(matches!(&updates[0].responses[0], Ok(GGMResponse::Forfeited(player_id)) if player_id == BOB));
+        assert!(matches!(&updates[0].responses[1], Ok(GGMResponse::Done(settlement, ..))
+            if settlement[0].debtor == BOB && settlement[0].creditor == ALICE));
+        assert!(!manager.has_games());
+    }
+
+    #[test]
+    fn a_started_game_everybody_left_is_cancelled_without_recording_it() {
+        let mut manager = manager();
+        let game_id = game_waiting_on_bob(&mut manager);
+
+        manager.execute(&ctx(ALICE), "leave", vec![]).unwrap();
+        manager.execute(&ctx(BOB), "leave", vec![]).unwrap();
+
+        assert!(matches!(manager.tick(&game_id), Ok(GGMResponse::Cancelled(_))));
+        assert!(!manager.has_games());
+        assert!(manager.ledger.recent_outcomes(GUILD_ID, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn paying_along_a_settlement_clears_the_debts_it_stands_in_for() {
+        let mut manager = manager();
+        record_debt(&manager, ALICE, BOB, 100);
+        record_debt(&manager, BOB, CAROL, 100);
+
+        // Bob is square, so Alice pays Carol directly.
+        let owes = manager.execute(&ctx(CAROL), "owes", vec!["<@101>"]);
+        assert!(matches!(owes, Ok(GGMResponse::OwedBy(_, debts)) if debts == [Debt::new(ALICE, CAROL, 100)]));
+
+        manager.execute(&ctx(ALICE), "paid", vec!["<@103>", "100"]).unwrap();
+        manager.execute(&ctx(CAROL), "confirm", vec![]).unwrap();
+
+        for player_id in [ALICE, BOB, CAROL] {
+            let owes = manager.execute(&ctx(ALICE), "owes", vec![&format!("<@{}>", player_id)]);
+            assert!(matches!(owes, Ok(GGMResponse::OwedBy(_, debts)) if debts.is_empty()));
+
+            let owed = manager.execute(&ctx(player_id), "owed", vec![]);
+            assert!(matches!(owed, Ok(GGMResponse::OwedTo(_, debts)) if debts.is_empty()));
+        }
+
+        // The debt to Bob was paid off along the way.
+        let paid = manager.execute(&ctx(ALICE), "paid", vec!["<@102>", "100"]);
+        assert!(matches!(paid, Err(GameError::NothingOwed(payee)) if payee == BOB));
+    }
+
+    #[test]
+    fn games_pick_up_where_they_left_off_after_a_restart() {
+        let mut manager = manager().with_seeds(SeedSource::fixed(["seed".to_string()]));
+        let game_id = game_waiting_on_bob(&mut manager);
+        manager.set_board(&game_id, "2".into());
+        let before = manager.game(&game_id).unwrap();
+
+        // A manager sharing the same stores, as after restarting the bot.
+        let mut restarted = manager.fork();
+        restarted.restore();
+
+        let after = restarted.game(&game_id).unwrap();
+        assert_eq!(serde_json::to_value(&after).unwrap(), serde_json::to_value(&before).unwrap());
+        assert_eq!(after.pending, vec![BOB.to_string()]);
+        // The board is edited where it was before the restart.
+        assert_eq!(restarted.board(&game_id), Some("2".to_string()));
+
+        restarted.execute(&ctx(BOB), "roll", vec![]).unwrap();
+        assert!(matches!(restarted.tick(&game_id), Ok(GGMResponse::Done(..))));
+
+        // Finished games aren't restored again.
+        assert!(!restarted.store.contains(&game_id).unwrap());
+    }
+
+    #[test]
+    fn info_is_a_snapshot_of_the_game() {
+        let seed = "snapshot".to_string();
+        let mut manager = manager().with_seeds(SeedSource::fixed([seed.clone()]));
+
+        let commands = [
+            (ALICE, "create", vec!["500"]),
+            (BOB, "join", vec![]),
+            (ALICE, "play", vec![]),
+            (ALICE, "roll", vec![]),
+        ];
+
+        for (user, command, args) in commands {
+            assert!(manager.execute(&ctx(user), command, args).is_ok());
+        }
+
+        let Ok(GGMResponse::ShowGeneralInfo(snapshot)) = manager.execute(&ctx(BOB), "info", vec![]) else {
+            panic!("expected the game's info");
+        };
+
+        assert_eq!(snapshot.mode, "classic");
+        assert_eq!(snapshot.status, GameStatus::ONGOING);
+        assert_eq!(snapshot.wager, 500);
+        assert_eq!(snapshot.players, [ALICE.to_string(), BOB.to_string()]);
+        assert_eq!(snapshot.pending, [BOB.to_string()]);
+        assert_eq!(snapshot.rolls, [(ALICE.to_string(), derive_roll(&seed, ALICE, 0, 0, 500))]);
+        assert!(snapshot.settlement.is_empty());
+    }
+
+    #[test]
+    fn commands_go_to_the_game_named_by_its_id_or_the_only_one_the_user_is_in() {
+        let mut manager = manager();
+
+        let mut create = |user_id: &str| {
+            let created = manager.execute(&ctx(user_id), "create", vec!["500"]);
+            let Ok(GGMResponse::ShowJoinInfo(game_id, ..)) = created else {
+                panic!("expected a new game");
+            };
+
+            game_id
+        };
+
+        let (alices_game, carols_game) = (create(ALICE), create(CAROL));
+        let mut game_ids = vec![alices_game.clone(), carols_game.clone()];
+        game_ids.sort();
+
+        let Err(GameError::SeveralGames(choices)) = manager.execute(&ctx(BOB), "join", vec![]) else {
+            panic!("expected bob to have to pick a game");
+        };
+        assert_eq!(choices, game_ids);
+
+        assert!(manager.execute(&ctx(BOB), "join", vec![&carols_game]).is_ok());
+        assert_eq!(manager.find_game(&ctx(BOB), &[]), Some(carols_game.clone()));
+
+        let Ok(GGMResponse::ShowGeneralInfo(snapshot)) = manager.execute(&ctx(BOB), "info", vec![]) else {
+            panic!("expected the info of carol's game");
+        };
+        assert_eq!(snapshot.players, [BOB.to_string(), CAROL.to_string()]);
+
+        let Ok(GGMResponse::ShowGeneralInfo(snapshot)) = manager.execute(&ctx(BOB), "info", vec![&alices_game]) else {
+            panic!("expected the info of alice's game");
+        };
+        assert_eq!(snapshot.players, [ALICE.to_string()]);
+    }
+}
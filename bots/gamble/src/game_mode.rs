@@ -0,0 +1,98 @@
+use serde::{ Deserialize, Serialize };
+
+use crate::{
+    error::GameError,
+    fairness::FairDice,
+    gamble_blackjack::GambleBlackjack,
+    gamble_classic::GambleClassic,
+    gamble_death_roll::GambleDeathRoll,
+    gamble_game::BoxedGambleGame,
+    messages::MessageKey,
+    types::{ PlayerId, RollValue },
+};
+
+/// Limits a game enforces, supplied by its mode when the game is created.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GameRules {
+    pub min_players: u64,
+    pub min_wager: u64,
+}
+
+/// A kind of game players can pick with `g!create <MODE> <GOLD_AMOUNT>`.
+pub struct GameMode {
+    pub name: &'static str,
+    pub summary: MessageKey,
+    pub help: MessageKey,
+    /// What `g!info` shows while players can still join.
+    pub info_waiting: MessageKey,
+    /// What `g!info` shows once the game started.
+    pub info_ongoing: MessageKey,
+    pub rules: GameRules,
+    /// Commands the mode is played with besides `g!roll`, e.g. `g!hit`, with their button label.
+    pub actions: &'static [(&'static str, MessageKey)],
+    pub create: fn(PlayerId, RollValue, GameRules, FairDice) -> Result<BoxedGambleGame, GameError>,
+}
+
+pub const DEFAULT_GAME_MODE: &str = "classic";
+
+pub const GAME_MODES: &[GameMode] = &[
+    GameMode {
+        name: "classic",
+        summary: MessageKey::ClassicSummary,
+        help: MessageKey::ClassicHelp,
+        info_waiting: MessageKey::InfoClassicWaiting,
+        info_ongoing: MessageKey::InfoClassicOngoing,
+        rules: GameRules {
+            min_players: 2,
+            min_wager: 100,
+        },
+        actions: &[],
+        create: |player_id, wager, rules, dice| {
+            Ok(Box::new(GambleClassic::new(player_id, wager, rules, dice)?))
+        },
+    },
+    GameMode {
+        name: "deathroll",
+        summary: MessageKey::DeathRollSummary,
+        help: MessageKey::DeathRollHelp,
+        info_waiting: MessageKey::InfoDeathRollWaiting,
+        info_ongoing: MessageKey::InfoDeathRollOngoing,
+        rules: GameRules {
+            min_players: 2,
+            min_wager: 100,
+        },
+        actions: &[],
+        create: |player_id, wager, rules, dice| {
+            Ok(Box::new(GambleDeathRoll::new(player_id, wager, rules, dice)?))
+        },
+    },
+    GameMode {
+        name: "blackjack",
+        summary: MessageKey::BlackjackSummary,
+        help: MessageKey::BlackjackHelp,
+        info_waiting: MessageKey::InfoBlackjackWaiting,
+        info_ongoing: MessageKey::InfoBlackjackTable,
+        rules: GameRules {
+            min_players: 2,
+            min_wager: 100,
+        },
+        actions: &[
+            ("hit", MessageKey::ButtonHit),
+            ("stand", MessageKey::ButtonStand),
+            ("double", MessageKey::ButtonDouble),
+            ("split", MessageKey::ButtonSplit),
+        ],
+        create: |player_id, wager, rules, dice| {
+            Ok(Box::new(GambleBlackjack::new(player_id, wager, rules, dice)?))
+        },
+    },
+];
+
+pub fn find_game_mode(name: &str) -> Option<&'static GameMode> {
+    GAME_MODES.iter().find(|mode| mode.name == name)
+}
+
+/// Whether a command is one of the moves some mode is played with.
+pub fn is_game_action(command: &str) -> bool {
+    GAME_MODES.iter().any(|mode| mode.actions.iter().any(|(action, _)| *action == command))
+}
@@ -0,0 +1,120 @@
+use std::{ path::Path, sync::Mutex };
+
+use rusqlite::{ Connection, params };
+use serde::{ Serialize, de::DeserializeOwned };
+use tracing::error;
+
+/// Snapshots in-progress games to the local SQLite database so a restart doesn't lose them.
+pub struct GameStore {
+    /// Every channel task snapshots through the same connection.
+    conn: Mutex<Connection>,
+}
+
+const SCHEMA: &str =
+    r#"
+CREATE TABLE IF NOT EXISTS games (
+    game_id TEXT PRIMARY KEY,
+    state TEXT NOT NULL
+);
+"#;
+
+impl GameStore {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// A throwaway database, e.g. for the CLI simulator.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    pub fn save(&self, game_id: &str, game: &impl Serialize) -> Result<(), Box<dyn std::error::Error>> {
+        let state = serde_json::to_string(game)?;
+
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO games (game_id, state) VALUES (?1, ?2) ON CONFLICT (game_id) DO UPDATE SET state = excluded.state",
+            params![game_id, state]
+        )?;
+
+        Ok(())
+    }
+
+    /// Whether a game with this id is snapshotted, in any channel.
+    pub fn contains(&self, game_id: &str) -> rusqlite::Result<bool> {
+        self.conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT EXISTS (SELECT 1 FROM games WHERE game_id = ?1)", params![game_id], |row| row.get(0))
+    }
+
+    pub fn delete(&self, game_id: &str) -> rusqlite::Result<()> {
+        self.conn.lock().unwrap().execute("DELETE FROM games WHERE game_id = ?1", params![game_id])?;
+        Ok(())
+    }
+
+    /// Every snapshotted game. Snapshots that can no longer be read, e.g. from an older version, are
+    /// logged and discarded rather than restored half-initialized.
+    pub fn load_all<T: DeserializeOwned>(&self) -> rusqlite::Result<Vec<(String, T)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare("SELECT game_id, state FROM games")?;
+
+        let rows = statement
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<rusqlite::Result<Vec<(String, String)>>>()?;
+
+        let mut games = vec![];
+
+        for (game_id, state) in rows {
+            match serde_json::from_str::<T>(&state) {
+                Ok(game) => games.push((game_id, game)),
+                Err(why) => {
+                    error!(?why, game_id, "gamble: failed to restore game snapshot, discarding it");
+                    conn.execute("DELETE FROM games WHERE game_id = ?1", params![game_id])?;
+                }
+            }
+        }
+
+        Ok(games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Game {
+        players: Vec<String>,
+    }
+
+    #[test]
+    fn snapshots_round_trip_until_deleted() {
+        let store = GameStore::open_in_memory().unwrap();
+        let game = Game { players: vec!["a".into()] };
+        store.save("1a2b3c4d", &game).unwrap();
+
+        assert!(store.contains("1a2b3c4d").unwrap());
+        assert_eq!(store.load_all::<Game>().unwrap(), vec![("1a2b3c4d".to_string(), game)]);
+
+        store.delete("1a2b3c4d").unwrap();
+        assert!(!store.contains("1a2b3c4d").unwrap());
+        assert!(store.load_all::<Game>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn unreadable_snapshots_are_discarded() {
+        let store = GameStore::open_in_memory().unwrap();
+        // Written before games had players.
+        store.save("1a2b3c4d", &serde_json::json!({})).unwrap();
+
+        assert!(store.load_all::<Game>().unwrap().is_empty());
+        assert!(!store.contains("1a2b3c4d").unwrap());
+    }
+}
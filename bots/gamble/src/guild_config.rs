@@ -0,0 +1,275 @@
+use std::{ collections::HashMap, path::Path, sync::Mutex };
+
+use rusqlite::{ Connection, OptionalExtension, params };
+use serde::{ Deserialize, Serialize };
+use tracing::error;
+
+use crate::{
+    commands::COMMAND_PREFIX,
+    error::GameError,
+    game_mode::{ GameMode, GameRules },
+    locale::Locale,
+    messages::Messages,
+    tone::Tone,
+};
+
+/// Settings a guild can change with `g!config set`. Unset limits fall back to the game mode's.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildConfig {
+    pub prefix: String,
+    pub min_players: Option<u64>,
+    pub min_wager: Option<u64>,
+    pub tone: Tone,
+    /// Unset, everybody reads the bot in their own Discord language.
+    pub locale: Option<Locale>,
+    /// Whether `g!create` opens a thread off the command and plays the game in it.
+    pub threads: bool,
+}
+
+impl Default for GuildConfig {
+    fn default() -> Self {
+        Self {
+            prefix: COMMAND_PREFIX.to_string(),
+            min_players: None,
+            min_wager: None,
+            tone: Tone::default(),
+            locale: None,
+            threads: false,
+        }
+    }
+}
+
+/// Every key `g!config get|set` accepts.
+pub const CONFIG_KEYS: &[&str] = &["prefix", "min_players", "min_wager", "tone", "language", "threads"];
+
+/// Value that puts a setting back to its default.
+const DEFAULT_VALUE: &str = "default";
+
+const MAX_PREFIX_LENGTH: usize = 5;
+
+impl GuildConfig {
+    /// The mode's rules with this guild's limits applied.
+    pub fn rules(&self, mode: &GameMode) -> GameRules {
+        GameRules {
+            min_players: self.min_players.unwrap_or(mode.rules.min_players),
+            min_wager: self.min_wager.unwrap_or(mode.rules.min_wager),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Result<String, GameError> {
+        let fmt_limit = |limit: Option<u64>| {
+            limit.map(|limit| limit.to_string()).unwrap_or_else(|| DEFAULT_VALUE.to_string())
+        };
+
+        match key {
+            "prefix" => Ok(self.prefix.clone()),
+            "min_players" => Ok(fmt_limit(self.min_players)),
+            "min_wager" => Ok(fmt_limit(self.min_wager)),
+            "tone" => Ok(self.tone.name().to_string()),
+            "language" => Ok(self.locale.map(|locale| locale.name()).unwrap_or(DEFAULT_VALUE).to_string()),
+            "threads" => Ok((if self.threads { "on" } else { "off" }).to_string()),
+            _ => Err(GameError::UnknownConfigKey),
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), GameError> {
+        let invalid = || GameError::InvalidConfigValue(key.to_string());
+        let parse_limit = |minimum: u64| -> Result<Option<u64>, GameError> {
+            if value == DEFAULT_VALUE {
+                return Ok(None);
+            }
+
+            match value.parse::<u64>() {
+                Ok(limit) if limit >= minimum => Ok(Some(limit)),
+                _ => Err(invalid()),
+            }
+        };
+
+        match key {
+            "prefix" => {
+                if value == DEFAULT_VALUE {
+                    self.prefix = COMMAND_PREFIX.to_string();
+                    return Ok(());
+                }
+
+                // Slashes, mentions and backticks would clash with slash commands and the hints.
+                let clashes = value.starts_with(['/', '<', '@']) || value.contains('`');
+                // Without a symbol, everyday words such as "go" would be taken for commands.
+                let wordlike = value.chars().all(char::is_alphanumeric);
+
+                if value.chars().count() > MAX_PREFIX_LENGTH || clashes || wordlike {
+                    return Err(invalid());
+                }
+
+                self.prefix = value.to_string();
+            }
+            // A game needs somebody to lose against.
+            "min_players" => {
+                self.min_players = parse_limit(2)?;
+            }
+            "min_wager" => {
+                self.min_wager = parse_limit(1)?;
+            }
+            "tone" => {
+                self.tone = match value {
+                    DEFAULT_VALUE => Tone::default(),
+                    tone => tone.parse().map_err(|_| invalid())?,
+                };
+            }
+            "language" => {
+                self.locale = match value {
+                    DEFAULT_VALUE => None,
+                    locale => Some(locale.parse().map_err(|_| invalid())?),
+                };
+            }
+            "threads" => {
+                self.threads = match value {
+                    "on" => true,
+                    "off" | DEFAULT_VALUE => false,
+                    _ => {
+                        return Err(invalid());
+                    }
+                };
+            }
+            _ => {
+                return Err(GameError::UnknownConfigKey);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// These settings as seen by a user whose Discord client is set to `locale`, which the
+    /// guild's language takes precedence over.
+    pub fn for_user(mut self, locale: Option<Locale>) -> Self {
+        self.locale = self.locale.or(locale);
+        self
+    }
+
+    pub fn messages(&self) -> Messages {
+        Messages { locale: self.locale.unwrap_or_default(), tone: self.tone }
+    }
+
+    /// The prefix in the hints of a message, which are written with the default one.
+    pub fn with_prefix(&self, message: String) -> String {
+        if self.prefix == COMMAND_PREFIX {
+            return message;
+        }
+
+        message.replace(&format!("`{}", COMMAND_PREFIX), &format!("`{}", self.prefix))
+    }
+}
+
+/// Keeps each guild's settings in the local SQLite database, cached in memory since the prefix
+/// is needed for every message.
+pub struct GuildConfigStore {
+    conn: Mutex<Connection>,
+    cache: Mutex<HashMap<String, GuildConfig>>,
+}
+
+const SCHEMA: &str =
+    r#"
+CREATE TABLE IF NOT EXISTS guild_config (
+    guild_id TEXT PRIMARY KEY,
+    config TEXT NOT NULL
+);
+"#;
+
+impl GuildConfigStore {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// A throwaway database, e.g. for the CLI simulator.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn: Mutex::new(conn), cache: Default::default() })
+    }
+
+    /// The guild's settings, or the defaults in DMs and for guilds that never changed anything.
+    pub fn get(&self, guild_id: Option<&str>) -> GuildConfig {
+        let Some(guild_id) = guild_id else {
+            return GuildConfig::default();
+        };
+
+        if let Some(config) = self.cache.lock().unwrap().get(guild_id) {
+            return config.clone();
+        }
+
+        let config = match self.load(guild_id) {
+            Ok(config) => config.unwrap_or_default(),
+            Err(why) => {
+                // Not cached, so the next message tries again.
+                error!(?why, guild_id, "gamble: failed to load guild config");
+                return GuildConfig::default();
+            }
+        };
+
+        self.cache.lock().unwrap().insert(guild_id.to_string(), config.clone());
+
+        config
+    }
+
+    fn load(&self, guild_id: &str) -> Result<Option<GuildConfig>, Box<dyn std::error::Error>> {
+        let config = self.conn
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT config FROM guild_config WHERE guild_id = ?1",
+                params![guild_id],
+                |row| row.get::<_, String>(0)
+            )
+            .optional()?;
+
+        match config {
+            Some(config) => Ok(Some(serde_json::from_str(&config)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn save(&self, guild_id: &str, config: &GuildConfig) -> Result<(), Box<dyn std::error::Error>> {
+        let state = serde_json::to_string(config)?;
+
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO guild_config (guild_id, config) VALUES (?1, ?2) ON CONFLICT (guild_id) DO UPDATE SET config = excluded.config",
+            params![guild_id, state]
+        )?;
+
+        self.cache.lock().unwrap().insert(guild_id.to_string(), config.clone());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixes_need_a_symbol_and_cannot_clash_with_mentions_or_hints() {
+        let mut config = GuildConfig::default();
+
+        for prefix in ["g", "gg", "bet", "42", "/g", "<g", "@g", "g`", "gamble!"] {
+            assert!(
+                matches!(config.set("prefix", prefix), Err(GameError::InvalidConfigValue(_))),
+                "{:?} was accepted",
+                prefix
+            );
+        }
+
+        assert_eq!(config.prefix, COMMAND_PREFIX);
+
+        for prefix in ["!", "g?", "$$", "bet."] {
+            config.set("prefix", prefix).unwrap();
+            assert_eq!(config.prefix, prefix);
+        }
+
+        config.set("prefix", "default").unwrap();
+        assert_eq!(config.prefix, COMMAND_PREFIX);
+    }
+}
@@ -0,0 +1,620 @@
+use std::{ path::Path, sync::Mutex };
+
+use rusqlite::{ Connection, params, types::Type };
+use serde::Serialize;
+
+use crate::{ fairness::FairGame, types::{ PlayerId, RollValue } };
+
+/// One transfer of a finished game's settlement: `debtor` owes `creditor` `amount` gold.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub guild_id: Option<String>,
+    pub channel_id: String,
+    pub debtor: PlayerId,
+    pub creditor: PlayerId,
+    pub amount: RollValue,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+}
+
+/// Gold `debtor` owes `creditor`: one transfer of a game's settlement, or what remains owed
+/// between two players once every outcome between them has been netted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Debt {
+    pub debtor: PlayerId,
+    pub creditor: PlayerId,
+    pub amount: RollValue,
+}
+
+impl Debt {
+    pub fn new(debtor: &str, creditor: &str, amount: RollValue) -> Self {
+        return Self { debtor: debtor.to_string(), creditor: creditor.to_string(), amount };
+    }
+}
+
+/// A payment the debtor claims to have made. It only reduces the debt once the creditor confirms it.
+#[derive(Debug, Clone)]
+pub struct Payment {
+    pub guild_id: String,
+    pub channel_id: String,
+    pub payer: PlayerId,
+    pub payee: PlayerId,
+    pub amount: RollValue,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+}
+
+/// Aggregated results of every game a player took part in until the end. Wins and losses are the
+/// games they came out of ahead or behind.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerStats {
+    pub player_id: PlayerId,
+    pub games: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub net: i64,
+    pub biggest_win: RollValue,
+    pub biggest_loss: RollValue,
+}
+
+/// Time window for statistics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Period {
+    Week,
+    Month,
+    All,
+}
+
+impl Period {
+    pub fn parse(period: &str) -> Option<Self> {
+        match period {
+            "week" => Some(Period::Week),
+            "month" => Some(Period::Month),
+            "all" => Some(Period::All),
+            _ => None,
+        }
+    }
+
+    /// Earliest timestamp included in the period.
+    pub fn since(&self, now: u64) -> u64 {
+        const DAY: u64 = 24 * 60 * 60;
+
+        match self {
+            Period::Week => now.saturating_sub(7 * DAY),
+            Period::Month => now.saturating_sub(30 * DAY),
+            Period::All => 0,
+        }
+    }
+}
+
+/// Records game outcomes in a local SQLite database so debts outlive the game.
+pub struct Ledger {
+    /// Shared by every channel's task, each query only holds the lock while it runs.
+    conn: Mutex<Connection>,
+}
+
+const SCHEMA: &str =
+    r#"
+CREATE TABLE IF NOT EXISTS finished_games (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id TEXT,
+    channel_id TEXT NOT NULL,
+    finished_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS finished_games_guild ON finished_games (guild_id, finished_at);
+CREATE TABLE IF NOT EXISTS participants (
+    game_id INTEGER NOT NULL REFERENCES finished_games (id),
+    player_id TEXT NOT NULL,
+    net INTEGER NOT NULL,
+    PRIMARY KEY (game_id, player_id)
+);
+CREATE TABLE IF NOT EXISTS outcomes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_id INTEGER NOT NULL REFERENCES finished_games (id),
+    guild_id TEXT,
+    channel_id TEXT NOT NULL,
+    debtor TEXT NOT NULL,
+    creditor TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS outcomes_guild ON outcomes (guild_id, timestamp);
+CREATE TABLE IF NOT EXISTS payments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    payer TEXT NOT NULL,
+    payee TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    confirmed INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX IF NOT EXISTS payments_guild ON payments (guild_id, payee, confirmed);
+CREATE TABLE IF NOT EXISTS fair_games (
+    game_id TEXT PRIMARY KEY,
+    seed_hash TEXT NOT NULL,
+    server_seed TEXT NOT NULL,
+    rolls TEXT NOT NULL
+);
+"#;
+
+/// Pairwise balances for a guild: every outcome and confirmed payment counts once in each
+/// direction so that summing per (debtor, creditor) pair leaves only the net amount still owed.
+const DEBTS_QUERY: &str =
+    r#"
+SELECT debtor, creditor, SUM(amount) AS owed FROM (
+    SELECT debtor, creditor, amount FROM outcomes WHERE guild_id = ?1
+    UNION ALL
+    SELECT creditor AS debtor, debtor AS creditor, -amount FROM outcomes WHERE guild_id = ?1
+    UNION ALL
+    SELECT payee AS debtor, payer AS creditor, amount FROM payments WHERE guild_id = ?1 AND confirmed = 1
+    UNION ALL
+    SELECT payer AS debtor, payee AS creditor, -amount FROM payments WHERE guild_id = ?1 AND confirmed = 1
+)
+GROUP BY debtor, creditor
+HAVING owed > 0
+ORDER BY owed DESC
+"#;
+
+/// Per-player statistics for a guild since a given timestamp, best net result first. A game counts
+/// once for each of its participants, however many transfers its settlement had.
+const STATS_QUERY: &str =
+    r#"
+SELECT player_id, COUNT(DISTINCT game_id), SUM(net > 0), SUM(net < 0), SUM(net), MAX(MAX(net, 0)), MAX(MAX(-net, 0))
+FROM participants
+JOIN finished_games ON finished_games.id = participants.game_id
+WHERE guild_id = ?1 AND finished_at >= ?2 AND (?3 IS NULL OR player_id = ?3)
+GROUP BY player_id
+ORDER BY SUM(net) DESC, player_id
+LIMIT ?4
+"#;
+
+impl Ledger {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// A throwaway database, e.g. for the CLI simulator.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        rename_outcome_parties(&conn)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// Records a finished game: what each of its `players` came out of it with, every transfer of its
+    /// settlement as an outcome, and its seed and rolls for `g!verify`. Either all of it is written
+    /// or none of it.
+    pub fn record_game(
+        &self,
+        guild_id: Option<&String>,
+        channel_id: &str,
+        players: &[PlayerId],
+        settlement: &[Debt],
+        fair_game: &FairGame,
+        timestamp: u64
+    ) -> rusqlite::Result<()> {
+        let rolls = serde_json
+            ::to_string(&fair_game.rolls)
+            .map_err(|why| rusqlite::Error::ToSqlConversionFailure(Box::new(why)))?;
+
+        let mut conn = self.conn.lock().unwrap();
+        let transaction = conn.transaction()?;
+
+        transaction.execute(
+            "INSERT INTO fair_games (game_id, seed_hash, server_seed, rolls) VALUES (?1, ?2, ?3, ?4)",
+            params![fair_game.game_id, fair_game.seed_hash, fair_game.server_seed, rolls]
+        )?;
+
+        transaction.execute(
+            "INSERT INTO finished_games (guild_id, channel_id, finished_at) VALUES (?1, ?2, ?3)",
+            params![guild_id, channel_id, timestamp as i64]
+        )?;
+
+        let game_id = transaction.last_insert_rowid();
+        // Players who left before the end can still be part of the settlement.
+        let mut nets = players
+            .iter()
+            .map(|player_id| (player_id, 0))
+            .collect::<Vec<(&PlayerId, i64)>>();
+
+        for debt in settlement {
+            for (player_id, amount) in [(&debt.creditor, debt.amount as i64), (&debt.debtor, -(debt.amount as i64))] {
+                match nets.iter_mut().find(|(id, _)| *id == player_id) {
+                    Some((_, net)) => *net += amount,
+                    None => nets.push((player_id, amount)),
+                }
+            }
+
+            transaction.execute(
+                "INSERT INTO outcomes (game_id, guild_id, channel_id, debtor, creditor, amount, timestamp) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    game_id,
+                    guild_id,
+                    channel_id,
+                    debt.debtor,
+                    debt.creditor,
+                    debt.amount as i64,
+                    timestamp as i64
+                ]
+            )?;
+        }
+
+        for (player_id, net) in nets {
+            transaction.execute(
+                "INSERT INTO participants (game_id, player_id, net) VALUES (?1, ?2, ?3)",
+                params![game_id, player_id, net]
+            )?;
+        }
+
+        transaction.commit()
+    }
+
+    /// Every net debt in a guild, largest first.
+    pub fn debts(&self, guild_id: &str) -> rusqlite::Result<Vec<Debt>> {
+        query_debts(&self.conn.lock().unwrap(), guild_id)
+    }
+
+    /// The most recent outcomes recorded in a guild, newest first.
+    pub fn recent_outcomes(&self, guild_id: &str, limit: usize) -> rusqlite::Result<Vec<Outcome>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "SELECT guild_id, channel_id, debtor, creditor, amount, timestamp FROM outcomes WHERE guild_id = ?1 ORDER BY timestamp DESC, id DESC LIMIT ?2"
+        )?;
+
+        let outcomes = statement
+            .query_map(params![guild_id, limit as i64], |row| {
+                Ok(Outcome {
+                    guild_id: row.get(0)?,
+                    channel_id: row.get(1)?,
+                    debtor: row.get(2)?,
+                    creditor: row.get(3)?,
+                    amount: row.get::<_, i64>(4)? as RollValue,
+                    timestamp: row.get::<_, i64>(5)? as u64,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<Outcome>>>()?;
+
+        Ok(outcomes)
+    }
+
+    /// Stores a payment claim until the payee confirms it, as long as it doesn't exceed what the payer
+    /// still owes the payee: `owed` works out the debt from the guild's debts, and the payer's other
+    /// pending claims to the payee are taken off it. Returns what was left of the debt before this
+    /// claim.
+    ///
+    /// The debt is read and the claim written in one transaction, so two claims made at the same
+    /// time can't both pay off the same debt.
+    pub fn record_payment(
+        &self,
+        payment: &Payment,
+        owed: impl FnOnce(&[Debt]) -> RollValue
+    ) -> rusqlite::Result<RollValue> {
+        let mut conn = self.conn.lock().unwrap();
+        let transaction = conn.transaction()?;
+
+        let owed = owed(&query_debts(&transaction, &payment.guild_id)?);
+        let pending = transaction.query_row(
+            "SELECT COALESCE(SUM(amount), 0) FROM payments WHERE guild_id = ?1 AND payer = ?2 AND payee = ?3 AND confirmed = 0",
+            params![payment.guild_id, payment.payer, payment.payee],
+            |row| row.get::<_, i64>(0)
+        )?;
+        let remaining = owed.saturating_sub(pending as RollValue);
+
+        if payment.amount <= remaining {
+            transaction.execute(
+                "INSERT INTO payments (guild_id, channel_id, payer, payee, amount, timestamp) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    payment.guild_id,
+                    payment.channel_id,
+                    payment.payer,
+                    payment.payee,
+                    payment.amount as i64,
+                    payment.timestamp as i64
+                ]
+            )?;
+        }
+
+        transaction.commit()?;
+
+        Ok(remaining)
+    }
+
+    /// Confirms every pending payment to `payee` (optionally only from `payer`), returning what was confirmed.
+    ///
+    /// Done in a single statement, so a payment confirmed twice at the same time is only returned once.
+    pub fn confirm_payments(
+        &self,
+        guild_id: &str,
+        payee: &PlayerId,
+        payer: Option<&PlayerId>
+    ) -> rusqlite::Result<Vec<Payment>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "UPDATE payments SET confirmed = 1 WHERE guild_id = ?1 AND payee = ?2 AND (?3 IS NULL OR payer = ?3) AND confirmed = 0 RETURNING id, guild_id, channel_id, payer, payee, amount, timestamp"
+        )?;
+
+        let mut payments = statement
+            .query_map(params![guild_id, payee, payer], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    Payment {
+                        guild_id: row.get(1)?,
+                        channel_id: row.get(2)?,
+                        payer: row.get(3)?,
+                        payee: row.get(4)?,
+                        amount: row.get::<_, i64>(5)? as RollValue,
+                        timestamp: row.get::<_, i64>(6)? as u64,
+                    },
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<(i64, Payment)>>>()?;
+
+        // `RETURNING` doesn't keep any order.
+        payments.sort_by_key(|(id, _)| *id);
+
+        Ok(payments.into_iter().map(|(_, payment)| payment).collect())
+    }
+
+    fn stats(
+        &self,
+        guild_id: &str,
+        since: u64,
+        player_id: Option<&PlayerId>,
+        limit: usize
+    ) -> rusqlite::Result<Vec<PlayerStats>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(STATS_QUERY)?;
+
+        let stats = statement
+            .query_map(params![guild_id, since as i64, player_id, limit as i64], |row| {
+                Ok(PlayerStats {
+                    player_id: row.get(0)?,
+                    games: row.get::<_, i64>(1)? as u64,
+                    wins: row.get::<_, i64>(2)? as u64,
+                    losses: row.get::<_, i64>(3)? as u64,
+                    net: row.get(4)?,
+                    biggest_win: row.get::<_, i64>(5)? as RollValue,
+                    biggest_loss: row.get::<_, i64>(6)? as RollValue,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<PlayerStats>>>()?;
+
+        Ok(stats)
+    }
+
+    /// All-time statistics for one player; zeroed if they never finished a game.
+    pub fn player_stats(&self, guild_id: &str, player_id: &PlayerId) -> rusqlite::Result<PlayerStats> {
+        let stats = self.stats(guild_id, 0, Some(player_id), 1)?;
+
+        Ok(
+            stats.into_iter().next().unwrap_or_else(|| PlayerStats {
+                player_id: player_id.clone(),
+                ..Default::default()
+            })
+        )
+    }
+
+    /// The players with the best net result since `since`.
+    pub fn leaderboard(
+        &self,
+        guild_id: &str,
+        since: u64,
+        limit: usize
+    ) -> rusqlite::Result<Vec<PlayerStats>> {
+        self.stats(guild_id, since, None, limit)
+    }
+
+    pub fn fair_game(&self, game_id: &str) -> rusqlite::Result<Option<FairGame>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "SELECT game_id, seed_hash, server_seed, rolls FROM fair_games WHERE game_id = ?1"
+        )?;
+
+        let game = statement
+            .query_map(params![game_id], |row| {
+                let rolls = serde_json
+                    ::from_str(&row.get::<_, String>(3)?)
+                    .map_err(|why| {
+                        rusqlite::Error::FromSqlConversionFailure(3, Type::Text, Box::new(why))
+                    })?;
+
+                Ok(FairGame {
+                    game_id: row.get(0)?,
+                    seed_hash: row.get(1)?,
+                    server_seed: row.get(2)?,
+                    rolls,
+                })
+            })?
+            .next()
+            .transpose()?;
+
+        Ok(game)
+    }
+}
+
+/// Ledgers created before outcomes were named after the debt they stand for still call their parties
+/// `winner` and `loser`.
+fn rename_outcome_parties(conn: &Connection) -> rusqlite::Result<()> {
+    let outdated = conn
+        .prepare("SELECT 1 FROM pragma_table_info('outcomes') WHERE name = 'winner'")?
+        .exists([])?;
+
+    if outdated {
+        conn.execute_batch(
+            "ALTER TABLE outcomes RENAME COLUMN winner TO creditor; ALTER TABLE outcomes RENAME COLUMN loser TO debtor;"
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Every net debt in a guild, largest first, read through `conn` so it can be part of a transaction.
+fn query_debts(conn: &Connection, guild_id: &str) -> rusqlite::Result<Vec<Debt>> {
+    let mut statement = conn.prepare(DEBTS_QUERY)?;
+
+    let debts = statement
+        .query_map(params![guild_id], |row| {
+            Ok(Debt {
+                debtor: row.get(0)?,
+                creditor: row.get(1)?,
+                amount: row.get::<_, i64>(2)? as RollValue,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<Debt>>>()?;
+
+    Ok(debts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::GUILD_ID;
+
+    /// Records a game of `guild_id` that finished at `timestamp` with `settlement`.
+    fn record(ledger: &Ledger, guild_id: &str, players: &[&str], settlement: &[Debt], timestamp: u64) {
+        let fair_game = FairGame {
+            game_id: format!("{:08x}", timestamp),
+            seed_hash: String::new(),
+            server_seed: String::new(),
+            rolls: vec![],
+        };
+        let players = players
+            .iter()
+            .map(|player_id| player_id.to_string())
+            .collect::<Vec<PlayerId>>();
+
+        ledger.record_game(Some(&guild_id.into()), "10", &players, settlement, &fair_game, timestamp).unwrap();
+    }
+
+    fn payment(payer: &str, payee: &str, amount: RollValue) -> Payment {
+        Payment {
+            guild_id: GUILD_ID.into(),
+            channel_id: "10".into(),
+            payer: payer.into(),
+            payee: payee.into(),
+            amount,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn outcomes_recorded_with_winners_and_losers_are_read_as_debts() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            r#"
+CREATE TABLE outcomes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_id INTEGER NOT NULL,
+    guild_id TEXT,
+    channel_id TEXT NOT NULL,
+    winner TEXT NOT NULL,
+    loser TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL
+);
+INSERT INTO outcomes (game_id, guild_id, channel_id, winner, loser, amount, timestamp) VALUES (1, '1', '10', 'a', 'b', 100, 0);
+"#
+        ).unwrap();
+
+        let ledger = Ledger::init(conn).unwrap();
+        let outcome = &ledger.recent_outcomes(GUILD_ID, 10).unwrap()[0];
+
+        assert_eq!((outcome.debtor.as_str(), outcome.creditor.as_str()), ("b", "a"));
+        assert_eq!(ledger.debts(GUILD_ID).unwrap(), vec![Debt::new("b", "a", 100)]);
+    }
+
+    #[test]
+    fn games_won_back_and_forth_net_out() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        record(&ledger, GUILD_ID, &[], &[Debt::new("a", "b", 100)], 1);
+        record(&ledger, GUILD_ID, &[], &[Debt::new("b", "a", 30)], 2);
+        record(&ledger, GUILD_ID, &[], &[Debt::new("c", "d", 50)], 3);
+        record(&ledger, GUILD_ID, &[], &[Debt::new("d", "c", 50)], 4);
+
+        assert_eq!(ledger.debts(GUILD_ID).unwrap(), vec![Debt::new("a", "b", 70)]);
+    }
+
+    #[test]
+    fn only_confirmed_payments_pay_off_debts() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        record(&ledger, GUILD_ID, &[], &[Debt::new("a", "b", 100)], 1);
+
+        assert_eq!(ledger.record_payment(&payment("a", "b", 40), |_| 100).unwrap(), 100);
+        assert_eq!(ledger.debts(GUILD_ID).unwrap(), vec![Debt::new("a", "b", 100)]);
+
+        ledger.confirm_payments(GUILD_ID, &"b".into(), None).unwrap();
+        assert_eq!(ledger.debts(GUILD_ID).unwrap(), vec![Debt::new("a", "b", 60)]);
+    }
+
+    #[test]
+    fn paying_more_than_owed_leaves_the_payer_owed() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        record(&ledger, GUILD_ID, &[], &[Debt::new("a", "b", 100)], 1);
+
+        // Paid along a settlement, which asks for more than this pair's own debt.
+        ledger.record_payment(&payment("a", "b", 150), |_| 150).unwrap();
+        ledger.confirm_payments(GUILD_ID, &"b".into(), None).unwrap();
+
+        assert_eq!(ledger.debts(GUILD_ID).unwrap(), vec![Debt::new("b", "a", 50)]);
+    }
+
+    #[test]
+    fn debts_are_kept_per_guild() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        record(&ledger, GUILD_ID, &[], &[Debt::new("a", "b", 100)], 1);
+        record(&ledger, "2", &[], &[Debt::new("b", "a", 100)], 2);
+
+        assert_eq!(ledger.debts(GUILD_ID).unwrap(), vec![Debt::new("a", "b", 100)]);
+        assert_eq!(ledger.debts("2").unwrap(), vec![Debt::new("b", "a", 100)]);
+    }
+
+    #[test]
+    fn a_game_counts_once_however_many_transfers_it_settled_with() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        // d broke even.
+        record(&ledger, GUILD_ID, &["a", "b", "c", "d"], &[Debt::new("b", "a", 50), Debt::new("c", "a", 20)], 1);
+        record(&ledger, GUILD_ID, &["a", "b"], &[Debt::new("a", "b", 30)], 2);
+
+        let a = ledger.player_stats(GUILD_ID, &"a".into()).unwrap();
+        assert_eq!((a.games, a.wins, a.losses, a.net, a.biggest_win, a.biggest_loss), (2, 1, 1, 40, 70, 30));
+
+        let b = ledger.player_stats(GUILD_ID, &"b".into()).unwrap();
+        assert_eq!((b.games, b.wins, b.losses, b.net, b.biggest_win, b.biggest_loss), (2, 1, 1, -20, 30, 50));
+
+        let d = ledger.player_stats(GUILD_ID, &"d".into()).unwrap();
+        assert_eq!((d.games, d.wins, d.losses, d.net), (1, 0, 0, 0));
+    }
+
+    #[test]
+    fn players_who_never_finished_a_game_have_empty_stats() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        record(&ledger, "2", &["a", "b"], &[Debt::new("b", "a", 50)], 1);
+
+        let stats = ledger.player_stats(GUILD_ID, &"a".into()).unwrap();
+        assert_eq!((stats.player_id.as_str(), stats.games, stats.net), ("a", 0, 0));
+    }
+
+    #[test]
+    fn the_leaderboard_ranks_the_period_by_net_result() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        record(&ledger, GUILD_ID, &["a", "b"], &[Debt::new("b", "a", 500)], 100);
+        record(&ledger, GUILD_ID, &["a", "b", "c"], &[Debt::new("a", "c", 30), Debt::new("a", "b", 10)], 200);
+
+        let ranking = |since, limit| {
+            ledger
+                .leaderboard(GUILD_ID, since, limit)
+                .unwrap()
+                .into_iter()
+                .map(|stats| (stats.player_id, stats.net))
+                .collect::<Vec<(PlayerId, i64)>>()
+        };
+
+        assert_eq!(ranking(0, 10), vec![("a".into(), 460), ("c".into(), 30), ("b".into(), -490)]);
+        assert_eq!(ranking(150, 10), vec![("c".into(), 30), ("b".into(), 10), ("a".into(), -40)]);
+        assert_eq!(ranking(150, 1), vec![("c".into(), 30)]);
+    }
+}
@@ -1,122 +1,462 @@
-use std::{ error::Error, sync::{ Arc, Mutex }, time::Duration };
+#![allow(clippy::needless_return, clippy::upper_case_acronyms)]
+
+use std::{ error::Error, sync::Arc, time::Duration };
 use tracing::{ info, warn, error };
 use twilight_cache_inmemory::{ DefaultInMemoryCache, ResourceType };
-use twilight_gateway::{ Event, EventTypeFlags, Intents, Shard, ShardId, StreamExt as _ };
+use twilight_gateway::{ Event, Intents, Shard, ShardId };
 use twilight_http::Client as Http;
+use twilight_model::{
+    application::interaction::{ Interaction, InteractionData },
+    channel::message::{ Component, Embed, MessageFlags },
+    guild::Permissions,
+    http::interaction::{ InteractionResponse, InteractionResponseType },
+    id::{ Id, marker::ChannelMarker },
+};
+use twilight_util::builder::InteractionResponseDataBuilder;
 
 use crate::{
-    gamble_game_manager::{ GambleGameManager },
-    utils::{ map_game_error_to_discord_message, map_ggm_response_to_discord_message },
+    commands::{
+        confirm_payment_buttons,
+        gamble_command,
+        game_buttons,
+        parse_command_data,
+        parse_component_data,
+    },
+    dispatcher::Job,
+    embeds::build_embed,
+    game_mode::{ find_game_mode, is_game_action },
+};
+
+pub use crate::{
+    dispatcher::Dispatcher,
+    options::{ GambleOptions, IdlePolicy, IdleTimeouts },
+    transport::{ EventSource, MessageSink, TransportResult },
+};
+
+// Everything needed to drive games without Discord, as the `gamble-cli` simulator and the
+// integration tests do.
+pub use crate::{
+    cards::{ Card, Hand },
+    clock::Clock,
+    commands::{ ParsedCommand, parse_prefixed_message },
+    error::GameError,
+    fairness::{ SeedSource, derive_roll, find_seed },
+    gamble_game::{ GameSnapshot, GameStatus },
+    gamble_game_manager::{ CommandContext, GGMResponse, GambleGameManager },
+    game_store::GameStore,
+    guild_config::{ GuildConfig, GuildConfigStore },
+    ledger::{ Debt, Ledger },
+    locale::{ LOCALES, Locale },
+    messages::{ MessageKey, Messages },
+    tone::{ TONES, Tone },
+    utils::{ map_game_error_to_discord_message, map_ggm_response_to_discord_message, unix_now },
 };
 
+mod cards;
+mod catalog;
+mod clock;
+mod commands;
+mod dispatcher;
+mod embeds;
 mod gamble_game;
+mod gamble_blackjack;
 mod gamble_classic;
+mod gamble_death_roll;
 mod error;
+mod fairness;
 mod types;
 mod gamble_game_manager;
+mod game_mode;
+mod game_store;
+mod guild_config;
+mod ledger;
+mod locale;
+mod messages;
+mod options;
+mod settlement;
+#[cfg(test)]
+mod test_support;
+mod tone;
+mod transport;
 mod utils;
 
 pub struct GambleBot;
 
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// A message to post, with optional components (buttons) attached. Replies with embeds leave
+/// their content empty.
+pub struct Reply {
+    pub content: String,
+    pub components: Vec<Component>,
+    pub embeds: Vec<Embed>,
+}
+
+impl Reply {
+    fn text(content: String) -> Self {
+        Self { content, components: vec![], embeds: vec![] }
+    }
+}
+
 impl GambleBot {
     /// Runs until Ctrl-C or fatal unrecoverable error.
-    pub async fn run(token: String) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let intents = Intents::GUILD_MESSAGES | Intents::DIRECT_MESSAGES | Intents::MESSAGE_CONTENT;
+    ///
+    /// Finished games are recorded in the SQLite database at `options.database_path`, which also
+    /// holds snapshots of in-progress games so they survive a restart, and each guild's settings.
+    pub async fn run(token: String, options: GambleOptions) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let intents =
+            Intents::GUILDS |
+            Intents::GUILD_MESSAGES |
+            Intents::DIRECT_MESSAGES |
+            Intents::MESSAGE_CONTENT;
 
-        let manager: Arc<Mutex<GambleGameManager>> = Arc::new(
-            Mutex::new(GambleGameManager::default())
-        );
+        let ledger = Ledger::open(&options.database_path)?;
+        let store = GameStore::open(&options.database_path)?;
+        let configs = GuildConfigStore::open(&options.database_path)?;
+
+        let mut manager = GambleGameManager::new(Arc::new(ledger), Arc::new(store), Arc::new(configs));
+        manager.restore();
+
+        let http = Arc::new(Http::new(token.clone()));
+        let dispatcher = Dispatcher::new(manager, http.clone(), options.idle_timeouts);
 
         // Simple supervisor loop: if the shard stream ends, recreate it after a short delay.
+        // Channel tasks, and the games they hold, carry over to the new shard.
         loop {
             info!("gamble: starting shard");
-            let http = Arc::new(Http::new(token.clone()));
-            let mut shard = Shard::new(ShardId::ONE, token.clone(), intents);
-
-            let cache = DefaultInMemoryCache::builder()
-                .resource_types(ResourceType::MESSAGE)
-                .build();
-
-            while let Some(item) = shard.next_event(EventTypeFlags::all()).await {
-                let event = match item {
-                    Ok(ev) => ev,
-                    Err(err) => {
-                        warn!(?err, "gamble: error receiving event; continuing");
-                        continue;
-                    }
-                };
+            let shard = Shard::new(ShardId::ONE, token.clone(), intents);
+
+            if let Err(why) = register_commands(&http).await {
+                error!(?why, "gamble: failed to register slash commands");
+            }
+
+            Self::serve(&dispatcher, shard).await;
+
+            // If we’re here, the event stream ended (None).
+            warn!("gamble: shard stream ended; reconnecting after backoff...");
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+    }
+
+    /// Hands every command coming from `source` to its channel's task until the source is exhausted.
+    pub async fn serve<S: MessageSink + 'static>(dispatcher: &Dispatcher<S>, mut source: impl EventSource) {
+        let cache = DefaultInMemoryCache::builder()
+            // Guilds, channels, roles and members are cached to work out moderator permissions,
+            // and the current user to work out whether the bot can post embeds.
+            .resource_types(
+                ResourceType::MESSAGE |
+                    ResourceType::GUILD |
+                    ResourceType::CHANNEL |
+                    ResourceType::ROLE |
+                    ResourceType::MEMBER |
+                    ResourceType::USER_CURRENT
+            )
+            .build();
 
-                cache.update(&event);
+        while let Some(event) = source.next_event().await {
+            cache.update(&event);
 
-                if let Event::MessageCreate(msg) = event {
+            match event {
+                Event::MessageCreate(msg) => {
                     if msg.author.bot {
                         continue;
                     }
 
-                    let message = msg.content.trim();
+                    let guild_id = msg.guild_id.map(|id| id.to_string());
+                    let config = dispatcher.guild_config(guild_id.as_ref());
 
-                    if !message.starts_with("g!") {
+                    let Some(command) = parse_prefixed_message(&msg.content, &config.prefix) else {
                         continue;
-                    }
+                    };
 
-                    let parts = msg.content.trim().split_ascii_whitespace().collect::<Vec<&str>>();
+                    let permissions = cache.permissions().in_channel(msg.author.id, msg.channel_id);
 
-                    let channel_id = msg.channel_id.to_string();
+                    let ctx = CommandContext {
+                        guild_id,
+                        channel_id: msg.channel_id.to_string(),
+                        user_id: msg.author.id.to_string(),
+                        is_moderator: permissions.as_ref().is_ok_and(|permissions| is_moderator(*permissions)),
+                        can_manage_guild: permissions.is_ok_and(can_manage_guild),
+                        locale: None,
+                        can_embed: msg.guild_id.is_none() || bot_can_embed(&cache, msg.channel_id),
+                    };
 
-                    let mut game_manager = manager.lock().unwrap();
+                    // Threads can't have threads of their own, so games created in one stay there.
+                    let in_thread = cache.channel(msg.channel_id).is_some_and(|channel| channel.kind.is_thread());
 
-                    let user_id = msg.author.id.to_string();
+                    let job = if command.name == "create" && config.threads && msg.guild_id.is_some() && !in_thread {
+                        Job::Thread(ctx, command, msg.id, msg.author.name.clone())
+                    } else {
+                        Job::Message(ctx, command)
+                    };
 
-                    let send_message = async |message: String| {
-                        if
-                            let Err(why) = http
-                                .create_message(msg.channel_id)
-                                .content(&message).await
-                        {
-                            error!(?why, "gamble: failed to send message");
-                        }
+                    dispatcher.dispatch(msg.channel_id, job);
+                }
+                Event::InteractionCreate(interaction) => {
+                    let command = match &interaction.data {
+                        Some(InteractionData::ApplicationCommand(data)) =>
+                            parse_command_data(data),
+                        Some(InteractionData::MessageComponent(data)) =>
+                            parse_component_data(data),
+                        _ => None,
                     };
 
-                    match
-                        game_manager.execute(
-                            channel_id.clone(),
-                            user_id.clone(),
-                            parts[0],
-                            parts[1..].to_vec()
-                        )
-                    {
-                        Ok(response) => {
-                            let message = map_ggm_response_to_discord_message(&user_id, response);
-                            if message.is_none() {
-                                continue;
-                            }
-                            send_message(message.unwrap()).await;
-                        }
-                        Err(e) => {
-                            let message = map_game_error_to_discord_message(&user_id, e);
-                            send_message(message).await;
-                        }
-                    }
+                    let (Some(command), Some(channel), Some(user_id)) = (
+                        command,
+                        &interaction.channel,
+                        interaction.author_id(),
+                    ) else {
+                        continue;
+                    };
 
-                    match game_manager.tick(channel_id) {
-                        Ok(response) => {
-                            let message = map_ggm_response_to_discord_message(&user_id, response);
-                            if message.is_none() {
-                                continue;
-                            }
-                            send_message(message.unwrap()).await;
-                        }
-                        Err(e) => {
-                            let message = map_game_error_to_discord_message(&user_id, e);
-                            send_message(message).await;
-                        }
-                    }
+                    let permissions = interaction.member.as_ref().and_then(|member| member.permissions);
+
+                    let ctx = CommandContext {
+                        guild_id: interaction.guild_id.map(|id| id.to_string()),
+                        channel_id: channel.id.to_string(),
+                        user_id: user_id.to_string(),
+                        is_moderator: permissions.is_some_and(is_moderator),
+                        can_manage_guild: permissions.is_some_and(can_manage_guild),
+                        locale: interaction.locale.as_deref().and_then(Locale::from_discord),
+                        can_embed: interaction.app_permissions.is_none_or(|permissions| {
+                            permissions.contains(Permissions::EMBED_LINKS)
+                        }),
+                    };
+
+                    let channel_id = channel.id;
+
+                    dispatcher.dispatch(channel_id, Job::Interaction(ctx, command, Box::new(interaction.0)));
                 }
+                _ => {}
             }
+        }
+    }
+}
 
-            // If we’re here, the event stream ended (None).
-            warn!("gamble: shard stream ended; reconnecting after backoff...");
-            tokio::time::sleep(Duration::from_secs(5)).await;
+async fn register_commands(http: &Http) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let application_id = http.current_user_application().await?.model().await?.id;
+
+    http.interaction(application_id).set_global_commands(&[gamble_command()]).await?;
+
+    Ok(())
+}
+
+/// Moderators can cancel and kick players from games they didn't create.
+fn is_moderator(permissions: Permissions) -> bool {
+    permissions.intersects(Permissions::MANAGE_MESSAGES | Permissions::MANAGE_GUILD)
+}
+
+/// Only members who can manage the guild can change its settings.
+fn can_manage_guild(permissions: Permissions) -> bool {
+    permissions.contains(Permissions::MANAGE_GUILD)
+}
+
+/// Whether the bot itself can post embeds in a guild channel. Unknown until the cache has seen the
+/// bot's member and roles, in which case replies stay plain text.
+fn bot_can_embed(cache: &DefaultInMemoryCache, channel_id: Id<ChannelMarker>) -> bool {
+    let Some(bot) = cache.current_user() else {
+        return false;
+    };
+
+    return cache
+        .permissions()
+        .in_channel(bot.id, channel_id)
+        .is_ok_and(|permissions| permissions.contains(Permissions::EMBED_LINKS));
+}
+
+/// Commands that change who is in a game or what they rolled, and so its board. The moves of modes
+/// played with something else than dice change it as well.
+const BOARD_COMMANDS: [&str; 5] = ["join", "play", "roll", "leave", "kick"];
+
+/// A game's board: a single message showing its players, rolls and who still needs to roll,
+/// edited in place instead of posting a message for every roll.
+pub(crate) struct Board {
+    pub(crate) game_id: String,
+    /// The message showing the board, unless it still has to be posted.
+    pub(crate) message_id: Option<String>,
+    pub(crate) reply: Reply,
+    /// The game is over, so this is the last time the board gets edited.
+    pub(crate) finished: bool,
+}
+
+/// Runs a command against the manager and ticks the game, returning every reply to post and the
+/// board to update.
+fn handle_command(
+    game_manager: &mut GambleGameManager,
+    ctx: &CommandContext,
+    command: ParsedCommand
+) -> (Vec<Reply>, Option<Board>) {
+    let args = command.args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<&str>>();
+
+    // Found before running the command, which can end the game.
+    let game_id = game_manager.find_game(ctx, &args);
+    let message_id = game_id.as_ref().and_then(|game_id| game_manager.board(game_id));
+    let result = game_manager.execute(ctx, &command.name, args);
+    let changes_board = result.is_ok() &&
+        (BOARD_COMMANDS.contains(&command.name.as_str()) || is_game_action(&command.name));
+    let mut responses = vec![result];
+
+    if let Some(game_id) = &game_id {
+        responses.push(game_manager.tick(game_id));
+    }
+
+    // Read after the command so a new prefix shows up in the reply confirming it.
+    let config = game_manager.guild_config(ctx.guild_id.as_ref()).for_user(ctx.locale);
+
+    let board = build_board(
+        game_manager,
+        game_id.as_ref(),
+        changes_board,
+        &ctx.user_id,
+        &responses,
+        &config,
+        ctx.can_embed
+    ).map(|board| Board { message_id, ..board });
+    let replies = map_responses(&ctx.user_id, responses, &config, ctx.can_embed);
+
+    (replies, board)
+}
+
+/// Rolls, rerolls and moves only show up on the game's board.
+fn shown_on_board(response: &Result<GGMResponse, GameError>) -> bool {
+    matches!(
+        response,
+        Ok(
+            GGMResponse::Started |
+            GGMResponse::PlayerRolled(_) |
+            GGMResponse::AutoRolled(..) |
+            GGMResponse::Played(_) |
+            GGMResponse::AutoPlayed(_)
+        ) |
+            Err(GameError::PlayersMatchedLowestRoll(_) | GameError::PlayersMatchedHighestRoll(_))
+    )
+}
+
+/// The final board of `game_id` when `responses` finished or cancelled it, or else its current board
+/// when the command `changed` it. Which message shows it is left to the caller, as finished games are
+/// gone from the manager.
+fn build_board(
+    game_manager: &GambleGameManager,
+    game_id: Option<&String>,
+    changed: bool,
+    user_id: &String,
+    responses: &[Result<GGMResponse, GameError>],
+    config: &GuildConfig,
+    can_embed: bool
+) -> Option<Board> {
+    let game_id = game_id?;
+    let finished = responses.iter().find_map(|response| {
+        match response {
+            Ok(
+                GGMResponse::Done(_, _, snapshot) |
+                GGMResponse::Cancelled(snapshot) |
+                GGMResponse::IdleCancelled(snapshot)
+            ) => Some(snapshot.clone()),
+            _ => None,
+        }
+    });
+
+    let (snapshot, finished) = match finished {
+        Some(snapshot) => (snapshot, true),
+        None if changed => (game_manager.game(game_id)?, false),
+        None => {
+            return None;
+        }
+    };
+
+    let mode = find_game_mode(snapshot.mode)?;
+    let mut reply = map_response(user_id, GGMResponse::ShowGeneralInfo(snapshot), config, can_embed)?;
+
+    // Finished games lose their buttons.
+    if !finished {
+        reply.components = game_buttons(game_id, mode, &config.messages());
+    }
+
+    return Some(Board { game_id: game_id.clone(), message_id: None, reply, finished });
+}
+
+/// The replies to post for `responses`, leaving out what the board shows.
+fn map_responses(
+    user_id: &String,
+    responses: Vec<Result<GGMResponse, GameError>>,
+    config: &GuildConfig,
+    can_embed: bool
+) -> Vec<Reply> {
+    return responses
+        .into_iter()
+        .filter(|response| !shown_on_board(response))
+        .filter_map(|response| {
+            match response {
+                Ok(response) => map_response(user_id, response, config, can_embed),
+                Err(e) => Some(Reply::text(map_game_error_to_discord_message(user_id, e, config))),
+            }
+        })
+        .collect();
+}
+
+/// Game announcements and payment claims get buttons so players don't have to type the next command.
+/// Game announcements, boards and results are posted as embeds when `can_embed`.
+fn map_response(user_id: &String, response: GGMResponse, config: &GuildConfig, can_embed: bool) -> Option<Reply> {
+    let components = match &response {
+        GGMResponse::ShowJoinInfo(game_id, _, mode) => game_buttons(game_id, mode, &config.messages()),
+        GGMResponse::PaymentPending(payment) => confirm_payment_buttons(&payment.payer, &config.messages()),
+        _ => vec![],
+    };
+
+    let embed = if can_embed { build_embed(&response, config) } else { None };
+
+    map_ggm_response_to_discord_message(user_id, response, config).map(|content| {
+        match embed {
+            Some(embed) =>
+                Reply {
+                    content: String::new(),
+                    components,
+                    embeds: vec![embed.description(content).build()],
+                },
+            None => Reply { content, components, embeds: vec![] },
+        }
+    })
+}
+
+/// Interactions must be answered exactly once; extra messages are sent as followups.
+async fn respond_to_interaction(
+    sink: &impl MessageSink,
+    interaction: &Interaction,
+    replies: Vec<Reply>
+) {
+    let mut replies = replies.into_iter();
+
+    let data = match replies.next() {
+        Some(reply) =>
+            InteractionResponseDataBuilder::new()
+                .content(reply.content)
+                .components(reply.components)
+                .embeds(reply.embeds)
+                .build(),
+        // Commands like `join` have nothing to say, but the interaction still needs an answer.
+        None =>
+            InteractionResponseDataBuilder::new()
+                .content(":white_check_mark:")
+                .flags(MessageFlags::EPHEMERAL)
+                .build(),
+    };
+
+    let response = InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(data),
+    };
+
+    if let Err(why) = sink.respond(interaction, &response).await {
+        error!(?why, "gamble: failed to respond to interaction");
+        return;
+    }
+
+    for reply in replies {
+        if let Err(why) = sink.follow_up(interaction, &reply).await {
+            error!(?why, "gamble: failed to send followup");
         }
     }
 }
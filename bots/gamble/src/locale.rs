@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use serde::{ Deserialize, Serialize };
+
+/// A language the bot speaks, picked with `g!config set language <LANGUAGE>` or taken from the
+/// Discord client of whoever used a slash command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+    De,
+}
+
+pub const LOCALES: &[Locale] = &[Locale::En, Locale::Fr, Locale::De];
+
+impl Locale {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::De => "de",
+        }
+    }
+
+    /// Discord locales are either a language (`fr`) or a language and a region (`en-US`).
+    pub fn from_discord(locale: &str) -> Option<Self> {
+        locale.split('-').next()?.parse().ok()
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        LOCALES.iter()
+            .find(|locale| locale.name() == name)
+            .copied()
+            .ok_or(())
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{ catalog, locale::Locale, tone::Tone };
+
+/// Declares `MessageKey` along with `MessageKey::ALL`, so the list of keys can't fall out of sync.
+macro_rules! message_keys {
+    ($($key:ident,)*) => {
+        /// Every message the bot can post, named after the `GameError` or `GGMResponse` variant it
+        /// renders. The remaining keys are pieces of those messages, like the lines of a list.
+        ///
+        /// Templates refer to their arguments as `{name}`, e.g. `{player}`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum MessageKey {
+            $($key,)*
+        }
+
+        impl MessageKey {
+            pub const ALL: &[MessageKey] = &[$(MessageKey::$key,)*];
+        }
+    };
+}
+
+message_keys! {
+    // Errors.
+    CannotInitGame,
+    SeveralGames,
+    NotEnoughPlayers,
+    GoldAmountTooSmall,
+    PlayerCannotRollOnAnInexistentGame,
+    PlayerCannotRequestInfoOnInexistentGame,
+    PlayerCannotPlayOnInexistentGame,
+    PlayerCannotJoinAnInexistentGame,
+    PlayerCannotJoinOngoingGame,
+    PlayerAlreadyPartOfGame,
+    PlayerCannotRoll,
+    PlayerAlreadyRolled,
+    PlayersMatchedLowestRoll,
+    PlayersMatchedHighestRoll,
+    NoWinnersFound,
+    UnknownCommand,
+    MissingPlayerMention,
+    LedgerOnlyAvailableInGuilds,
+    LedgerUnavailable,
+    CannotPayYourself,
+    InvalidPaymentAmount,
+    NothingOwed,
+    PaymentExceedsDebt,
+    NoPendingPayments,
+    UnknownPeriod,
+    UnknownGameMode,
+    PlayerNotPartOfGame,
+    NotYourTurn,
+    PlayerCannotLeaveAnInexistentGame,
+    PlayerCannotCancelAnInexistentGame,
+    PlayerCannotKickFromAnInexistentGame,
+    OnlyCreatorOrModerator,
+    CannotKickFromOngoingGame,
+    KickedPlayerNotPartOfGame,
+    WinnerCannotLeave,
+    PlayerCannotActOnAnInexistentGame,
+    NoHandToPlay,
+    CannotDouble,
+    CannotSplit,
+    HouseCannotLeave,
+    GameAlreadyOver,
+    MissingGameId,
+    UnknownGameId,
+    ConfigOnlyAvailableInGuilds,
+    OnlyGuildManagers,
+    UnknownConfigKey,
+    InvalidConfigValue,
+    ConfigUnavailable,
+
+    // Responses.
+    Started,
+    ShowJoinInfo,
+    Done,
+    PayoutLine,
+    NoPayouts,
+    Verified,
+    VerifiedRoll,
+    VerifiedFair,
+    VerifiedUnfair,
+    TieBreak,
+    Dealer,
+    PlayerRolled,
+    OwedTo,
+    OwedToNobody,
+    OwedBy,
+    OwedByNobody,
+    DebtLine,
+    Ledger,
+    LedgerEmpty,
+    OutcomeLine,
+    PaymentPending,
+    PaymentsConfirmed,
+    Settlement,
+    SettlementEmpty,
+    Stats,
+    StatsNoGames,
+    Leaderboard,
+    LeaderboardEmpty,
+    LeaderboardRow,
+    PeriodWeek,
+    PeriodMonth,
+    PeriodAll,
+    ShowModes,
+    ModeLine,
+    ShowModeHelp,
+    IdleCancelled,
+    AutoRolled,
+    AutoPlayed,
+    Forfeited,
+    Left,
+    LeftAndForfeited,
+    Kicked,
+    Cancelled,
+    ShowConfig,
+    ConfigLine,
+    ConfigValue,
+    ConfigUpdated,
+    Help,
+
+    // Game modes.
+    ClassicSummary,
+    ClassicHelp,
+    DeathRollSummary,
+    DeathRollHelp,
+    BlackjackSummary,
+    BlackjackHelp,
+
+    // `g!info`.
+    InfoPlayer,
+    InfoNoPlayers,
+    InfoClassicWaiting,
+    InfoClassicOngoing,
+    InfoPendingRoll,
+    InfoDeathRollWaiting,
+    InfoDeathRollOngoing,
+    InfoBlackjackWaiting,
+    InfoBlackjackTable,
+    InfoDealerHand,
+    InfoHand,
+    InfoDone,
+    InfoCancelled,
+    InfoRolls,
+    InfoRollLine,
+    ThreadName,
+
+    // Embed fields.
+    FieldPlayers,
+    FieldRolls,
+    FieldWager,
+    FieldWinner,
+    FieldLoser,
+    FieldDifference,
+
+    // Buttons.
+    ButtonJoin,
+    ButtonStart,
+    ButtonRoll,
+    ButtonHit,
+    ButtonStand,
+    ButtonDouble,
+    ButtonSplit,
+    ButtonInfo,
+    ButtonConfirm,
+}
+
+/// The catalog a message is rendered from: the language it's written in and the voice it's
+/// written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Messages {
+    pub locale: Locale,
+    pub tone: Tone,
+}
+
+impl Messages {
+    pub fn template(&self, key: MessageKey) -> &'static str {
+        return catalog::template(self.locale, self.tone, key);
+    }
+
+    pub fn get(&self, key: MessageKey, args: &[(&str, &str)]) -> String {
+        return render(self.template(key), args);
+    }
+}
+
+/// Fills the `{name}` placeholders of a template in a single pass, so arguments that look like
+/// placeholders themselves are left alone. Unknown placeholders are kept as they are.
+pub fn render(template: &str, args: &[(&str, &str)]) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        message.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest
+            .find('}')
+            .and_then(|end| {
+                args.iter()
+                    .find(|(name, _)| *name == &rest[1..end])
+                    .map(|(_, value)| (end, value))
+            });
+
+        match value {
+            Some((end, value)) => {
+                message.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                message.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    message.push_str(rest);
+
+    return message;
+}
@@ -0,0 +1,81 @@
+use std::{ env, error::Error, path::PathBuf, str::FromStr, time::Duration };
+
+/// What happens to players who take too long to roll in an ongoing game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdlePolicy {
+    /// The bot rolls for them, or stands their hands in blackjack.
+    AutoRoll,
+    /// Their roll counts as the worst possible roll, and their blackjack hands are lost.
+    Forfeit,
+}
+
+impl FromStr for IdlePolicy {
+    type Err = String;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        match policy {
+            "autoroll" => Ok(IdlePolicy::AutoRoll),
+            "forfeit" => Ok(IdlePolicy::Forfeit),
+            _ => Err(format!("unknown idle policy `{}`, expected `autoroll` or `forfeit`", policy)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IdleTimeouts {
+    /// How long a game can wait for players to join and start before it is cancelled.
+    pub initiated: Duration,
+    /// How long an ongoing game waits on a player's roll before applying `policy`.
+    pub ongoing: Duration,
+    pub policy: IdlePolicy,
+}
+
+impl Default for IdleTimeouts {
+    fn default() -> Self {
+        Self {
+            initiated: Duration::from_secs(10 * 60),
+            ongoing: Duration::from_secs(5 * 60),
+            policy: IdlePolicy::AutoRoll,
+        }
+    }
+}
+
+pub struct GambleOptions {
+    pub database_path: PathBuf,
+    pub idle_timeouts: IdleTimeouts,
+}
+
+fn minutes_from_env(name: &str) -> Result<Option<Duration>, Box<dyn Error + Send + Sync>> {
+    match env::var(name) {
+        Ok(minutes) => {
+            let minutes = minutes.parse::<u64>().map_err(|_| format!("{} must be a number of minutes", name))?;
+            Ok(Some(Duration::from_secs(minutes * 60)))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+impl GambleOptions {
+    /// Reads `GAMBLE_DATABASE_PATH`, `GAMBLE_IDLE_CANCEL_MINUTES`, `GAMBLE_IDLE_ROLL_MINUTES`
+    /// and `GAMBLE_IDLE_POLICY`, falling back to defaults for anything unset.
+    pub fn from_env() -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let mut idle_timeouts = IdleTimeouts::default();
+
+        if let Some(initiated) = minutes_from_env("GAMBLE_IDLE_CANCEL_MINUTES")? {
+            idle_timeouts.initiated = initiated;
+        }
+
+        if let Some(ongoing) = minutes_from_env("GAMBLE_IDLE_ROLL_MINUTES")? {
+            idle_timeouts.ongoing = ongoing;
+        }
+
+        if let Ok(policy) = env::var("GAMBLE_IDLE_POLICY") {
+            idle_timeouts.policy = policy.parse()?;
+        }
+
+        Ok(Self {
+            database_path: env::var("GAMBLE_DATABASE_PATH").unwrap_or_else(|_| "gamble.db".into()).into(),
+            idle_timeouts,
+        })
+    }
+}
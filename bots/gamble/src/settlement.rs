@@ -0,0 +1,123 @@
+use std::{ cmp::Reverse, collections::{ BinaryHeap, HashMap } };
+
+use crate::{ ledger::Debt, types::{ PlayerId, RollValue } };
+
+/// Collapses pairwise debts into as few transfers as possible.
+///
+/// Only each player's net balance matters: if A owes B 100 and B owes C 100, B is square and
+/// A pays C directly. Finding the true minimum is NP-hard, so after pairing up players whose
+/// balances cancel out exactly, the largest debtor repeatedly pays the largest creditor.
+/// This never needs more than one transfer fewer than there are players with a balance.
+pub fn simplify_debts(debts: &[Debt]) -> Vec<Debt> {
+    let mut balances: HashMap<&PlayerId, i128> = HashMap::new();
+
+    for debt in debts {
+        *balances.entry(&debt.creditor).or_default() += debt.amount as i128;
+        *balances.entry(&debt.debtor).or_default() -= debt.amount as i128;
+    }
+
+    // Ties are broken by player id so the same ledger always yields the same plan.
+    let mut debtors: Vec<(RollValue, &PlayerId)> = vec![];
+    let mut creditors: Vec<(RollValue, &PlayerId)> = vec![];
+
+    for (player_id, balance) in balances {
+        if balance < 0 {
+            debtors.push((balance.unsigned_abs() as RollValue, player_id));
+        } else if balance > 0 {
+            creditors.push((balance as RollValue, player_id));
+        }
+    }
+
+    debtors.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+    creditors.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+
+    let mut transfers = vec![];
+
+    // Exact matches settle two players with a single transfer.
+    debtors.retain(|(amount, debtor)| {
+        match creditors.iter().position(|(owed, _)| owed == amount) {
+            Some(index) => {
+                let (_, creditor) = creditors.remove(index);
+                transfers.push(Debt {
+                    debtor: (*debtor).clone(),
+                    creditor: creditor.clone(),
+                    amount: *amount,
+                });
+                false
+            }
+            None => true,
+        }
+    });
+
+    let mut debtors = debtors
+        .into_iter()
+        .map(|(amount, player_id)| (amount, Reverse(player_id)))
+        .collect::<BinaryHeap<_>>();
+    let mut creditors = creditors
+        .into_iter()
+        .map(|(amount, player_id)| (amount, Reverse(player_id)))
+        .collect::<BinaryHeap<_>>();
+
+    while let (Some((owes, Reverse(debtor))), Some((owed, Reverse(creditor)))) = (
+        debtors.pop(),
+        creditors.pop(),
+    ) {
+        let amount = owes.min(owed);
+
+        transfers.push(Debt {
+            debtor: debtor.clone(),
+            creditor: creditor.clone(),
+            amount,
+        });
+
+        if owes > amount {
+            debtors.push((owes - amount, Reverse(debtor)));
+        }
+
+        if owed > amount {
+            creditors.push((owed - amount, Reverse(creditor)));
+        }
+    }
+
+    transfers.sort_by_key(|debt| Reverse(debt.amount));
+    transfers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_chain_of_debts_collapses_into_a_single_transfer() {
+        let debts = [Debt::new("a", "b", 100), Debt::new("b", "c", 100)];
+
+        assert_eq!(simplify_debts(&debts), vec![Debt::new("a", "c", 100)]);
+    }
+
+    #[test]
+    fn players_whose_balances_cancel_out_pay_each_other() {
+        // a is down 40 and b 10, while c is up 40 and d 10.
+        let debts = [Debt::new("a", "c", 30), Debt::new("a", "d", 10), Debt::new("b", "c", 10)];
+
+        assert_eq!(simplify_debts(&debts), vec![Debt::new("a", "c", 40), Debt::new("b", "d", 10)]);
+    }
+
+    #[test]
+    fn the_same_debts_always_settle_the_same_way() {
+        // Every balance ties with another one, so only player ids can tell them apart.
+        let debts = vec![Debt::new("a", "c", 50), Debt::new("b", "d", 50), Debt::new("e", "f", 20), Debt::new("g", "f", 20)];
+        let expected = simplify_debts(&debts);
+
+        for _ in 0..10 {
+            assert_eq!(simplify_debts(&debts), expected);
+            assert_eq!(simplify_debts(&debts.iter().rev().cloned().collect::<Vec<Debt>>()), expected);
+        }
+    }
+
+    #[test]
+    fn players_who_are_square_are_left_out() {
+        let debts = [Debt::new("a", "b", 100), Debt::new("b", "a", 100), Debt::new("c", "d", 20), Debt::new("e", "c", 20)];
+
+        assert_eq!(simplify_debts(&debts), vec![Debt::new("e", "d", 20)]);
+    }
+}
@@ -0,0 +1,38 @@
+//! Fixtures shared by the unit tests.
+
+use std::sync::Arc;
+
+use crate::{
+    game_store::GameStore,
+    gamble_game_manager::{ CommandContext, GambleGameManager },
+    guild_config::GuildConfigStore,
+    ledger::Ledger,
+};
+
+pub const GUILD_ID: &str = "1";
+pub const CHANNEL_ID: &str = "10";
+pub const ALICE: &str = "101";
+pub const BOB: &str = "102";
+pub const CAROL: &str = "103";
+
+/// A manager whose ledger and stores only live in memory.
+pub fn manager() -> GambleGameManager {
+    GambleGameManager::new(
+        Arc::new(Ledger::open_in_memory().unwrap()),
+        Arc::new(GameStore::open_in_memory().unwrap()),
+        Arc::new(GuildConfigStore::open_in_memory().unwrap())
+    )
+}
+
+/// A command sent by `user_id` in the test channel.
+pub fn ctx(user_id: &str) -> CommandContext {
+    CommandContext {
+        guild_id: Some(GUILD_ID.into()),
+        channel_id: CHANNEL_ID.into(),
+        user_id: user_id.into(),
+        is_moderator: false,
+        can_manage_guild: false,
+        locale: None,
+        can_embed: false,
+    }
+}
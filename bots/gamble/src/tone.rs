@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use serde::{ Deserialize, Serialize };
+
+/// The voice the bot speaks with in a guild, picked with `g!config set tone <TONE>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tone {
+    /// The original voice, insults included.
+    #[default]
+    Savage,
+    Neutral,
+    Wholesome,
+}
+
+pub const TONES: &[Tone] = &[Tone::Savage, Tone::Neutral, Tone::Wholesome];
+
+impl Tone {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tone::Savage => "savage",
+            Tone::Neutral => "neutral",
+            Tone::Wholesome => "wholesome",
+        }
+    }
+}
+
+impl FromStr for Tone {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        TONES.iter()
+            .find(|tone| tone.name() == name)
+            .copied()
+            .ok_or(())
+    }
+}
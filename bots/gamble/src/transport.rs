@@ -0,0 +1,149 @@
+use std::{ error::Error, future::Future };
+
+use tracing::warn;
+use twilight_gateway::{ Event, EventTypeFlags, Shard, StreamExt };
+use twilight_http::Client as Http;
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::message::{ AllowedMentions, MentionType },
+    http::interaction::InteractionResponse,
+    id::{ Id, marker::{ ChannelMarker, MessageMarker } },
+};
+
+use crate::Reply;
+
+pub type TransportResult<T = ()> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Where the bot gets its gateway events from: a live `Shard`, or a scripted fake in tests.
+pub trait EventSource {
+    /// The next event, or `None` once the source is exhausted and needs to be recreated.
+    fn next_event(&mut self) -> impl Future<Output = Option<Event>> + Send;
+}
+
+/// Where the bot posts its replies: the Discord HTTP API, or a recorder in tests.
+pub trait MessageSink: Send + Sync {
+    /// Posts `reply`, returning the id of the new message.
+    fn send_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        reply: &Reply
+    ) -> impl Future<Output = TransportResult<Id<MessageMarker>>> + Send;
+
+    /// Replaces the content, components and embeds of a message the bot posted.
+    fn edit_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        reply: &Reply
+    ) -> impl Future<Output = TransportResult> + Send;
+
+    /// Opens a public thread off a message, returning the id of the thread.
+    fn create_thread(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        name: &str
+    ) -> impl Future<Output = TransportResult<Id<ChannelMarker>>> + Send;
+
+    fn respond(
+        &self,
+        interaction: &Interaction,
+        response: &InteractionResponse
+    ) -> impl Future<Output = TransportResult> + Send;
+
+    fn follow_up(
+        &self,
+        interaction: &Interaction,
+        reply: &Reply
+    ) -> impl Future<Output = TransportResult> + Send;
+}
+
+impl EventSource for Shard {
+    async fn next_event(&mut self) -> Option<Event> {
+        loop {
+            match StreamExt::next_event(self, EventTypeFlags::all()).await? {
+                Ok(event) => {
+                    return Some(event);
+                }
+                Err(err) => {
+                    warn!(?err, "gamble: error receiving event; continuing");
+                }
+            }
+        }
+    }
+}
+
+/// Replies echo what players typed, so they may only ever ping users, never `@everyone`, `@here`
+/// or a role.
+fn allowed_mentions() -> AllowedMentions {
+    AllowedMentions {
+        parse: vec![MentionType::Users],
+        ..Default::default()
+    }
+}
+
+impl MessageSink for Http {
+    async fn send_message(&self, channel_id: Id<ChannelMarker>, reply: &Reply) -> TransportResult<Id<MessageMarker>> {
+        let message = self.create_message(channel_id)
+            .allowed_mentions(Some(&allowed_mentions()))
+            .content(&reply.content)
+            .components(&reply.components)
+            .embeds(&reply.embeds).await?
+            .model().await?;
+
+        Ok(message.id)
+    }
+
+    async fn edit_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        reply: &Reply
+    ) -> TransportResult {
+        self.update_message(channel_id, message_id)
+            .allowed_mentions(Some(&allowed_mentions()))
+            .content(Some(&reply.content))
+            .components(Some(&reply.components))
+            .embeds(Some(&reply.embeds)).await?;
+
+        Ok(())
+    }
+
+    async fn create_thread(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        name: &str
+    ) -> TransportResult<Id<ChannelMarker>> {
+        let thread = self.create_thread_from_message(channel_id, message_id, name).await?.model().await?;
+
+        Ok(thread.id)
+    }
+
+    async fn respond(&self, interaction: &Interaction, response: &InteractionResponse) -> TransportResult {
+        let mut response = response.clone();
+
+        if let Some(data) = &mut response.data {
+            data.allowed_mentions = Some(allowed_mentions());
+        }
+
+        self.interaction(interaction.application_id).create_response(
+            interaction.id,
+            &interaction.token,
+            &response
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn follow_up(&self, interaction: &Interaction, reply: &Reply) -> TransportResult {
+        self.interaction(interaction.application_id)
+            .create_followup(&interaction.token)
+            .allowed_mentions(Some(&allowed_mentions()))
+            .content(&reply.content)
+            .components(&reply.components)
+            .embeds(&reply.embeds).await?;
+
+        Ok(())
+    }
+}
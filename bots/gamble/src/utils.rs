@@ -1,96 +1,634 @@
-use crate::{ error::GameError, gamble_game_manager::GGMResponse, types::PlayerId };
+use std::time::{ SystemTime, UNIX_EPOCH };
 
-fn fmt_discord_name(player_id: &String) -> String {
+use crate::{
+    cards::{ Card, Hand, total },
+    error::GameError,
+    fairness::{ DEALER, TIE_BREAK, Verification },
+    gamble_game::{ GameSnapshot, GameStatus },
+    gamble_game_manager::GGMResponse,
+    game_mode::find_game_mode,
+    guild_config::{ CONFIG_KEYS, GuildConfig },
+    ledger::{ Debt, Outcome, Period },
+    messages::MessageKey,
+    types::PlayerId,
+};
+
+pub fn fmt_discord_name(player_id: &String) -> String {
     format!("<@{}>", player_id)
 }
 
-pub fn build_matched_roll_message(roll_type: String, player_ids: &Vec<PlayerId>) -> String {
+/// Renders a message from the catalog of the guild's language and tone.
+fn build_message(config: &GuildConfig, key: MessageKey, args: &[(&str, &str)]) -> String {
+    return config.messages().get(key, args);
+}
+
+fn build_matched_roll_message(config: &GuildConfig, key: MessageKey, player_ids: &[PlayerId]) -> String {
     player_ids
         .iter()
-        .map(|player_id| {
-            format!("<@{}>, you matched the {} roll. Please reroll.", player_id, roll_type)
+        .map(|player_id| build_message(config, key, &[("player", &fmt_discord_name(player_id))]))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn build_debts_message(config: &GuildConfig, debts: &[Debt]) -> String {
+    debts
+        .iter()
+        .map(|debt| {
+            build_message(
+                config,
+                MessageKey::DebtLine,
+                &[
+                    ("debtor", &fmt_discord_name(&debt.debtor)),
+                    ("creditor", &fmt_discord_name(&debt.creditor)),
+                    ("amount", &fmt_amount(debt.amount)),
+                ]
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn build_outcomes_message(config: &GuildConfig, outcomes: &[Outcome]) -> String {
+    outcomes
+        .iter()
+        .map(|outcome| {
+            build_message(
+                config,
+                MessageKey::OutcomeLine,
+                &[
+                    ("time", &format!("<t:{}:R>", outcome.timestamp)),
+                    ("creditor", &fmt_discord_name(&outcome.creditor)),
+                    ("amount", &fmt_amount(outcome.amount)),
+                    ("debtor", &fmt_discord_name(&outcome.debtor)),
+                ]
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// One `key` line per transfer of a game's settlement.
+fn build_settlement_message(config: &GuildConfig, key: MessageKey, settlement: &[Debt]) -> String {
+    if settlement.is_empty() {
+        return build_message(config, MessageKey::NoPayouts, &[]);
+    }
+
+    settlement
+        .iter()
+        .map(|debt| {
+            build_message(
+                config,
+                key,
+                &[
+                    ("debtor", &fmt_discord_name(&debt.debtor)),
+                    ("creditor", &fmt_discord_name(&debt.creditor)),
+                    ("amount", &fmt_amount(debt.amount)),
+                ]
+            )
         })
         .collect::<Vec<String>>()
         .join("\n")
 }
 
-pub fn map_game_error_to_discord_message(player_id: &PlayerId, error: GameError) -> String {
+fn fmt_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(Card::to_string)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn fmt_hand_status(hand: &Hand) -> &'static str {
+    if hand.forfeited {
+        return ":white_flag:";
+    }
+
+    if hand.is_bust() {
+        return ":boom:";
+    }
+
+    if hand.is_blackjack() {
+        return ":star2:";
+    }
+
+    if !hand.done {
+        return ":hourglass_flowing_sand:";
+    }
+
+    return "";
+}
+
+/// The dealer's hand and every player's hands of a card game, as a line and a list.
+fn build_table_message(config: &GuildConfig, snapshot: &GameSnapshot) -> (String, String) {
+    let mut dealer_cards = fmt_cards(&snapshot.dealer);
+
+    // The dealer's second card stays face down until the players are done.
+    if snapshot.status == GameStatus::ONGOING {
+        dealer_cards.push_str(" :grey_question:");
+    }
+
+    let dealer = build_message(
+        config,
+        MessageKey::InfoDealerHand,
+        &[("cards", &dealer_cards), ("total", &total(&snapshot.dealer).to_string())]
+    );
+
+    let hands = snapshot.hands
+        .iter()
+        .map(|hand| {
+            build_message(
+                config,
+                MessageKey::InfoHand,
+                &[
+                    ("player", &fmt_discord_name(&hand.player_id)),
+                    ("cards", &fmt_cards(&hand.cards)),
+                    ("total", &hand.total().to_string()),
+                    ("amount", &fmt_amount(hand.wager)),
+                    ("status", fmt_hand_status(hand)),
+                ]
+            )
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    return (dealer, hands);
+}
+
+/// Renders what `g!info` and the game's board show about a game.
+fn build_snapshot_message(config: &GuildConfig, snapshot: &GameSnapshot) -> String {
+    let fmt_players = |key: MessageKey, player_ids: &[PlayerId]| {
+        player_ids
+            .iter()
+            .map(|player_id| build_message(config, key, &[("player", &fmt_discord_name(player_id))]))
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    let Some(mode) = find_game_mode(snapshot.mode) else {
+        return String::new();
+    };
+
+    // Modes played against the house have the creator bank the game.
+    let house = snapshot.players.first().map(fmt_discord_name).unwrap_or_default();
+
+    let table = || {
+        let current = snapshot.pending.first().map(fmt_discord_name).unwrap_or_default();
+        let (dealer, hands) = build_table_message(config, snapshot);
+
+        build_message(
+            config,
+            mode.info_ongoing,
+            &[
+                ("players", &fmt_players(MessageKey::InfoPendingRoll, &snapshot.pending)),
+                ("player", &current),
+                ("max", &snapshot.max_roll.map(fmt_amount).unwrap_or_default()),
+                ("amount", &fmt_amount(snapshot.wager)),
+                ("house", &house),
+                ("dealer", &dealer),
+                ("hands", &hands),
+            ]
+        )
+    };
+
+    let info = match snapshot.status {
+        GameStatus::INITIATED => {
+            let players = if snapshot.players.is_empty() {
+                build_message(config, MessageKey::InfoNoPlayers, &[])
+            } else {
+                fmt_players(MessageKey::InfoPlayer, &snapshot.players)
+            };
+
+            build_message(
+                config,
+                mode.info_waiting,
+                &[("amount", &fmt_amount(snapshot.wager)), ("players", &players), ("house", &house)]
+            )
+        }
+        GameStatus::ONGOING => table(),
+        // Card games keep showing their table once it's over, with the dealer's hand revealed.
+        GameStatus::DONE if !snapshot.hands.is_empty() => table(),
+        GameStatus::DONE => build_settlement_message(config, MessageKey::InfoDone, &snapshot.settlement),
+        GameStatus::CANCELLED => {
+            build_message(config, MessageKey::InfoCancelled, &[])
+        }
+    };
+
+    if snapshot.rolls.is_empty() {
+        return info;
+    }
+
+    let rolls = snapshot.rolls
+        .iter()
+        .map(|(player_id, value)| {
+            build_message(
+                config,
+                MessageKey::InfoRollLine,
+                &[("player", &fmt_discord_name(player_id)), ("roll", &fmt_amount(*value))]
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    return build_message(config, MessageKey::InfoRolls, &[("info", &info), ("rolls", &rolls)]);
+}
+
+fn build_verification_message(config: &GuildConfig, verification: &Verification) -> String {
+    let game = &verification.game;
+    let verdict = if verification.is_fair() { MessageKey::VerifiedFair } else { MessageKey::VerifiedUnfair };
+    let hash_check = if verification.seed_matches_hash { ":white_check_mark:" } else { ":x:" };
+
+    let rolls = verification.rolls
+        .iter()
+        .map(|(roll, value)| {
+            let player = match roll.player_id.as_str() {
+                TIE_BREAK => build_message(config, MessageKey::TieBreak, &[]),
+                DEALER => build_message(config, MessageKey::Dealer, &[]),
+                player_id => fmt_discord_name(&player_id.to_string()),
+            };
+            let check = if roll.value == *value { ":white_check_mark:" } else { ":x:" };
+
+            build_message(
+                config,
+                MessageKey::VerifiedRoll,
+                &[
+                    ("nonce", &roll.nonce.to_string()),
+                    ("player", &player),
+                    ("value", &fmt_amount(roll.value)),
+                    ("min", &fmt_amount(roll.min)),
+                    ("max", &fmt_amount(roll.max)),
+                    ("check", check),
+                ]
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    build_message(
+        config,
+        MessageKey::Verified,
+        &[
+            ("game_id", &game.game_id),
+            ("seed", &game.server_seed),
+            ("hash", &game.seed_hash),
+            ("hash_check", hash_check),
+            ("rolls", &rolls),
+            ("verdict", &build_message(config, verdict, &[])),
+        ]
+    )
+}
+
+/// Every command hint uses the guild's prefix.
+pub fn map_game_error_to_discord_message(
+    player_id: &PlayerId,
+    error: GameError,
+    config: &GuildConfig
+) -> String {
+    return config.with_prefix(build_error_message(player_id, error, config));
+}
+
+fn build_error_message(player_id: &PlayerId, error: GameError, config: &GuildConfig) -> String {
     let player = fmt_discord_name(player_id);
+    let message = |key: MessageKey| build_message(config, key, &[("player", &player)]);
 
     match error {
         GameError::PlayerCannotRollOnAnInexistentGame =>
-            format!("{}, __you absolute dipshit__, what are you rolling for? (i.e.: `g!create`)", player),
+            message(MessageKey::PlayerCannotRollOnAnInexistentGame),
         GameError::PlayerCannotRequestInfoOnInexistentGame =>
-            format!("{}, __you're a lost cause__, you gotta create a game first before requesting info. (i.e.: `g!create`)", player),
-        GameError::NoWinnersFound =>
-            ":thinking: wtf, no winners were found.. but the game is done? Holy fuck.".into(),
-        GameError::PlayerCannotPlayOnInexistentGame =>
-            format!("{}, __you absolute mongoloid__, you gotta create a game first before playing. (i.e.: `g!create`)", player),
-        GameError::PlayerCannotJoinAnInexistentGame =>
-            format!("{}, __you absolute donut__, you gotta create a game first before joining one. (i.e.: `g!create`)", player),
-        GameError::CannotInitGame =>
-            format!(":man_facepalming: {}, bro there is already an ongoing game. (i.e.: `g!info`)", player),
+            message(MessageKey::PlayerCannotRequestInfoOnInexistentGame),
+        GameError::NoWinnersFound => message(MessageKey::NoWinnersFound),
+        GameError::PlayerCannotPlayOnInexistentGame => message(MessageKey::PlayerCannotPlayOnInexistentGame),
+        GameError::PlayerCannotJoinAnInexistentGame => message(MessageKey::PlayerCannotJoinAnInexistentGame),
+        GameError::CannotInitGame => message(MessageKey::CannotInitGame),
         GameError::NotEnoughPlayers(minimum_number_of_players) =>
-            format!(
-                ":upside_down: {}, there needs to be at least {} players.",
-                player,
-                minimum_number_of_players
+            build_message(
+                config,
+                MessageKey::NotEnoughPlayers,
+                &[("player", &player), ("count", &minimum_number_of_players.to_string())]
             ),
         GameError::GoldAmountTooSmall(minimum_gold_amount) =>
-            format!(
-                ":pinched_fingers: {}, what are you broke? Gamble at least {} gold.",
-                player,
-                fmt_amount(minimum_gold_amount)
+            build_message(
+                config,
+                MessageKey::GoldAmountTooSmall,
+                &[("player", &player), ("amount", &fmt_amount(minimum_gold_amount))]
             ),
-        GameError::PlayerCannotJoinOngoingGame =>
-            format!(":weary: {}, let the game end first and then join the next one.", player),
-        GameError::PlayerAlreadyPartOfGame =>
-            format!(":zany_face: {}, you're already part of the game dipshit.", player),
-        GameError::PlayerCannotRoll =>
-            format!(":unamused: {}, it's not the right time to roll.", player),
-        GameError::PlayerAlreadyRolled =>
-            format!(":expresionless: {}, you think rolling twice is going to help your cause?", player),
+        GameError::PlayerCannotJoinOngoingGame => message(MessageKey::PlayerCannotJoinOngoingGame),
+        GameError::PlayerAlreadyPartOfGame => message(MessageKey::PlayerAlreadyPartOfGame),
+        GameError::PlayerCannotRoll => message(MessageKey::PlayerCannotRoll),
+        GameError::PlayerAlreadyRolled => message(MessageKey::PlayerAlreadyRolled),
         GameError::PlayersMatchedLowestRoll(items) =>
-            build_matched_roll_message("lowest".into(), &items),
+            build_matched_roll_message(config, MessageKey::PlayersMatchedLowestRoll, &items),
         GameError::PlayersMatchedHighestRoll(items) =>
-            build_matched_roll_message("highest".into(), &items),
-        GameError::GameAlreadyExists =>
-            format!("{}, a game already exists in this channel.. try finishing it first?", player),
-        GameError::UnknownCommand =>
-            format!("{}, is this your first time? (i.e.: `g!help`)", player),
+            build_matched_roll_message(config, MessageKey::PlayersMatchedHighestRoll, &items),
+        GameError::SeveralGames(game_ids) => {
+            let games = game_ids
+                .iter()
+                .map(|game_id| format!("`{}`", game_id))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            build_message(config, MessageKey::SeveralGames, &[("player", &player), ("games", &games)])
+        }
+        GameError::UnknownCommand => message(MessageKey::UnknownCommand),
+        GameError::MissingPlayerMention => message(MessageKey::MissingPlayerMention),
+        GameError::LedgerOnlyAvailableInGuilds => message(MessageKey::LedgerOnlyAvailableInGuilds),
+        GameError::LedgerUnavailable => message(MessageKey::LedgerUnavailable),
+        GameError::CannotPayYourself => message(MessageKey::CannotPayYourself),
+        GameError::InvalidPaymentAmount => message(MessageKey::InvalidPaymentAmount),
+        GameError::NothingOwed(payee) =>
+            build_message(
+                config,
+                MessageKey::NothingOwed,
+                &[("player", &player), ("payee", &fmt_discord_name(&payee))]
+            ),
+        GameError::PaymentExceedsDebt(remaining) =>
+            build_message(
+                config,
+                MessageKey::PaymentExceedsDebt,
+                &[("player", &player), ("amount", &fmt_amount(remaining))]
+            ),
+        GameError::NoPendingPayments => message(MessageKey::NoPendingPayments),
+        GameError::UnknownGameMode => message(MessageKey::UnknownGameMode),
+        GameError::PlayerNotPartOfGame => message(MessageKey::PlayerNotPartOfGame),
+        GameError::NotYourTurn(current_player) =>
+            build_message(
+                config,
+                MessageKey::NotYourTurn,
+                &[("player", &player), ("current", &fmt_discord_name(&current_player))]
+            ),
+        GameError::PlayerCannotLeaveAnInexistentGame => message(MessageKey::PlayerCannotLeaveAnInexistentGame),
+        GameError::PlayerCannotCancelAnInexistentGame => message(MessageKey::PlayerCannotCancelAnInexistentGame),
+        GameError::PlayerCannotKickFromAnInexistentGame =>
+            message(MessageKey::PlayerCannotKickFromAnInexistentGame),
+        GameError::OnlyCreatorOrModerator => message(MessageKey::OnlyCreatorOrModerator),
+        GameError::CannotKickFromOngoingGame => message(MessageKey::CannotKickFromOngoingGame),
+        GameError::KickedPlayerNotPartOfGame(kicked) =>
+            build_message(
+                config,
+                MessageKey::KickedPlayerNotPartOfGame,
+                &[("player", &player), ("kicked", &fmt_discord_name(&kicked))]
+            ),
+        GameError::WinnerCannotLeave => message(MessageKey::WinnerCannotLeave),
+        GameError::PlayerCannotActOnAnInexistentGame => message(MessageKey::PlayerCannotActOnAnInexistentGame),
+        GameError::NoHandToPlay => message(MessageKey::NoHandToPlay),
+        GameError::CannotDouble => message(MessageKey::CannotDouble),
+        GameError::CannotSplit => message(MessageKey::CannotSplit),
+        GameError::HouseCannotLeave => message(MessageKey::HouseCannotLeave),
+        GameError::GameAlreadyOver => message(MessageKey::GameAlreadyOver),
+        GameError::MissingGameId => message(MessageKey::MissingGameId),
+        GameError::UnknownGameId(game_id) =>
+            build_message(config, MessageKey::UnknownGameId, &[("player", &player), ("game_id", &game_id)]),
+        GameError::UnknownPeriod => message(MessageKey::UnknownPeriod),
+        GameError::ConfigOnlyAvailableInGuilds => message(MessageKey::ConfigOnlyAvailableInGuilds),
+        GameError::OnlyGuildManagers => message(MessageKey::OnlyGuildManagers),
+        GameError::UnknownConfigKey =>
+            build_message(
+                config,
+                MessageKey::UnknownConfigKey,
+                &[("player", &player), ("keys", &fmt_config_keys())]
+            ),
+        GameError::InvalidConfigValue(key) =>
+            build_message(config, MessageKey::InvalidConfigValue, &[("player", &player), ("key", &key)]),
+        GameError::ConfigUnavailable => message(MessageKey::ConfigUnavailable),
     }
 }
 
+/// Every command hint uses the guild's prefix, and game modes show the guild's limits.
 pub fn map_ggm_response_to_discord_message(
     player_id: &PlayerId,
-    response: GGMResponse
+    response: GGMResponse,
+    config: &GuildConfig
+) -> Option<String> {
+    return build_response_message(player_id, response, config).map(|message| {
+        config.with_prefix(message)
+    });
+}
+
+fn build_response_message(
+    player_id: &PlayerId,
+    response: GGMResponse,
+    config: &GuildConfig
 ) -> Option<String> {
     let player = fmt_discord_name(player_id);
+    let message = |key: MessageKey, args: &[(&str, &str)]| Some(build_message(config, key, args));
 
     match response {
-        GGMResponse::Started => Some("Game started :rocket:! Type `g!roll`!".into()),
+        GGMResponse::Started => message(MessageKey::Started, &[]),
         GGMResponse::Empty => None,
-        GGMResponse::ShowJoinInfo => Some("Type `g!join` to join the game!".into()),
-        GGMResponse::Done((winner_id, loser_id, amount)) => {
-            let winner = fmt_discord_name(&winner_id);
-            let loser = fmt_discord_name(&loser_id);
+        GGMResponse::ShowJoinInfo(game_id, seed_hash, _) =>
+            message(MessageKey::ShowJoinInfo, &[("game_id", &game_id), ("seed_hash", &seed_hash)]),
+        GGMResponse::Done(settlement, fair_game, _) =>
+            message(
+                MessageKey::Done,
+                &[
+                    ("payouts", &build_settlement_message(config, MessageKey::PayoutLine, &settlement)),
+                    ("seed", &fair_game.server_seed),
+                    ("game_id", &fair_game.game_id),
+                ]
+            ),
+        GGMResponse::Verified(verification) => Some(build_verification_message(config, &verification)),
+        GGMResponse::PlayerRolled(roll_value) =>
+            message(MessageKey::PlayerRolled, &[("player", &player), ("roll", &fmt_amount(roll_value))]),
+        GGMResponse::Played(snapshot) | GGMResponse::ShowGeneralInfo(snapshot) =>
+            Some(build_snapshot_message(config, &snapshot)),
+        GGMResponse::OwedTo(creditor, debts) => {
+            let creditor = fmt_discord_name(&creditor);
+
+            if debts.is_empty() {
+                return message(MessageKey::OwedToNobody, &[("creditor", &creditor)]);
+            }
+
+            message(
+                MessageKey::OwedTo,
+                &[("creditor", &creditor), ("debts", &build_debts_message(config, &debts))]
+            )
+        }
+        GGMResponse::OwedBy(debtor, debts) => {
+            let debtor = fmt_discord_name(&debtor);
+
+            if debts.is_empty() {
+                return message(MessageKey::OwedByNobody, &[("debtor", &debtor)]);
+            }
+
+            message(
+                MessageKey::OwedBy,
+                &[("debtor", &debtor), ("debts", &build_debts_message(config, &debts))]
+            )
+        }
+        GGMResponse::Ledger(outcomes) => {
+            if outcomes.is_empty() {
+                return message(MessageKey::LedgerEmpty, &[]);
+            }
 
+            message(MessageKey::Ledger, &[("outcomes", &build_outcomes_message(config, &outcomes))])
+        }
+        GGMResponse::PaymentPending(payment) =>
+            message(
+                MessageKey::PaymentPending,
+                &[
+                    ("payee", &fmt_discord_name(&payment.payee)),
+                    ("payer", &fmt_discord_name(&payment.payer)),
+                    ("amount", &fmt_amount(payment.amount)),
+                ]
+            ),
+        GGMResponse::PaymentsConfirmed(payments) =>
             Some(
-                format!(
-                    "__A winner has emerged!__\n:coin: {} owes {} **{}** gold.",
-                    loser,
-                    winner,
-                    fmt_amount(amount)
-                )
+                payments
+                    .iter()
+                    .map(|payment| {
+                        build_message(
+                            config,
+                            MessageKey::PaymentsConfirmed,
+                            &[
+                                ("payee", &fmt_discord_name(&payment.payee)),
+                                ("amount", &fmt_amount(payment.amount)),
+                                ("payer", &fmt_discord_name(&payment.payer)),
+                            ]
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ),
+        GGMResponse::Settlement(transfers) => {
+            if transfers.is_empty() {
+                return message(MessageKey::SettlementEmpty, &[]);
+            }
+
+            message(MessageKey::Settlement, &[("debts", &build_debts_message(config, &transfers))])
+        }
+        GGMResponse::Stats(stats) => {
+            let player = fmt_discord_name(&stats.player_id);
+
+            if stats.games == 0 {
+                return message(MessageKey::StatsNoGames, &[("player", &player)]);
+            }
+
+            message(
+                MessageKey::Stats,
+                &[
+                    ("player", &player),
+                    ("games", &stats.games.to_string()),
+                    ("wins", &stats.wins.to_string()),
+                    ("losses", &stats.losses.to_string()),
+                    ("net", &fmt_signed_amount(stats.net)),
+                    ("biggest_win", &fmt_amount(stats.biggest_win)),
+                    ("biggest_loss", &fmt_amount(stats.biggest_loss)),
+                ]
             )
         }
-        GGMResponse::PlayerRolled(roll_value) =>
-            Some(format!("{} rolled a {}!", player, fmt_amount(roll_value))),
-        GGMResponse::ShowGeneralInfo(info) => Some(info),
-        GGMResponse::Message(message) => Some(message),
+        GGMResponse::Leaderboard(period, stats) => {
+            let period = match period {
+                Period::Week => build_message(config, MessageKey::PeriodWeek, &[]),
+                Period::Month => build_message(config, MessageKey::PeriodMonth, &[]),
+                Period::All => build_message(config, MessageKey::PeriodAll, &[]),
+            };
+
+            if stats.is_empty() {
+                return message(MessageKey::LeaderboardEmpty, &[("period", &period)]);
+            }
+
+            let rows = stats
+                .iter()
+                .enumerate()
+                .map(|(rank, stats)| {
+                    build_message(
+                        config,
+                        MessageKey::LeaderboardRow,
+                        &[
+                            ("rank", &(rank + 1).to_string()),
+                            ("player", &fmt_discord_name(&stats.player_id)),
+                            ("net", &fmt_signed_amount(stats.net)),
+                            ("wins", &stats.wins.to_string()),
+                            ("losses", &stats.losses.to_string()),
+                        ]
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            message(MessageKey::Leaderboard, &[("period", &period), ("rows", &rows)])
+        }
+        GGMResponse::ShowModes(modes) => {
+            let modes = modes
+                .iter()
+                .map(|mode| {
+                    let rules = config.rules(mode);
+
+                    build_message(
+                        config,
+                        MessageKey::ModeLine,
+                        &[
+                            ("name", mode.name),
+                            ("summary", config.messages().template(mode.summary)),
+                            ("min_players", &rules.min_players.to_string()),
+                            ("min_wager", &fmt_amount(rules.min_wager)),
+                        ]
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            message(MessageKey::ShowModes, &[("modes", &modes)])
+        }
+        GGMResponse::ShowModeHelp(mode) => {
+            let rules = config.rules(mode);
+
+            message(
+                MessageKey::ShowModeHelp,
+                &[
+                    ("help", config.messages().template(mode.help)),
+                    ("min_players", &rules.min_players.to_string()),
+                    ("min_wager", &fmt_amount(rules.min_wager)),
+                    ("name", mode.name),
+                    ("wager", &rules.min_wager.to_string()),
+                ]
+            )
+        }
+        GGMResponse::IdleCancelled(_) => message(MessageKey::IdleCancelled, &[]),
+        GGMResponse::AutoRolled(player_id, roll_value) =>
+            message(
+                MessageKey::AutoRolled,
+                &[("player", &fmt_discord_name(&player_id)), ("roll", &fmt_amount(roll_value))]
+            ),
+        GGMResponse::AutoPlayed(player_id) =>
+            message(MessageKey::AutoPlayed, &[("player", &fmt_discord_name(&player_id))]),
+        GGMResponse::Forfeited(player_id) =>
+            message(MessageKey::Forfeited, &[("player", &fmt_discord_name(&player_id))]),
+        GGMResponse::Left(player_id) => message(MessageKey::Left, &[("player", &fmt_discord_name(&player_id))]),
+        GGMResponse::LeftAndForfeited(player_id) =>
+            message(MessageKey::LeftAndForfeited, &[("player", &fmt_discord_name(&player_id))]),
+        GGMResponse::Kicked(player_id) =>
+            message(MessageKey::Kicked, &[("player", &fmt_discord_name(&player_id))]),
+        GGMResponse::Cancelled(_) => message(MessageKey::Cancelled, &[]),
+        GGMResponse::ShowConfig(settings) => {
+            let settings = CONFIG_KEYS.iter()
+                .map(|key| {
+                    build_message(
+                        config,
+                        MessageKey::ConfigLine,
+                        &[("key", key), ("value", &settings.get(key).unwrap_or_default())]
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            message(MessageKey::ShowConfig, &[("settings", &settings)])
+        }
+        GGMResponse::ConfigValue(key, value) => message(MessageKey::ConfigValue, &[("key", &key), ("value", &value)]),
+        GGMResponse::ConfigUpdated(key, value) =>
+            message(MessageKey::ConfigUpdated, &[("player", &player), ("key", &key), ("value", &value)]),
+        GGMResponse::Help => message(MessageKey::Help, &[]),
     }
 }
 
+fn fmt_config_keys() -> String {
+    CONFIG_KEYS.iter()
+        .map(|key| format!("`{}`", key))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 pub fn fmt_amount(n: u64) -> String {
     let s = n.to_string();
     let mut result = String::new();
@@ -98,9 +636,34 @@ pub fn fmt_amount(n: u64) -> String {
     let len = chars.len();
     for (i, ch) in chars.iter().enumerate() {
         result.push(*ch);
-        if (len - i - 1) % 3 == 0 && i != len - 1 {
+        if (len - i - 1).is_multiple_of(3) && i != len - 1 {
             result.push(' ');
         }
     }
     result
 }
+
+pub fn fmt_signed_amount(n: i64) -> String {
+    let sign = if n < 0 { "-" } else { "+" };
+    format!("{}{}", sign, fmt_amount(n.unsigned_abs()))
+}
+
+/// Current unix timestamp in seconds.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Extracts the user id from a Discord mention such as `<@123>` or `<@!123>`.
+pub fn parse_mention(mention: &str) -> Option<PlayerId> {
+    let id = mention.strip_prefix("<@")?.strip_suffix('>')?;
+    let id = id.strip_prefix('!').unwrap_or(id);
+
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(id.to_string())
+}
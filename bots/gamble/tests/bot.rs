@@ -0,0 +1,766 @@
+//! Drives `GambleBot::serve` end to end with a scripted gateway and a sink that records every
+//! message the bot would have posted.
+
+use std::{ collections::VecDeque, sync::{ Arc, Mutex }, time::Duration };
+
+use gamble::{
+    Dispatcher,
+    EventSource,
+    GambleBot,
+    GambleGameManager,
+    GameStore,
+    GuildConfig,
+    GuildConfigStore,
+    IdleTimeouts,
+    Ledger,
+    MessageSink,
+    Reply,
+    SeedSource,
+    TransportResult,
+    derive_roll,
+    find_seed,
+};
+use serde_json::json;
+use twilight_gateway::Event;
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::{ Message, message::Embed },
+    gateway::payload::incoming::{ InteractionCreate, MessageCreate },
+    guild::Permissions,
+    http::interaction::InteractionResponse,
+    id::{ Id, marker::{ ChannelMarker, MessageMarker } },
+};
+
+const GUILD_ID: u64 = 1;
+const CHANNEL_ID: u64 = 10;
+/// Threads the bot opens are numbered from here.
+const THREAD_ID: u64 = 20;
+const ALICE: u64 = 101;
+const BOB: u64 = 102;
+const CAROL: u64 = 103;
+const WAGER: u64 = 500;
+
+struct ScriptedGateway {
+    events: VecDeque<Event>,
+    /// How long to wait before each event.
+    pause: Duration,
+}
+
+impl EventSource for ScriptedGateway {
+    async fn next_event(&mut self) -> Option<Event> {
+        // Lets channel tasks catch up, as a thread has to exist before anyone can post in it. Time is
+        // paused, so this only returns once every task is waiting again, database work included.
+        tokio::time::sleep(self.pause).await;
+        self.events.pop_front()
+    }
+}
+
+#[derive(Default)]
+struct RecordingSink {
+    sent: Mutex<Vec<String>>,
+    /// The channel each message in `sent` was posted in.
+    channels: Mutex<Vec<u64>>,
+    embeds: Mutex<Vec<Embed>>,
+    /// Every edit as the id of the edited message, its new content and whether it kept any buttons.
+    edits: Mutex<Vec<(u64, String, bool)>>,
+    /// The name of every thread the bot opened.
+    threads: Mutex<Vec<String>>,
+    /// Panics instead of sending or editing in the next message starting with this, taking the channel's
+    /// task down.
+    panic_on: Mutex<Option<&'static str>>,
+}
+
+impl RecordingSink {
+    fn panic_if_asked(&self, content: &str) {
+        if self.panic_on.lock().unwrap().take_if(|prefix| content.starts_with(*prefix)).is_some() {
+            panic!("failed to send {:?}", content);
+        }
+    }
+
+    fn record(&self, channel_id: u64, content: String, embeds: &[Embed]) {
+        self.sent.lock().unwrap().push(content);
+        self.channels.lock().unwrap().push(channel_id);
+        self.embeds.lock().unwrap().extend_from_slice(embeds);
+    }
+}
+
+impl MessageSink for RecordingSink {
+    async fn send_message(&self, channel_id: Id<ChannelMarker>, reply: &Reply) -> TransportResult<Id<MessageMarker>> {
+        self.panic_if_asked(&reply.content);
+        self.record(channel_id.get(), reply.content.clone(), &reply.embeds);
+        // Messages are numbered in the order they were sent, starting at 1.
+        Ok(Id::new(self.sent.lock().unwrap().len() as u64))
+    }
+
+    async fn edit_message(
+        &self,
+        _: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        reply: &Reply
+    ) -> TransportResult {
+        self.panic_if_asked(&reply.content);
+        self.edits.lock().unwrap().push((message_id.get(), reply.content.clone(), !reply.components.is_empty()));
+        Ok(())
+    }
+
+    async fn respond(&self, _: &Interaction, response: &InteractionResponse) -> TransportResult {
+        let data = response.data.as_ref().unwrap();
+        self.record(CHANNEL_ID, data.content.clone().unwrap_or_default(), data.embeds.as_deref().unwrap_or_default());
+        Ok(())
+    }
+
+    async fn create_thread(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        _: Id<MessageMarker>,
+        name: &str
+    ) -> TransportResult<Id<ChannelMarker>> {
+        assert_eq!(channel_id.get(), CHANNEL_ID);
+        let mut threads = self.threads.lock().unwrap();
+        threads.push(name.to_string());
+        Ok(Id::new(THREAD_ID + (threads.len() as u64) - 1))
+    }
+
+    async fn follow_up(&self, _: &Interaction, reply: &Reply) -> TransportResult {
+        self.record(CHANNEL_ID, reply.content.clone(), &reply.embeds);
+        Ok(())
+    }
+}
+
+fn message(id: u64, channel_id: u64, author: u64, content: &str) -> Event {
+    let message: Message = serde_json
+        ::from_value(
+            json!({
+                "id": id.to_string(),
+                "channel_id": channel_id.to_string(),
+                "guild_id": GUILD_ID.to_string(),
+                "author": {
+                    "id": author.to_string(),
+                    "username": format!("player{}", author),
+                    "discriminator": "0000",
+                    "avatar": null,
+                },
+                "content": content,
+                "timestamp": "2025-01-01T00:00:00.000000+00:00",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": [],
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": 0,
+            })
+        )
+        .unwrap();
+
+    Event::MessageCreate(Box::new(MessageCreate(message)))
+}
+
+/// `/gamble create <amount>` sent by `author`, where the bot has `app_permissions`.
+fn slash_create(id: u64, author: u64, amount: u64, app_permissions: Permissions) -> Event {
+    let interaction: Interaction = serde_json
+        ::from_value(
+            json!({
+                "id": id.to_string(),
+                "application_id": "1",
+                "type": 2,
+                "token": "token",
+                "version": 1,
+                "guild_id": GUILD_ID.to_string(),
+                "channel": { "id": CHANNEL_ID.to_string(), "type": 0 },
+                "app_permissions": app_permissions.bits().to_string(),
+                "authorizing_integration_owners": {},
+                "entitlements": [],
+                "member": {
+                    "user": {
+                        "id": author.to_string(),
+                        "username": format!("player{}", author),
+                        "discriminator": "0000",
+                        "avatar": null,
+                    },
+                    "roles": [],
+                    "joined_at": "2025-01-01T00:00:00.000000+00:00",
+                    "deaf": false,
+                    "mute": false,
+                    "flags": 0,
+                    "permissions": "0",
+                },
+                "data": {
+                    "id": "1",
+                    "name": "gamble",
+                    "type": 1,
+                    "options": [{
+                        "name": "create",
+                        "type": 1,
+                        "options": [{ "name": "amount", "type": 4, "value": amount }],
+                    }],
+                },
+            })
+        )
+        .unwrap();
+
+    Event::InteractionCreate(Box::new(InteractionCreate(interaction)))
+}
+
+/// Plays `commands` as `(author, content)` messages and returns everything the bot posted.
+async fn play(seeds: Vec<String>, commands: &[(u64, &str)]) -> Vec<String> {
+    play_with_config(GuildConfig::default(), seeds, commands).await
+}
+
+/// Same as `play`, in a guild whose settings were already changed to `config`.
+async fn play_with_config(
+    config: GuildConfig,
+    seeds: Vec<String>,
+    commands: &[(u64, &str)]
+) -> Vec<String> {
+    let sink = serve(config, seeds, script(commands)).await;
+
+    sink.sent.lock().unwrap().clone()
+}
+
+/// `commands` as `(author, content)` messages.
+fn script(commands: &[(u64, &str)]) -> VecDeque<Event> {
+    commands
+        .iter()
+        .enumerate()
+        .map(|(index, (author, content))| message(1000 + (index as u64), CHANNEL_ID, *author, content))
+        .collect()
+}
+
+/// Feeds `events` to the bot and returns the sink that recorded its replies.
+async fn serve(config: GuildConfig, seeds: Vec<String>, events: VecDeque<Event>) -> Arc<RecordingSink> {
+    serve_paced(config, seeds, events, Duration::from_millis(1)).await
+}
+
+/// Same as `serve`, waiting `pause` before each event.
+async fn serve_paced(
+    config: GuildConfig,
+    seeds: Vec<String>,
+    events: VecDeque<Event>,
+    pause: Duration
+) -> Arc<RecordingSink> {
+    let (dispatcher, sink) = dispatcher(config, seeds);
+
+    GambleBot::serve(&dispatcher, ScriptedGateway { events, pause }).await;
+    dispatcher.shutdown().await;
+
+    sink
+}
+
+/// A dispatcher for a guild whose settings were changed to `config`, and the sink recording its replies.
+fn dispatcher(config: GuildConfig, seeds: Vec<String>) -> (Dispatcher<RecordingSink>, Arc<RecordingSink>) {
+    let configs = GuildConfigStore::open_in_memory().unwrap();
+    configs.save(&GUILD_ID.to_string(), &config).unwrap();
+
+    let manager = GambleGameManager::new(
+        Arc::new(Ledger::open_in_memory().unwrap()),
+        Arc::new(GameStore::open_in_memory().unwrap()),
+        Arc::new(configs)
+    ).with_seeds(SeedSource::fixed(seeds));
+
+    let sink = Arc::new(RecordingSink::default());
+    let dispatcher = Dispatcher::new(manager, sink.clone(), IdleTimeouts::default());
+
+    (dispatcher, sink)
+}
+
+/// Classic rolls are between 0 and the wager; the nonce counts every roll of the game.
+fn roll(seed: &str, player: u64, nonce: u64) -> u64 {
+    derive_roll(seed, &player.to_string(), nonce, 0, WAGER)
+}
+
+fn rolled(player: u64, value: u64) -> String {
+    format!("- <@{}> **{}**", player, value)
+}
+
+fn owes(loser: u64, winner: u64, amount: u64) -> String {
+    format!("__A winner has emerged!__\n:coin: <@{}> owes <@{}> **{}** gold.", loser, winner, amount)
+}
+
+#[tokio::test(start_paused = true)]
+async fn classic_game_from_create_to_payout() {
+    let seed = find_seed(|seed| roll(seed, ALICE, 0) > roll(seed, BOB, 1)).unwrap();
+    let (alice_roll, bob_roll) = (roll(&seed, ALICE, 0), roll(&seed, BOB, 1));
+
+    let sink = serve(
+        GuildConfig::default(),
+        vec![seed.clone()],
+        script(
+            &[
+                (ALICE, "g!create 500"),
+                (BOB, "g!join"),
+                (ALICE, "g!play"),
+                (ALICE, "g!roll"),
+                (BOB, "g!roll"),
+                (ALICE, "g!ledger"),
+            ]
+        )
+    ).await;
+    let sent = sink.sent.lock().unwrap();
+    let edits = sink.edits.lock().unwrap();
+
+    assert!(sent[0].starts_with("Type `g!join` or click **Join** to join the game!"));
+    // Joining posts the board, starting and rolling edit it.
+    assert!(sent[1].starts_with(":moneybag: __Ongoing Game!__"));
+    assert!(edits.iter().all(|(message_id, ..)| *message_id == 2));
+    assert!(edits[0].1.starts_with("Game is ongoing!"));
+
+    let board = &edits.last().unwrap().1;
+    assert!(board.contains(&rolled(ALICE, alice_roll)));
+    assert!(board.contains(&rolled(BOB, bob_roll)));
+
+    let result = sent
+        .iter()
+        .find(|message| message.starts_with("__A winner has emerged!__"))
+        .unwrap();
+
+    assert!(result.starts_with(&owes(BOB, ALICE, alice_roll - bob_roll)));
+    assert!(result.contains(&seed));
+
+    let ledger_entry = format!("<@{}> won **{}** gold from <@{}>", ALICE, alice_roll - bob_roll, BOB);
+    assert!(sent.last().unwrap().contains(&ledger_entry));
+}
+
+/// Plays a script, then asks to verify the game it created. Game ids are random, so the id is only
+/// read back from the bot's replies once the script is over.
+struct VerifyingGateway {
+    script: ScriptedGateway,
+    sink: Arc<RecordingSink>,
+    verified: bool,
+}
+
+impl EventSource for VerifyingGateway {
+    async fn next_event(&mut self) -> Option<Event> {
+        if let Some(event) = self.script.next_event().await {
+            return Some(event);
+        }
+
+        if self.verified {
+            return None;
+        }
+
+        self.verified = true;
+
+        let sent = self.sink.sent.lock().unwrap();
+        let game_id = sent[0].split("Game `").nth(1)?.split('`').next()?;
+
+        Some(message(2000, CHANNEL_ID, CAROL, &format!("g!verify {}", game_id)))
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn finished_games_can_be_verified_from_their_revealed_seed() {
+    let seed = find_seed(|seed| roll(seed, ALICE, 0) > roll(seed, BOB, 1)).unwrap();
+    let (alice_roll, bob_roll) = (roll(&seed, ALICE, 0), roll(&seed, BOB, 1));
+
+    let (dispatcher, sink) = dispatcher(GuildConfig::default(), vec![seed.clone()]);
+    let events = script(
+        &[(ALICE, "g!create 500"), (BOB, "g!join"), (ALICE, "g!play"), (ALICE, "g!roll"), (BOB, "g!roll")]
+    );
+    let gateway = VerifyingGateway {
+        script: ScriptedGateway { events, pause: Duration::from_millis(1) },
+        sink: sink.clone(),
+        verified: false,
+    };
+
+    GambleBot::serve(&dispatcher, gateway).await;
+    dispatcher.shutdown().await;
+
+    let sent = sink.sent.lock().unwrap();
+    let verification = sent.last().unwrap();
+
+    assert!(verification.starts_with(":mag: __Game `"));
+    assert!(verification.contains(&format!("Seed `{}`", seed)));
+    assert!(verification.contains(&format!("- #0 <@{}> rolled **{}** between 0 and 500", ALICE, alice_roll)));
+    assert!(verification.contains(&format!("- #1 <@{}> rolled **{}** between 0 and 500", BOB, bob_roll)));
+    assert!(verification.ends_with("Every roll matches the revealed seed."));
+}
+
+#[tokio::test(start_paused = true)]
+async fn channels_that_went_quiet_still_answer_commands() {
+    // Long enough for the channel's task to end once the game is cancelled.
+    let sink = serve_paced(
+        GuildConfig::default(),
+        vec![],
+        script(&[(ALICE, "g!create 500"), (ALICE, "g!cancel"), (ALICE, "g!create 500")]),
+        Duration::from_secs(60)
+    ).await;
+    let sent = sink.sent.lock().unwrap();
+
+    assert_eq!(sent.len(), 3);
+    assert!(sent[1].starts_with(":x: The game was cancelled."));
+    assert!(sent[2].starts_with("Type `g!join` or click **Join** to join the game!"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn channels_whose_task_panicked_pick_their_games_back_up() {
+    let (dispatcher, sink) = dispatcher(GuildConfig::default(), vec![]);
+    *sink.panic_on.lock().unwrap() = Some("Type `g!join`");
+
+    let events = script(&[(ALICE, "g!create 500"), (BOB, "g!join")]);
+    GambleBot::serve(&dispatcher, ScriptedGateway { events, pause: Duration::from_millis(1) }).await;
+    dispatcher.shutdown().await;
+
+    // The game was created before the task died, so Bob joins it.
+    let sent = sink.sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert!(sent[0].starts_with(":moneybag: __Ongoing Game!__"));
+    assert!(sent[0].contains(&format!("<@{}>", BOB)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn boards_outlive_the_task_that_posted_them() {
+    let (dispatcher, sink) = dispatcher(GuildConfig::default(), vec![]);
+    *sink.panic_on.lock().unwrap() = Some("Game is ongoing!");
+
+    let events = script(&[(ALICE, "g!create 500"), (BOB, "g!join"), (ALICE, "g!play"), (ALICE, "g!roll")]);
+    GambleBot::serve(&dispatcher, ScriptedGateway { events, pause: Duration::from_millis(1) }).await;
+    dispatcher.shutdown().await;
+
+    let sent = sink.sent.lock().unwrap();
+    let edits = sink.edits.lock().unwrap();
+
+    // The task died starting the game, and the one replacing it rolls on the same board.
+    assert_eq!(sent.iter().filter(|message| message.starts_with(":moneybag: __Ongoing Game!__")).count(), 1);
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].0, 2);
+    assert!(edits[0].1.contains(&format!("<@{}> **", ALICE)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelling_a_game_strips_its_board_of_buttons() {
+    let sink = serve(
+        GuildConfig::default(),
+        vec![],
+        script(
+            &[
+                (ALICE, "g!create 500"),
+                (BOB, "g!join"),
+                (ALICE, "g!cancel"),
+                (ALICE, "g!create 500"),
+                (BOB, "g!join"),
+            ]
+        )
+    ).await;
+    let sent = sink.sent.lock().unwrap();
+    let edits = sink.edits.lock().unwrap();
+
+    assert!(sent[1].starts_with(":moneybag: __Ongoing Game!__"));
+    assert_eq!(edits.len(), 1);
+
+    let (message_id, board, has_buttons) = &edits[0];
+    assert_eq!(*message_id, 2);
+    assert!(board.starts_with("The game was cancelled."));
+    assert!(!has_buttons);
+
+    // The next game gets a board of its own.
+    assert_eq!(sent.iter().filter(|message| message.starts_with(":moneybag: __Ongoing Game!__")).count(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn players_tied_for_the_highest_roll_reroll_for_the_win() {
+    let seed = find_seed(|seed| {
+        let (alice, bob, carol) = (roll(seed, ALICE, 0), roll(seed, BOB, 1), roll(seed, CAROL, 2));
+        alice == bob && carol < alice && roll(seed, ALICE, 3) != roll(seed, BOB, 4)
+    }).unwrap();
+    let (high, low) = (roll(&seed, ALICE, 0), roll(&seed, CAROL, 2));
+    let (alice_reroll, bob_reroll) = (roll(&seed, ALICE, 3), roll(&seed, BOB, 4));
+    let winner = if alice_reroll > bob_reroll { ALICE } else { BOB };
+
+    let sink = serve(
+        GuildConfig::default(),
+        vec![seed],
+        script(
+            &[
+                (ALICE, "g!create 500"),
+                (BOB, "g!join"),
+                (CAROL, "g!join"),
+                (ALICE, "g!play"),
+                (ALICE, "g!roll"),
+                (BOB, "g!roll"),
+                (CAROL, "g!roll"),
+                (ALICE, "g!roll"),
+                (BOB, "g!roll"),
+            ]
+        )
+    ).await;
+    let sent = sink.sent.lock().unwrap();
+    let edits = sink.edits.lock().unwrap();
+
+    // The board shows who has to reroll instead of a message of its own.
+    let tie = &edits[4].1;
+    assert!(tie.contains(&format!("- <@{}> still needs to roll!", ALICE)));
+    assert!(tie.contains(&format!("- <@{}> still needs to roll!", BOB)));
+    assert!(!sent.iter().any(|message| message.contains("you matched the highest roll")));
+
+    let board = &edits.last().unwrap().1;
+    assert!(board.contains(&rolled(ALICE, alice_reroll)));
+    assert!(board.contains(&rolled(BOB, bob_reroll)));
+    // The payout is still based on the rolls of the first round.
+    assert!(sent.last().unwrap().starts_with(&owes(CAROL, winner, high - low)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn players_tied_for_the_lowest_roll_reroll_to_decide_who_pays() {
+    let seed = find_seed(|seed| {
+        let (alice, bob, carol) = (roll(seed, ALICE, 0), roll(seed, BOB, 1), roll(seed, CAROL, 2));
+        bob == carol && alice > bob && roll(seed, BOB, 3) != roll(seed, CAROL, 4)
+    }).unwrap();
+    let (high, low) = (roll(&seed, ALICE, 0), roll(&seed, BOB, 1));
+    let (bob_reroll, carol_reroll) = (roll(&seed, BOB, 3), roll(&seed, CAROL, 4));
+    let loser = if bob_reroll < carol_reroll { BOB } else { CAROL };
+
+    let sink = serve(
+        GuildConfig::default(),
+        vec![seed],
+        script(
+            &[
+                (ALICE, "g!create 500"),
+                (BOB, "g!join"),
+                (CAROL, "g!join"),
+                (ALICE, "g!play"),
+                (ALICE, "g!roll"),
+                (BOB, "g!roll"),
+                (CAROL, "g!roll"),
+                // Alice already won and has nothing left to roll.
+                (ALICE, "g!roll"),
+                (BOB, "g!roll"),
+                (CAROL, "g!roll"),
+            ]
+        )
+    ).await;
+    let sent = sink.sent.lock().unwrap();
+    let edits = sink.edits.lock().unwrap();
+
+    let tie = &edits[4].1;
+    assert!(tie.contains(&format!("- <@{}> still needs to roll!", BOB)));
+    assert!(tie.contains(&format!("- <@{}> still needs to roll!", CAROL)));
+    assert!(!tie.contains(&format!("- <@{}> still needs to roll!", ALICE)));
+    assert!(sent.iter().any(|message| message.starts_with(&format!(":expresionless: <@{}>", ALICE))));
+    assert!(sent.last().unwrap().starts_with(&owes(loser, ALICE, high - low)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn death_roll_ends_when_someone_rolls_a_one() {
+    // Replays the death roll to find out how many turns a seed takes and who rolls the 1.
+    let replay = |seed: &str| {
+        let (mut max_roll, mut nonce, mut players) = (WAGER, 0, [ALICE, BOB].iter().cycle());
+
+        loop {
+            let player = *players.next().unwrap();
+            let value = derive_roll(seed, &player.to_string(), nonce, 1, max_roll);
+            nonce += 1;
+
+            if value == 1 {
+                return (nonce, player);
+            }
+
+            max_roll = value;
+        }
+    };
+
+    let seed = "deathroll".to_string();
+    let (turns, loser) = replay(&seed);
+    let winner = if loser == ALICE { BOB } else { ALICE };
+
+    let mut commands = vec![(ALICE, "g!create deathroll 500"), (BOB, "g!join"), (ALICE, "g!play")];
+    commands.extend(
+        (0..turns).map(|turn| (if turn % 2 == 0 { ALICE } else { BOB }, "g!roll"))
+    );
+
+    let sent = play(vec![seed], &commands).await;
+
+    assert!(sent.last().unwrap().starts_with(&owes(loser, winner, WAGER)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn guild_settings_change_the_prefix_and_the_minimum_wager() {
+    let mut config = GuildConfig::default();
+    config.set("prefix", "!").unwrap();
+    config.set("min_wager", "1000").unwrap();
+
+    let sent = play_with_config(
+        config,
+        vec![],
+        &[
+            (ALICE, "g!create 500"),
+            (ALICE, "!create 500"),
+            (ALICE, "!create"),
+            (ALICE, "!create 1000"),
+        ]
+    ).await;
+
+    assert_eq!(sent.len(), 3);
+    assert!(sent[0].contains("Gamble at least 1 000 gold."));
+    assert!(sent[1].contains("Gamble at least 1 000 gold."));
+    assert!(sent[2].starts_with("Type `!join` or click **Join** to join the game!"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn only_guild_managers_can_change_settings() {
+    // The scripted gateway never sends the guild, so nobody has any permission.
+    let sent = play(vec![], &[(ALICE, "g!config set prefix !"), (ALICE, "!help")]).await;
+
+    assert_eq!(sent.len(), 1);
+    assert!(sent[0].contains("only members who can manage the server"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn the_neutral_tone_drops_the_insults() {
+    let mut config = GuildConfig::default();
+    config.set("tone", "neutral").unwrap();
+
+    let sent = play_with_config(config, vec![], &[(ALICE, "g!roll"), (ALICE, "g!play")]).await;
+
+    assert_eq!(sent, [
+        format!("<@{}>, there is no game to roll in. (i.e.: `g!create`)", ALICE),
+        format!("<@{}>, there is no game to start. (i.e.: `g!create`)", ALICE),
+    ]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn the_guild_language_translates_the_replies() {
+    let mut config = GuildConfig::default();
+    config.set("tone", "neutral").unwrap();
+    config.set("language", "fr").unwrap();
+
+    let sent = play_with_config(config, vec![], &[(ALICE, "g!roll")]).await;
+
+    assert_eq!(sent, [
+        format!("<@{}>, il n'y a aucune partie dans laquelle lancer les dés. (ex. : `g!create`)", ALICE),
+    ]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn info_shows_who_still_needs_to_roll() {
+    let seed = "info".to_string();
+    let sent = play(
+        vec![seed.clone()],
+        &[(ALICE, "g!create 500"), (BOB, "g!join"), (ALICE, "g!play"), (ALICE, "g!roll"), (ALICE, "g!info")]
+    ).await;
+
+    assert_eq!(
+        sent.last().unwrap(),
+        &format!(
+            "Game is ongoing!\n- <@{}> still needs to roll! (i.e.: `g!roll`)\n\n*Rolls*\n{}",
+            BOB,
+            rolled(ALICE, roll(&seed, ALICE, 0))
+        )
+    );
+}
+
+/// A manager to drive directly, without going through the gateway.
+#[tokio::test(start_paused = true)]
+async fn announcements_are_embeds_where_the_bot_can_embed_links() {
+    let events = VecDeque::from([
+        slash_create(1000, ALICE, WAGER, Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS),
+    ]);
+
+    let sink = serve(GuildConfig::default(), vec![], events).await;
+    let embeds = sink.embeds.lock().unwrap();
+
+    assert_eq!(sink.sent.lock().unwrap().as_slice(), [""]);
+    assert_eq!(embeds.len(), 1);
+    assert!(embeds[0].description.as_ref().unwrap().starts_with("Type `g!join` or click **Join**"));
+    assert_eq!(embeds[0].color, Some(0x3498db));
+}
+
+#[tokio::test(start_paused = true)]
+async fn announcements_fall_back_to_plain_text_without_embed_links() {
+    let events = VecDeque::from([slash_create(1000, ALICE, WAGER, Permissions::SEND_MESSAGES)]);
+
+    let sink = serve(GuildConfig::default(), vec![], events).await;
+    let sent = sink.sent.lock().unwrap();
+
+    assert!(sink.embeds.lock().unwrap().is_empty());
+    assert!(sent[0].starts_with("Type `g!join` or click **Join**"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn games_are_played_in_their_own_thread() {
+    let mut config = GuildConfig::default();
+    config.set("threads", "on").unwrap();
+
+    let events = VecDeque::from([
+        message(1000, CHANNEL_ID, ALICE, "g!create 500"),
+        // A second game off the same channel doesn't clash with the first one.
+        message(1001, CHANNEL_ID, CAROL, "g!create 500"),
+        message(1002, THREAD_ID, BOB, "g!join"),
+        // Games created in a thread aren't tied to it.
+        message(1003, CHANNEL_ID, BOB, "g!join"),
+    ]);
+
+    let sink = serve(config, vec![], events).await;
+    let sent = sink.sent.lock().unwrap();
+    let channels = sink.channels.lock().unwrap();
+
+    assert_eq!(sink.threads.lock().unwrap().as_slice(), ["player101's game", "player103's game"]);
+
+    let announcements = sent
+        .iter()
+        .zip(channels.iter())
+        .filter(|(message, _)| message.starts_with("Type `g!join` or click **Join**"))
+        .map(|(_, channel_id)| *channel_id)
+        .collect::<Vec<u64>>();
+
+    assert_eq!(announcements.len(), 2);
+    assert!(announcements.contains(&THREAD_ID));
+    assert!(announcements.contains(&(THREAD_ID + 1)));
+
+    // Bob's board is in the first game's thread, and there's no game to join in the channel itself.
+    assert!(sent.iter().zip(channels.iter()).any(|(message, channel_id)| {
+        message.contains(&format!("- <@{}>", BOB)) && *channel_id == THREAD_ID
+    }));
+    assert_eq!(channels.last(), Some(&CHANNEL_ID));
+}
+
+#[tokio::test(start_paused = true)]
+async fn blackjack_hands_are_played_against_the_dealer_and_banked_by_the_creator() {
+    // Bob is dealt Q♠ 4♣ and Carol a pair of aces, against the dealer's 9♥ and K♦ in the hole.
+    let sink = serve(
+        GuildConfig::default(),
+        vec!["blackjack-5".into()],
+        script(
+            &[
+                (ALICE, "g!create blackjack 500"),
+                (BOB, "g!join"),
+                (CAROL, "g!join"),
+                (ALICE, "g!play"),
+                (BOB, "g!double"),
+                (BOB, "g!hit"),
+                (CAROL, "g!split"),
+                (ALICE, "g!ledger"),
+            ]
+        )
+    ).await;
+
+    let sent = sink.sent.lock().unwrap();
+    let edits = sink.edits.lock().unwrap();
+
+    assert_eq!(
+        edits[1].1,
+        ":black_joker: __Blackjack!__\n<@101> is the house, **500** gold a hand.\n\n*Dealer* 9♥ :grey_question: **9**\n- <@102> Q♠ 4♣ **14** for 500 gold :hourglass_flowing_sand:\n- <@103> A♦ A♣ **12** for 500 gold :hourglass_flowing_sand:"
+    );
+
+    // Doubling draws a single card, after which the hand can't be played anymore.
+    assert!(edits[2].1.contains("- <@102> Q♠ 4♣ 9♦ **23** for 1 000 gold :boom:"));
+    assert_eq!(sent[2], ":unamused: <@102>, you don't have a hand to play. Sit down first.");
+
+    // Split aces get one card each, which ends the players' turn and lets the dealer play.
+    assert!(
+        edits[3].1.ends_with(
+            "*Dealer* 9♥ K♦ **19**\n- <@102> Q♠ 4♣ 9♦ **23** for 1 000 gold :boom:\n- <@103> A♦ 5♦ **16** for 500 gold\n- <@103> A♣ 7♣ **18** for 500 gold"
+        )
+    );
+    assert!(
+        sent[3].starts_with(
+            "__A winner has emerged!__\n:coin: <@102> owes <@101> **1 000** gold.\n:coin: <@103> owes <@101> **1 000** gold.\n:unlock: Seed `blackjack-5`"
+        )
+    );
+
+    // Every transfer of the settlement makes it to the ledger.
+    assert!(sent[4].contains("<@101> won **1 000** gold from <@102>"));
+    assert!(sent[4].contains("<@101> won **1 000** gold from <@103>"));
+}
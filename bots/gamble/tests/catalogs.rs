@@ -0,0 +1,40 @@
+//! Every catalog has to cover every message with the same placeholders as the English one, or
+//! part of a message would silently go missing in that language or tone.
+
+use std::collections::BTreeSet;
+
+use gamble::{ LOCALES, Locale, MessageKey, Messages, TONES, Tone };
+
+fn placeholders(template: &str) -> BTreeSet<&str> {
+    template
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}'))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+#[test]
+fn every_catalog_covers_every_message_key() {
+    let reference = Messages { locale: Locale::En, tone: Tone::Savage };
+
+    for &locale in LOCALES {
+        for &tone in TONES {
+            let messages = Messages { locale, tone };
+
+            for &key in MessageKey::ALL {
+                let template = messages.template(key);
+
+                assert!(!template.trim().is_empty(), "{:?} is empty in {:?}/{:?}", key, locale, tone);
+                assert_eq!(
+                    placeholders(template),
+                    placeholders(reference.template(key)),
+                    "{:?} has different placeholders in {:?}/{:?}",
+                    key,
+                    locale,
+                    tone
+                );
+            }
+        }
+    }
+}
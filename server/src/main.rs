@@ -11,10 +11,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Each bot gets its own token (and thus its own shard & rate limits)
     // export DISCORD_TOKEN_GREETER=...; export DISCORD_TOKEN_MODERATOR=...
     let gamble_token = env::var("DISCORD_TOKEN_GAMBLE").map_err(|_| "Set DISCORD_TOKEN_GAMBLE")?;
+    let gamble_options = gamble::GambleOptions::from_env()?;
 
     info!("starting bots (Ctrl-C to stop)");
 
-    let bot_gamble = gamble::GambleBot::run(gamble_token);
+    let bot_gamble = gamble::GambleBot::run(gamble_token, gamble_options);
 
     select! {
         res = async {